@@ -0,0 +1,70 @@
+#[path = "executor.rs"]
+mod executor;
+
+use executor::{after, block_on};
+
+// Exercise 1
+// Make me compile!
+// `fetch_price` is async: wait for it before doing arithmetic with it
+async fn fetch_price(item: &str) -> u32 {
+    after(2, item.len() as u32 * 10).await
+}
+
+async fn total_price(items: &[&str]) -> u32 {
+    let mut total = 0;
+    for item in items {
+        total += fetch_price(item).await;
+    }
+    total
+}
+
+// Exercise 2
+// Calling an async function does nothing until the future is awaited or run.
+// Return how many times `count` ran when the futures are created but dropped
+// and when they are run with `block_on`
+async fn count(counter: &std::cell::Cell<u32>) {
+    counter.set(counter.get() + 1);
+}
+
+fn runs(counter: &std::cell::Cell<u32>) -> (u32, u32) {
+    drop(count(counter));
+    let dropped = counter.get();
+    block_on(count(counter));
+    (dropped, counter.get())
+}
+
+// Exercise 3
+// An async block is a future too: build one that awaits both prices and
+// returns the cheaper one, minus the member discount
+fn cheapest<'a>(a: &'a str, b: &'a str) -> impl std::future::Future<Output = u32> + 'a {
+    let discount = 5;
+    async move {
+        let a = fetch_price(a).await;
+        let b = fetch_price(b).await;
+        a.min(b) - discount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn await_every_price() {
+        assert_eq!(block_on(total_price(&["tea", "coffee"])), 90);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn futures_are_lazy() {
+        let counter = std::cell::Cell::new(0);
+        assert_eq!(runs(&counter), (0, 1));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn async_blocks() {
+        assert_eq!(block_on(cheapest("espresso", "tea")), 25);
+    }
+}
@@ -0,0 +1,83 @@
+#[path = "executor.rs"]
+mod executor;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// Exercise 1
+// Awaiting two futures one after the other makes the second wait for the
+// first. `Join` polls both every time it is polled and completes once both
+// have, with both outputs. Implement `poll`
+struct Join<A: Future, B: Future> {
+    a: Pin<Box<A>>,
+    b: Pin<Box<B>>,
+    a_output: Option<A::Output>,
+    b_output: Option<B::Output>,
+}
+
+fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join {
+        a: Box::pin(a),
+        b: Box::pin(b),
+        a_output: None,
+        b_output: None,
+    }
+}
+
+impl<A: Future, B: Future> Future for Join<A, B>
+where
+    A::Output: Unpin,
+    B::Output: Unpin,
+{
+    type Output = (A::Output, B::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if this.a_output.is_none() {
+            if let Poll::Ready(output) = this.a.as_mut().poll(cx) {
+                this.a_output = Some(output);
+            }
+        }
+        if this.b_output.is_none() {
+            if let Poll::Ready(output) = this.b.as_mut().poll(cx) {
+                this.b_output = Some(output);
+            }
+        }
+        if this.a_output.is_some() && this.b_output.is_some() {
+            Poll::Ready((this.a_output.take().unwrap(), this.b_output.take().unwrap()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::executor::{after, block_on, Yield};
+    use super::*;
+    use std::cell::RefCell;
+
+    // Test for exercise 1
+    #[test]
+    fn join_returns_both_outputs() {
+        assert_eq!(block_on(join(after(3, "slow"), after(1, 42))), ("slow", 42));
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn join_runs_both_at_once() {
+        let log = RefCell::new(Vec::new());
+        let task = |name: &'static str, ticks: u32| {
+            let log = &log;
+            async move {
+                for tick in 0..ticks {
+                    log.borrow_mut().push(format!("{name}{tick}"));
+                    Yield { times: 1 }.await;
+                }
+            }
+        };
+        block_on(join(task("a", 2), task("b", 3)));
+        assert_eq!(*log.borrow(), vec!["a0", "b0", "a1", "b1", "b2"]);
+    }
+}
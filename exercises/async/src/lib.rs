@@ -0,0 +1,8 @@
+#![allow(dead_code)]
+// Every exercise file loads the executor itself, so that it also builds on
+// its own as a `cargo test --test <name>` target
+#![allow(clippy::duplicate_mod)]
+
+mod async_fn;
+mod join;
+mod select;
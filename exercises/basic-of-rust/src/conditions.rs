@@ -1,3 +1,13 @@
+// The exercises compare with `true` and `false` and spell out their
+// conditions the long way on purpose. `manual_is_multiple_of` is only known to
+// recent clippy versions, hence `unknown_lints`
+#![allow(unknown_lints)]
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::needless_bool,
+    clippy::manual_is_multiple_of
+)]
+
 //Exercise 1
 // Complete this function to return the bigger number!
 // Do not use:
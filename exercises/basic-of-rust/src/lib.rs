@@ -1,3 +1,6 @@
+// The exercises are only called from their tests
+#![allow(dead_code)]
+
 mod conditions;
 mod strings;
 mod functions;
@@ -1,3 +1,7 @@
+// Exercise 3 must keep all of its lines, and the tests compare with `true`
+// and `false` on purpose
+#![allow(clippy::let_and_return, clippy::bool_assert_comparison)]
+
 // Exercise 1
 #[allow(dead_code)]
 fn exercise1(color: &str) -> String {
@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+mod vectors;
+mod hashmaps;
+mod sets_and_trees;
+mod queues;
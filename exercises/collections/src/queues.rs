@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+
+// Exercise 1
+// A print queue: jobs are printed in the order they were added, but urgent
+// jobs jump to the front
+#[derive(Default)]
+struct PrintQueue {
+    jobs: VecDeque<String>,
+}
+
+impl PrintQueue {
+    fn add(&mut self, job: &str) {
+        self.jobs.push_back(job.to_string());
+    }
+
+    fn add_urgent(&mut self, job: &str) {
+        self.jobs.push_front(job.to_string());
+    }
+
+    fn print_next(&mut self) -> Option<String> {
+        self.jobs.pop_front()
+    }
+}
+
+// Exercise 2
+// Output: the last `n` lines of the text, keeping at most `n` of them
+// in memory at any time
+fn tail(text: &str, n: usize) -> Vec<&str> {
+    let mut last = VecDeque::with_capacity(n + 1);
+    for line in text.lines() {
+        last.push_back(line);
+        if last.len() > n {
+            last.pop_front();
+        }
+    }
+    last.into_iter().collect()
+}
+
+// Exercise 3
+// Output: the items rotated `k` places to the left
+fn rotate(items: &[i32], k: usize) -> Vec<i32> {
+    let mut queue: VecDeque<i32> = items.iter().copied().collect();
+    if !queue.is_empty() {
+        queue.rotate_left(k % queue.len());
+    }
+    queue.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn urgent_jobs_first() {
+        let mut queue = PrintQueue::default();
+        queue.add("report");
+        queue.add("photo");
+        queue.add_urgent("ticket");
+        assert_eq!(queue.print_next().as_deref(), Some("ticket"));
+        assert_eq!(queue.print_next().as_deref(), Some("report"));
+        assert_eq!(queue.print_next().as_deref(), Some("photo"));
+        assert_eq!(queue.print_next(), None);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn last_lines() {
+        assert_eq!(tail("a\nb\nc\nd", 2), vec!["c", "d"]);
+        assert_eq!(tail("a", 3), vec!["a"]);
+        assert!(tail("a\nb", 0).is_empty());
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn rotated() {
+        assert_eq!(rotate(&[1, 2, 3, 4], 1), vec![2, 3, 4, 1]);
+        assert_eq!(rotate(&[1, 2, 3], 5), vec![3, 1, 2]);
+        assert!(rotate(&[], 2).is_empty());
+    }
+}
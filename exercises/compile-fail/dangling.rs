@@ -0,0 +1,10 @@
+// Exercise 1
+// A reference must not outlive the value it points to. Make this file fail
+// to compile by returning a reference to `greeting`, which is dropped when
+// the function returns. The compiler must report the error in
+// `dangling.stderr`
+
+pub fn longest_greeting(name: &str) -> &str {
+    let greeting = format!("Hello, {name}!");
+    &greeting
+}
@@ -0,0 +1,13 @@
+// Exercise 1
+// There can be only one mutable borrow of a value at a time. Make this file
+// fail to compile by using `first` after `second` was created. The compiler
+// must report the error in `two_borrows.stderr`
+
+pub fn push_twice() -> Vec<i32> {
+    let mut numbers = vec![1, 2, 3];
+    let first = &mut numbers;
+    let second = &mut numbers;
+    second.push(4);
+    first.push(5);
+    numbers
+}
@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+// Every exercise file loads the test timeout helper itself, so that it also
+// builds on its own as a `cargo test --test <name>` target
+#![allow(clippy::duplicate_mod)]
+
+mod threads;
+mod channels;
+mod shared_state;
+mod deadlock;
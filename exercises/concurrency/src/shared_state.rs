@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[cfg(test)]
+#[path = "timeout.rs"]
+mod timeout;
+
+// Exercise 1
+// Make me compile!
+// Ten threads each add their number to a shared list
+fn collect_from_threads() -> Vec<usize> {
+    let list = Arc::new(Mutex::new(Vec::new()));
+    let handles: Vec<_> = (0..10)
+        .map(|i| {
+            let list = Arc::clone(&list);
+            thread::spawn(move || list.lock().unwrap().push(i))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let mut list = list.lock().unwrap().clone();
+    list.sort();
+    list
+}
+
+// Exercise 2
+// Count the words of every text on its own thread, into one shared map
+fn word_counts(texts: Vec<String>) -> HashMap<String, usize> {
+    let counts = Arc::new(Mutex::new(HashMap::new()));
+    let handles: Vec<_> = texts
+        .into_iter()
+        .map(|text| {
+            let counts = Arc::clone(&counts);
+            thread::spawn(move || {
+                for word in text.split_whitespace() {
+                    *counts.lock().unwrap().entry(word.to_string()).or_insert(0) += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let counts = counts.lock().unwrap().clone();
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::timeout::with_timeout;
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn every_thread_pushes() {
+        assert_eq!(
+            with_timeout(5, collect_from_threads),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn shared_word_counts() {
+        let texts = vec!["a b a".to_string(), "b c".to_string(), "a".to_string()];
+        let counts = with_timeout(5, || word_counts(texts));
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["b"], 2);
+        assert_eq!(counts["c"], 1);
+    }
+}
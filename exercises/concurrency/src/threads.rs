@@ -0,0 +1,82 @@
+use std::thread;
+
+#[cfg(test)]
+#[path = "timeout.rs"]
+mod timeout;
+
+// Exercise 1
+// Spawn one thread per number, each returning its square,
+// and collect the results in the same order
+fn squares(numbers: Vec<u64>) -> Vec<u64> {
+    let handles: Vec<_> = numbers
+        .into_iter()
+        .map(|n| thread::spawn(move || n * n))
+        .collect();
+    handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect()
+}
+
+// Exercise 2
+// Make me compile!
+// The thread borrows `name`, which may not live long enough: move it in
+fn greet_from_thread(name: String) -> String {
+    let handle = thread::spawn(move || format!("Hello, {name}!"));
+    handle.join().unwrap()
+}
+
+// Exercise 3
+// Spawn a thread that panics and report it instead of crashing:
+// Ok with the thread's result, Err with "thread panicked" otherwise
+fn run_checked(f: fn() -> i32) -> Result<i32, String> {
+    thread::spawn(f)
+        .join()
+        .map_err(|_| "thread panicked".to_string())
+}
+
+// Exercise 4
+// With scoped threads the closures may borrow local data:
+// sum each half of `numbers` on its own thread
+fn sum_halves(numbers: &[i32]) -> (i32, i32) {
+    let (left, right) = numbers.split_at(numbers.len() / 2);
+    thread::scope(|scope| {
+        let left = scope.spawn(|| left.iter().sum());
+        let right = scope.spawn(|| right.iter().sum());
+        (left.join().unwrap(), right.join().unwrap())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::timeout::with_timeout;
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn squares_in_order() {
+        let result = with_timeout(5, || squares(vec![1, 2, 3, 4]));
+        assert_eq!(result, vec![1, 4, 9, 16]);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn greet() {
+        let result = with_timeout(5, || greet_from_thread("Ferris".to_string()));
+        assert_eq!(result, "Hello, Ferris!");
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn panics_are_reported() {
+        let result = with_timeout(5, || (run_checked(|| 7), run_checked(|| panic!("boom"))));
+        assert_eq!(result, (Ok(7), Err("thread panicked".to_string())));
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn scoped_threads() {
+        let result = with_timeout(5, || sum_halves(&[1, 2, 3, 4, 5]));
+        assert_eq!(result, (3, 12));
+    }
+}
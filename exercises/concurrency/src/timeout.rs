@@ -0,0 +1,18 @@
+// Test helper: fails the test instead of hanging when `f` does not finish in time
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+pub fn with_timeout<T: Send + 'static>(seconds: u64, f: impl FnOnce() -> T + Send + 'static) -> T {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(f());
+    });
+    match receiver.recv_timeout(Duration::from_secs(seconds)) {
+        Ok(value) => value,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            panic!("timed out after {seconds}s, is something stuck?")
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => panic!("the code under test panicked"),
+    }
+}
@@ -0,0 +1,97 @@
+// Exercise 1
+// Implement `From<(u8, u8, u8)>` for `Color`
+#[derive(Debug, PartialEq)]
+struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Color { red, green, blue }
+    }
+}
+
+// Exercise 2
+// Implement `From<&str>` for `Person`: "Name,age" gives that person, and
+// anything that does not parse gives the default person
+#[derive(Debug, PartialEq)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+impl Default for Person {
+    fn default() -> Self {
+        Person {
+            name: "John".to_string(),
+            age: 30,
+        }
+    }
+}
+
+impl From<&str> for Person {
+    fn from(text: &str) -> Self {
+        let Some((name, age)) = text.split_once(',') else {
+            return Person::default();
+        };
+        match age.trim().parse() {
+            Ok(age) if !name.is_empty() => Person {
+                name: name.to_string(),
+                age,
+            },
+            _ => Person::default(),
+        }
+    }
+}
+
+// Exercise 3
+// Make me compile!
+// Implementing `From` gives `Into` for free: accept anything that converts
+// into a `Color`
+fn brightness(color: impl Into<Color>) -> u32 {
+    let color = color.into();
+    (color.red as u32 + color.green as u32 + color.blue as u32) / 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn color_from_tuple() {
+        assert_eq!(
+            Color::from((255, 128, 0)),
+            Color {
+                red: 255,
+                green: 128,
+                blue: 0
+            }
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn person_from_str() {
+        assert_eq!(
+            Person::from("Mark,20"),
+            Person {
+                name: "Mark".to_string(),
+                age: 20
+            }
+        );
+        assert_eq!(Person::from(""), Person::default());
+        assert_eq!(Person::from(",1"), Person::default());
+        assert_eq!(Person::from("Mark,twenty"), Person::default());
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn into_for_free() {
+        assert_eq!(brightness((30, 60, 90)), 60);
+        let person: Person = "Ann,41".into();
+        assert_eq!(person.age, 41);
+    }
+}
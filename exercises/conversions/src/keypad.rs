@@ -0,0 +1,169 @@
+use std::fmt;
+use std::str::FromStr;
+
+// The keypad and commands of a cash machine
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Digit(u8),
+    Enter,
+    Cancel,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct UnknownKey(char);
+
+impl fmt::Display for UnknownKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no key for `{}`", self.0)
+    }
+}
+
+// Exercise 1
+// Implement `TryFrom<char>` for `Key`: '0'-'9' are digits, '\n' or '#'
+// is Enter and 'x' or '*' is Cancel. Anything else is an `UnknownKey`
+impl TryFrom<char> for Key {
+    type Error = UnknownKey;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '0'..='9' => Ok(Key::Digit(c as u8 - b'0')),
+            '\n' | '#' => Ok(Key::Enter),
+            'x' | '*' => Ok(Key::Cancel),
+            other => Err(UnknownKey(other)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    InsertCard { account: String },
+    EnterPin(String),
+    Withdraw(u64),
+    Balance,
+    EjectCard,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ParseActionError {
+    Empty,
+    UnknownCommand(String),
+    MissingArgument(&'static str),
+    InvalidAmount(String),
+    InvalidPin(String),
+}
+
+// Exercise 2
+// Implement `FromStr` for `Action`, for commands typed like
+// "insert 12345", "pin 0000", "withdraw 50", "balance" or "eject".
+// A PIN is exactly four digits
+impl FromStr for Action {
+    type Err = ParseActionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let command = words.next().ok_or(ParseActionError::Empty)?;
+        let argument = words.next();
+        let required = |name| argument.ok_or(ParseActionError::MissingArgument(name));
+        match command {
+            "insert" => Ok(Action::InsertCard {
+                account: required("account")?.to_string(),
+            }),
+            "pin" => {
+                let pin = required("pin")?;
+                if pin.len() == 4 && pin.bytes().all(|b| b.is_ascii_digit()) {
+                    Ok(Action::EnterPin(pin.to_string()))
+                } else {
+                    Err(ParseActionError::InvalidPin(pin.to_string()))
+                }
+            }
+            "withdraw" => {
+                let amount = required("amount")?;
+                amount
+                    .parse()
+                    .map(Action::Withdraw)
+                    .map_err(|_| ParseActionError::InvalidAmount(amount.to_string()))
+            }
+            "balance" => Ok(Action::Balance),
+            "eject" => Ok(Action::EjectCard),
+            other => Err(ParseActionError::UnknownCommand(other.to_string())),
+        }
+    }
+}
+
+// Exercise 3
+// Input: everything typed on the keypad
+// Output: the digits typed before the first Enter, stopping at Cancel
+// (which clears them) or at an unknown key
+fn read_pin(typed: &str) -> Result<String, UnknownKey> {
+    let mut pin = String::new();
+    for c in typed.chars() {
+        match Key::try_from(c)? {
+            Key::Digit(d) => pin.push(char::from(b'0' + d)),
+            Key::Enter => break,
+            Key::Cancel => pin.clear(),
+        }
+    }
+    Ok(pin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn keys_from_chars() {
+        assert_eq!(Key::try_from('7'), Ok(Key::Digit(7)));
+        assert_eq!(Key::try_from('#'), Ok(Key::Enter));
+        assert_eq!(Key::try_from('*'), Ok(Key::Cancel));
+        assert_eq!(Key::try_from('?'), Err(UnknownKey('?')));
+        assert_eq!(UnknownKey('?').to_string(), "no key for `?`");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn actions_from_commands() {
+        assert_eq!(
+            "insert 12345".parse(),
+            Ok(Action::InsertCard {
+                account: "12345".to_string()
+            })
+        );
+        assert_eq!("pin 0420".parse(), Ok(Action::EnterPin("0420".to_string())));
+        assert_eq!("withdraw 50".parse(), Ok(Action::Withdraw(50)));
+        assert_eq!("  balance ".parse(), Ok(Action::Balance));
+        assert_eq!("eject".parse(), Ok(Action::EjectCard));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn bad_commands() {
+        let error = |s: &str| s.parse::<Action>().unwrap_err();
+        assert_eq!(error(""), ParseActionError::Empty);
+        assert_eq!(
+            error("dance"),
+            ParseActionError::UnknownCommand("dance".to_string())
+        );
+        assert_eq!(
+            error("withdraw"),
+            ParseActionError::MissingArgument("amount")
+        );
+        assert_eq!(
+            error("withdraw -5"),
+            ParseActionError::InvalidAmount("-5".to_string())
+        );
+        assert_eq!(
+            error("pin 12a4"),
+            ParseActionError::InvalidPin("12a4".to_string())
+        );
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn pin_from_key_presses() {
+        assert_eq!(read_pin("1234#99"), Ok("1234".to_string()));
+        assert_eq!(read_pin("12*5678\n"), Ok("5678".to_string()));
+        assert_eq!(read_pin("12a"), Err(UnknownKey('a')));
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+mod from_into;
+mod try_from;
+mod from_str;
+mod keypad;
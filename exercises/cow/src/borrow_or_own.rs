@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+
+// Exercise 1
+// Replace every tab with four spaces. Most input has no tabs at all, so
+// return it borrowed as it is and only allocate a new `String` when there
+// is something to replace
+fn expand_tabs(text: &str) -> Cow<'_, str> {
+    if text.contains('\t') {
+        Cow::Owned(text.replace('\t', "    "))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+// Exercise 2
+// Trim the surrounding whitespace and collapse every run of inner
+// whitespace into one space. Borrow a slice of `text` whenever the result
+// is a part of it
+fn normalize_spaces(text: &str) -> Cow<'_, str> {
+    let trimmed = text.trim();
+    let needs_work =
+        trimmed.contains("  ") || trimmed.chars().any(|c| c.is_whitespace() && c != ' ');
+    if !needs_work {
+        return Cow::Borrowed(trimmed);
+    }
+    Cow::Owned(trimmed.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+// Exercise 3
+// `Cow` works for any borrowed type with an owned version. Clamp every
+// value to `0..=max`, copying the slice only when a value is out of range
+fn clamp_all(values: &[i32], max: i32) -> Cow<'_, [i32]> {
+    let mut values = Cow::Borrowed(values);
+    for i in 0..values.len() {
+        let clamped = values[i].clamp(0, max);
+        if clamped != values[i] {
+            // `to_mut` copies the data the first time it is called
+            values.to_mut()[i] = clamped;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use test_support::allocations;
+
+    test_support::count_allocations!();
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn expand_tabs_results() {
+        assert_eq!(expand_tabs("a\tb"), "a    b");
+        assert_eq!(expand_tabs("no tabs"), "no tabs");
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn expand_tabs_borrows_when_it_can() {
+        let (result, count) = allocations(|| expand_tabs("no tabs here"));
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(count, 0);
+        let (result, count) = allocations(|| expand_tabs("\tindented"));
+        assert!(matches!(result, Cow::Owned(_)));
+        assert!(count > 0);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn normalize_spaces_results() {
+        assert_eq!(normalize_spaces("  hello   world \n"), "hello world");
+        assert_eq!(normalize_spaces("tab\tand\nnewline"), "tab and newline");
+        assert_eq!(normalize_spaces("   "), "");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn normalize_spaces_borrows_when_it_can() {
+        for text in ["already clean", "  only trimmed  ", ""] {
+            let (result, count) = allocations(|| normalize_spaces(text));
+            assert!(matches!(result, Cow::Borrowed(_)), "{text:?}");
+            assert_eq!(count, 0, "{text:?}");
+        }
+        let (result, _) = allocations(|| normalize_spaces("two  spaces"));
+        assert!(matches!(result, Cow::Owned(_)));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn clamp_all_copies_only_when_needed() {
+        let in_range = [1, 5, 3];
+        let (result, count) = allocations(|| clamp_all(&in_range, 5));
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(count, 0);
+
+        let (result, count) = allocations(|| clamp_all(&[-2, 7, 3, 9], 5));
+        assert_eq!(*result, [0, 5, 3, 5]);
+        // Fixing two values still copies the slice just once
+        assert_eq!(count, 1);
+    }
+}
@@ -0,0 +1,4 @@
+#![allow(dead_code)]
+
+mod borrow_or_own;
+mod cow_quiz;
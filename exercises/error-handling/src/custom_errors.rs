@@ -0,0 +1,106 @@
+use std::fmt;
+use std::num::ParseIntError;
+
+// Exercise 1
+// Define the ways creating a `PositiveNonzeroInteger` can fail
+#[derive(Debug, PartialEq)]
+enum CreationError {
+    Negative,
+    Zero,
+}
+
+#[derive(Debug, PartialEq)]
+struct PositiveNonzeroInteger(u64);
+
+impl PositiveNonzeroInteger {
+    fn new(value: i64) -> Result<PositiveNonzeroInteger, CreationError> {
+        match value {
+            x if x < 0 => Err(CreationError::Negative),
+            0 => Err(CreationError::Zero),
+            x => Ok(PositiveNonzeroInteger(x as u64)),
+        }
+    }
+}
+
+// Exercise 2
+// Implement `Display` so the errors read "number is negative" and "number is zero"
+impl fmt::Display for CreationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            CreationError::Negative => "number is negative",
+            CreationError::Zero => "number is zero",
+        };
+        f.write_str(description)
+    }
+}
+
+// Exercise 3
+// `parse_positive` can fail in two different ways. Wrap both in one error
+// type and add `From` impls so `?` converts them
+#[derive(Debug, PartialEq)]
+enum ParsePosNonzeroError {
+    Creation(CreationError),
+    ParseInt(ParseIntError),
+}
+
+impl From<CreationError> for ParsePosNonzeroError {
+    fn from(err: CreationError) -> Self {
+        ParsePosNonzeroError::Creation(err)
+    }
+}
+
+impl From<ParseIntError> for ParsePosNonzeroError {
+    fn from(err: ParseIntError) -> Self {
+        ParsePosNonzeroError::ParseInt(err)
+    }
+}
+
+fn parse_positive(text: &str) -> Result<PositiveNonzeroInteger, ParsePosNonzeroError> {
+    let value: i64 = text.parse()?;
+    Ok(PositiveNonzeroInteger::new(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn creation() {
+        assert_eq!(
+            PositiveNonzeroInteger::new(10),
+            Ok(PositiveNonzeroInteger(10))
+        );
+        assert_eq!(
+            PositiveNonzeroInteger::new(-10),
+            Err(CreationError::Negative)
+        );
+        assert_eq!(PositiveNonzeroInteger::new(0), Err(CreationError::Zero));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn error_messages() {
+        assert_eq!(CreationError::Negative.to_string(), "number is negative");
+        assert_eq!(CreationError::Zero.to_string(), "number is zero");
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn parse_error() {
+        assert!(matches!(
+            parse_positive("not a number"),
+            Err(ParsePosNonzeroError::ParseInt(_))
+        ));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn creation_error() {
+        assert_eq!(
+            parse_positive("-555"),
+            Err(ParsePosNonzeroError::Creation(CreationError::Negative))
+        );
+        assert_eq!(parse_positive("42"), Ok(PositiveNonzeroInteger(42)));
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+mod results;
+mod options;
+mod custom_errors;
+mod boxed_errors;
@@ -0,0 +1,72 @@
+// Exercise 1
+// Input: a full name like "Ada Lovelace"
+// Output: the initial of the last name, or None if there is only one name.
+// Use `Option` combinators instead of `match`
+fn last_initial(name: &str) -> Option<char> {
+    name.split_whitespace()
+        .nth(1)
+        .and_then(|last| last.chars().next())
+}
+
+// Exercise 2
+// Output: the length of the user's nickname, 0 when they have none
+fn nickname_length(nickname: Option<&str>) -> usize {
+    nickname.map_or(0, str::len)
+}
+
+// Exercise 3
+// Input: a position in the list
+// Output: the item there doubled, or an error message with the position
+fn double_at(items: &[i32], position: usize) -> Result<i32, String> {
+    items
+        .get(position)
+        .map(|item| item * 2)
+        .ok_or(format!("nothing at position {position}"))
+}
+
+// Exercise 4
+// Output: the first even number of the list, if it is greater than 10
+fn first_big_even(items: &[i32]) -> Option<i32> {
+    items
+        .iter()
+        .copied()
+        .find(|item| item % 2 == 0)
+        .filter(|item| *item > 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn last_initial_works() {
+        assert_eq!(last_initial("Ada Lovelace"), Some('L'));
+        assert_eq!(last_initial("Ferris"), None);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn nickname_length_works() {
+        assert_eq!(nickname_length(Some("crab")), 4);
+        assert_eq!(nickname_length(None), 0);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn double_at_works() {
+        assert_eq!(double_at(&[1, 2, 3], 1), Ok(4));
+        assert_eq!(
+            double_at(&[1, 2, 3], 5),
+            Err("nothing at position 5".to_string())
+        );
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn first_big_even_works() {
+        assert_eq!(first_big_even(&[3, 12, 14]), Some(12));
+        assert_eq!(first_big_even(&[3, 4, 12]), None);
+        assert_eq!(first_big_even(&[]), None);
+    }
+}
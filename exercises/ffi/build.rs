@@ -0,0 +1,42 @@
+//! Compiles the C files in `c/` into a static library with the system C
+//! compiler, so `cargo test` can check the FFI exercises end to end.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+const SOURCES: [&str; 2] = ["fixture", "callbacks"];
+
+fn run(command: &mut Command) {
+    let status = command
+        .status()
+        .unwrap_or_else(|err| panic!("could not run {command:?}: {err}"));
+    assert!(status.success(), "{command:?} failed with {status}");
+}
+
+fn main() {
+    let out = PathBuf::from(env::var("OUT_DIR").expect("cargo sets OUT_DIR"));
+    let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("cargo sets it"));
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let archiver = env::var("AR").unwrap_or_else(|_| "ar".to_string());
+
+    // One object per source, so the linker only pulls in the ones an exercise uses
+    let mut archive = Command::new(archiver);
+    archive.arg("crs").arg(out.join("libfixture.a"));
+    for source in SOURCES {
+        let object = out.join(format!("{source}.o"));
+        run(Command::new(&compiler)
+            .args(["-c", "-fPIC", "-O2", "-o"])
+            .arg(&object)
+            .arg(manifest.join(format!("c/{source}.c"))));
+        archive.arg(object);
+        println!("cargo:rerun-if-changed=c/{source}.c");
+    }
+    run(&mut archive);
+
+    // Dependents get this search path too, so the exercise files can link the
+    // library when they are built as test targets of the workspace root
+    println!("cargo:rustc-link-search=native={}", out.display());
+    println!("cargo:rerun-if-env-changed=CC");
+    println!("cargo:rerun-if-env-changed=AR");
+}
@@ -0,0 +1,13 @@
+/* The part of the fixture that calls back into Rust. It is a separate object in
+   the library, so exercises that do not define `rust_square` still link. */
+
+/* Implemented in Rust */
+int rust_square(int x);
+
+int fixture_sum_of_squares(int n) {
+    int sum = 0;
+    for (int i = 1; i <= n; i++) {
+        sum += rust_square(i);
+    }
+    return sum;
+}
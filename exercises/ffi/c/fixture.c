@@ -0,0 +1,43 @@
+/* C functions the FFI exercises call, built into a static library by build.rs */
+
+#include <ctype.h>
+#include <stddef.h>
+
+int fixture_add(int a, int b) {
+    return a + b;
+}
+
+size_t fixture_count_vowels(const char *text) {
+    size_t count = 0;
+    for (; *text != '\0'; text++) {
+        switch (tolower((unsigned char)*text)) {
+        case 'a': case 'e': case 'i': case 'o': case 'u':
+            count++;
+        }
+    }
+    return count;
+}
+
+const char *fixture_greeting(void) {
+    return "hello from C";
+}
+
+/* Writes `text` upper-cased into `out`, which holds `capacity` bytes.
+   Returns the length of the result, or -1 when it does not fit. */
+int fixture_shout(const char *text, char *out, size_t capacity) {
+    size_t i = 0;
+    for (; text[i] != '\0'; i++) {
+        if (i + 1 >= capacity) {
+            return -1;
+        }
+        out[i] = (char)toupper((unsigned char)text[i]);
+    }
+    out[i] = '\0';
+    return (int)i;
+}
+
+void fixture_map(int *values, size_t len, int (*f)(int)) {
+    for (size_t i = 0; i < len; i++) {
+        values[i] = f(values[i]);
+    }
+}
@@ -0,0 +1,53 @@
+use std::os::raw::c_int;
+
+#[link(name = "fixture", kind = "static")]
+extern "C" {
+    fn fixture_map(values: *mut c_int, len: usize, f: extern "C" fn(c_int) -> c_int);
+    fn fixture_sum_of_squares(n: c_int) -> c_int;
+}
+
+// Exercise 1
+// C can call Rust functions declared `extern "C"`. `fixture_map` calls `f`
+// on every value. Write `triple` and pass it along
+extern "C" fn triple(x: c_int) -> c_int {
+    x * 3
+}
+
+fn triple_all(values: &mut [i32]) {
+    // SAFETY: the pointer and length come from the same live slice
+    unsafe { fixture_map(values.as_mut_ptr(), values.len(), triple) }
+}
+
+// Exercise 2
+// `fixture_sum_of_squares` calls a function named `rust_square` that C
+// only knows by name. Export it with `#[no_mangle]` so the linker finds it
+#[no_mangle]
+pub extern "C" fn rust_square(x: c_int) -> c_int {
+    x * x
+}
+
+fn sum_of_squares(n: i32) -> i32 {
+    // SAFETY: `fixture_sum_of_squares` only does arithmetic
+    unsafe { fixture_sum_of_squares(n) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn c_calls_back_into_rust() {
+        let mut values = [1, -2, 10];
+        triple_all(&mut values);
+        assert_eq!(values, [3, -6, 30]);
+        triple_all(&mut []);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn c_calls_an_exported_function() {
+        assert_eq!(sum_of_squares(3), 14);
+        assert_eq!(sum_of_squares(0), 0);
+    }
+}
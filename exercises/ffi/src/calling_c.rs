@@ -0,0 +1,34 @@
+use std::os::raw::c_int;
+
+// Exercise 1
+// `c/fixture.c` defines `int fixture_add(int a, int b)`. Declare it in the
+// `extern "C"` block below, with `c_int` for C's `int`
+#[link(name = "fixture", kind = "static")]
+extern "C" {
+    fn fixture_add(a: c_int, b: c_int) -> c_int;
+}
+
+// Exercise 2
+// The compiler cannot check what happens on the other side, so every call
+// into C is `unsafe`. Wrap it in a safe function
+fn add(a: i32, b: i32) -> i32 {
+    // SAFETY: `fixture_add` only reads its two arguments
+    unsafe { fixture_add(a, b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn calls_into_c() {
+        assert_eq!(unsafe { fixture_add(2, 3) }, 5);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn safe_wrapper() {
+        assert_eq!(add(-4, 10), 6);
+    }
+}
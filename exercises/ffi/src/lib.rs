@@ -0,0 +1,5 @@
+#![allow(dead_code)]
+
+mod calling_c;
+mod strings;
+mod callbacks;
@@ -0,0 +1,71 @@
+use std::ffi::{c_char, CStr, CString, NulError};
+use std::os::raw::c_int;
+
+#[link(name = "fixture", kind = "static")]
+extern "C" {
+    fn fixture_count_vowels(text: *const c_char) -> usize;
+    fn fixture_greeting() -> *const c_char;
+    fn fixture_shout(text: *const c_char, out: *mut c_char, capacity: usize) -> c_int;
+}
+
+// Exercise 1
+// C strings end with a nul byte and cannot contain one. Turn `text` into a
+// `CString` to pass it to C, reporting the error for text with a `\0` in it
+fn count_vowels(text: &str) -> Result<usize, NulError> {
+    let text = CString::new(text)?;
+    // SAFETY: `text` is a valid nul-terminated string for the whole call
+    Ok(unsafe { fixture_count_vowels(text.as_ptr()) })
+}
+
+// Exercise 2
+// `fixture_greeting` returns a pointer to a string owned by C. Borrow it as
+// a `CStr` and copy it into a `String`
+fn greeting() -> String {
+    // SAFETY: the pointer is to a static nul-terminated string
+    let greeting = unsafe { CStr::from_ptr(fixture_greeting()) };
+    greeting.to_string_lossy().into_owned()
+}
+
+// Exercise 3
+// `fixture_shout` writes its result into a buffer the caller provides.
+// Give it a buffer of `capacity` bytes and read the answer back, returning
+// `None` when C reports that the result did not fit
+fn shout(text: &str, capacity: usize) -> Option<String> {
+    let text = CString::new(text).ok()?;
+    let mut buffer = vec![0 as c_char; capacity];
+    // SAFETY: `buffer` holds `capacity` bytes and C writes at most that many
+    let written = unsafe { fixture_shout(text.as_ptr(), buffer.as_mut_ptr(), capacity) };
+    if written < 0 {
+        return None;
+    }
+    // SAFETY: on success C nul-terminated the buffer
+    let shouted = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+    Some(shouted.to_str().ok()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn passes_strings_to_c() {
+        assert_eq!(count_vowels("Rust Bootcamp"), Ok(4));
+        assert_eq!(count_vowels(""), Ok(0));
+        assert!(count_vowels("nul\0byte").is_err());
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn reads_strings_from_c() {
+        assert_eq!(greeting(), "hello from C");
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn fills_a_buffer() {
+        assert_eq!(shout("hey there", 16), Some("HEY THERE".to_string()));
+        assert_eq!(shout("hey there", 10), Some("HEY THERE".to_string()));
+        assert_eq!(shout("hey there", 9), None);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+
+// Exercise 1
+// `Cache` remembers the results of a slow function and is shared between
+// threads through `&Cache`. A `RefCell<HashMap<..>>` compiles on one thread
+// but is not `Sync`, and `Cell` only works for `Copy` values. Pick the
+// primitive for `entries`, and another for `computed`, so the tests below
+// compile and pass
+struct Cache {
+    entries: Mutex<HashMap<u64, u64>>,
+    computed: AtomicU32,
+}
+
+impl Cache {
+    fn new() -> Self {
+        Cache {
+            entries: Mutex::new(HashMap::new()),
+            computed: AtomicU32::new(0),
+        }
+    }
+
+    // Exercise 2
+    // Return the cached value for `n`, computing and storing it on a miss.
+    // Don't hold the lock while computing, other threads may want to read
+    fn get(&self, n: u64) -> u64 {
+        if let Some(value) = self.entries.lock().unwrap().get(&n) {
+            return *value;
+        }
+        let value = slow_square(n);
+        self.computed.fetch_add(1, Ordering::SeqCst);
+        *self.entries.lock().unwrap().entry(n).or_insert(value)
+    }
+}
+
+fn slow_square(n: u64) -> u64 {
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    n * n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn assert_sync<T: Sync>() {}
+
+    // Test for exercise 1
+    #[test]
+    fn cache_is_sync() {
+        assert_sync::<Cache>();
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn hits_do_not_recompute() {
+        let cache = Cache::new();
+        assert_eq!(cache.get(4), 16);
+        assert_eq!(cache.get(4), 16);
+        assert_eq!(cache.computed.load(Ordering::SeqCst), 1);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn shared_between_threads() {
+        let cache = Cache::new();
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for n in 0..10 {
+                        assert_eq!(cache.get(n), n * n);
+                    }
+                });
+            }
+        });
+        assert_eq!(cache.entries.lock().unwrap().len(), 10);
+        // Threads can race on a miss, but never compute more than once each
+        let computed = cache.computed.load(Ordering::SeqCst);
+        assert!((10..=80).contains(&computed), "{computed}");
+    }
+}
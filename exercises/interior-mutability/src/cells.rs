@@ -0,0 +1,89 @@
+use std::cell::{Cell, RefCell};
+
+// Exercise 1
+// `Cell` swaps whole `Copy` values in and out, with no borrows to track.
+// Make `visit` count its calls through `&self`
+struct Counter {
+    visits: Cell<u32>,
+}
+
+impl Counter {
+    fn visit(&self) -> u32 {
+        self.visits.set(self.visits.get() + 1);
+        self.visits.get()
+    }
+}
+
+// Exercise 2
+// `RefCell` hands out references instead, checking the borrow rules at
+// runtime. Make `log` append to the history through `&self`
+struct Logger {
+    history: RefCell<Vec<String>>,
+}
+
+impl Logger {
+    fn log(&self, message: &str) {
+        self.history.borrow_mut().push(message.to_string());
+    }
+
+    fn last(&self) -> Option<String> {
+        self.history.borrow().last().cloned()
+    }
+}
+
+// Exercise 3
+// Holding a `Ref` while asking for a `RefMut` panics. `try_borrow_mut`
+// reports the conflict instead. Return `false` when the history is
+// already borrowed
+fn try_log(logger: &Logger, message: &str) -> bool {
+    match logger.history.try_borrow_mut() {
+        Ok(mut history) => {
+            history.push(message.to_string());
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn cell_counts_through_shared_references() {
+        let counter = Counter {
+            visits: Cell::new(0),
+        };
+        let (a, b) = (&counter, &counter);
+        assert_eq!(a.visit(), 1);
+        assert_eq!(b.visit(), 2);
+        assert_eq!(counter.visits.get(), 2);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn refcell_appends_through_shared_references() {
+        let logger = Logger {
+            history: RefCell::new(Vec::new()),
+        };
+        logger.log("started");
+        logger.log("stopped");
+        assert_eq!(logger.last(), Some("stopped".to_string()));
+        assert_eq!(logger.history.borrow().len(), 2);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn conflicting_borrows_are_reported() {
+        let logger = Logger {
+            history: RefCell::new(Vec::new()),
+        };
+        assert!(try_log(&logger, "first"));
+        let reading = logger.history.borrow();
+        assert!(!try_log(&logger, "second"));
+        drop(reading);
+        assert!(try_log(&logger, "third"));
+        assert_eq!(*logger.history.borrow(), vec!["first", "third"]);
+    }
+}
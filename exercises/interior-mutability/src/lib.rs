@@ -0,0 +1,5 @@
+#![allow(dead_code)]
+
+mod cells;
+mod once;
+mod cache;
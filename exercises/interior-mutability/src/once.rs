@@ -0,0 +1,81 @@
+use std::cell::{Cell, OnceCell};
+use std::collections::HashMap;
+use std::sync::{LazyLock, OnceLock};
+
+// Exercise 1
+// `OnceCell` starts empty and can be filled a single time. Compute the
+// report on first use only, counting how often `build` really runs
+struct Report {
+    text: OnceCell<String>,
+    builds: Cell<u32>,
+}
+
+impl Report {
+    fn get(&self) -> &str {
+        self.text.get_or_init(|| {
+            self.builds.set(self.builds.get() + 1);
+            "quarterly numbers".to_string()
+        })
+    }
+}
+
+// Exercise 2
+// `OnceLock` is the thread-safe version, so it can live in a `static`.
+// The first call to `set_greeting` wins, later ones get their value back
+static GREETING: OnceLock<String> = OnceLock::new();
+
+fn set_greeting(greeting: &str) -> Result<(), String> {
+    GREETING.set(greeting.to_string())
+}
+
+// Exercise 3
+// `LazyLock` bundles the initializer with the value. Build the lookup
+// table the first time it is used
+static UNITS: LazyLock<HashMap<&'static str, u32>> =
+    LazyLock::new(|| HashMap::from([("second", 1), ("minute", 60), ("hour", 3600)]));
+
+fn seconds(amount: u32, unit: &str) -> Option<u32> {
+    UNITS.get(unit).map(|factor| amount * factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn once_cell_builds_once() {
+        let report = Report {
+            text: OnceCell::new(),
+            builds: Cell::new(0),
+        };
+        assert_eq!(report.get(), "quarterly numbers");
+        assert_eq!(report.get(), "quarterly numbers");
+        assert_eq!(report.builds.get(), 1);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn first_greeting_wins() {
+        let handles: Vec<_> = ["hello", "xin chào"]
+            .into_iter()
+            .map(|greeting| std::thread::spawn(move || set_greeting(greeting)))
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        let winner = GREETING.get().unwrap();
+        assert!(results.contains(&Err(if winner == "hello" {
+            "xin chào".to_string()
+        } else {
+            "hello".to_string()
+        })));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn lazy_table() {
+        assert_eq!(seconds(2, "minute"), Some(120));
+        assert_eq!(seconds(1, "hour"), Some(3600));
+        assert_eq!(seconds(1, "fortnight"), None);
+    }
+}
@@ -0,0 +1,73 @@
+// Exercise 1
+// Make me compile!
+// Return the longer of the two string slices, the first one on a tie
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if y.len() > x.len() {
+        y
+    } else {
+        x
+    }
+}
+
+// Exercise 2
+// Input: a sentence
+// Output: its first word, borrowed from the sentence (the whole sentence if it has no space)
+fn first_word(sentence: &str) -> &str {
+    match sentence.find(' ') {
+        Some(end) => &sentence[..end],
+        None => sentence,
+    }
+}
+
+// Exercise 3
+// The returned reference only ever comes from `text`, never from `prefix`.
+// Give `prefix` its own lifetime so the test can drop it early
+fn strip<'a>(text: &'a str, prefix: &str) -> &'a str {
+    text.strip_prefix(prefix).unwrap_or(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn longest_works() {
+        assert_eq!(longest("rust", "go"), "rust");
+        assert_eq!(longest("c", "java"), "java");
+        assert_eq!(longest("abc", "xyz"), "abc");
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn longest_within_a_scope() {
+        let outer = String::from("a long string");
+        let result;
+        {
+            let inner = String::from("short");
+            result = longest(outer.as_str(), inner.as_str()).to_string();
+        }
+        assert_eq!(result, "a long string");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn first_word_works() {
+        assert_eq!(first_word("hello world"), "hello");
+        assert_eq!(first_word("rust"), "rust");
+        assert_eq!(first_word(""), "");
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn strip_outlives_prefix() {
+        let text = String::from("Mr. Smith");
+        let stripped;
+        {
+            let prefix = String::from("Mr. ");
+            stripped = strip(&text, &prefix);
+        }
+        assert_eq!(stripped, "Smith");
+        assert_eq!(strip("Smith", "Mr. "), "Smith");
+    }
+}
@@ -0,0 +1,100 @@
+// Exercise 1
+// The compiler can figure out the lifetimes of this function on its own.
+// Remove the explicit annotations and keep the tests passing
+fn trim_dots(text: &str) -> &str {
+    text.trim_matches('.')
+}
+
+// Exercise 2
+// Methods taking `&self` return references tied to `self` by default.
+// Return the name of the longest named player
+struct Team {
+    players: Vec<String>,
+}
+
+impl Team {
+    fn longest_name(&self) -> Option<&str> {
+        let mut longest: Option<&str> = None;
+        for player in &self.players {
+            if longest.is_none_or(|name| player.len() > name.len()) {
+                longest = Some(player);
+            }
+        }
+        longest
+    }
+}
+
+// Exercise 3
+// Make me compile!
+// Elision cannot pick between two inputs: say which one the result borrows from
+fn pick<'a>(first: &'a str, _second: &str, use_first: bool) -> &'a str {
+    if use_first {
+        first
+    } else {
+        "neither"
+    }
+}
+
+// Exercise 4
+// Input: an HTTP status code
+// Output: its reason phrase. Nothing is borrowed from the input, so the
+// result must live for the whole program
+fn reason(code: u16) -> &'static str {
+    match code {
+        200 => "OK",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn trim_dots_works() {
+        assert_eq!(trim_dots("...wait..."), "wait");
+        assert_eq!(trim_dots("done"), "done");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn longest_name_works() {
+        let team = Team {
+            players: vec![
+                "Ann".to_string(),
+                "Bartholomew".to_string(),
+                "Cy".to_string(),
+            ],
+        };
+        assert_eq!(team.longest_name(), Some("Bartholomew"));
+        assert_eq!(Team { players: vec![] }.longest_name(), None);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn pick_works() {
+        let first = String::from("first");
+        let picked;
+        {
+            let second = String::from("second");
+            picked = pick(&first, &second, true);
+        }
+        assert_eq!(picked, "first");
+        assert_eq!(pick("a", "b", false), "neither");
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn reason_outlives_everything() {
+        let phrase;
+        {
+            let code = 404;
+            phrase = reason(code);
+        }
+        assert_eq!(phrase, "Not Found");
+        assert_eq!(reason(418), "Unknown");
+    }
+}
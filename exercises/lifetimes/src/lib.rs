@@ -0,0 +1,5 @@
+#![allow(dead_code)]
+
+mod annotations;
+mod struct_refs;
+mod elision;
@@ -0,0 +1,105 @@
+// Exercise 1
+// Make me compile!
+// An `Excerpt` borrows part of a book instead of copying it
+struct Excerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> Excerpt<'a> {
+    // Return the excerpt's first sentence, ending at the first '.' (without it)
+    fn first_sentence(&self) -> &'a str {
+        self.part.split('.').next().unwrap_or(self.part)
+    }
+}
+
+// Exercise 2
+// Input: a line like "key=value"
+// Output: a `Pair` borrowing both halves of the line, or None without a '='
+#[derive(Debug, PartialEq)]
+struct Pair<'a> {
+    key: &'a str,
+    value: &'a str,
+}
+
+fn parse_pair(line: &str) -> Option<Pair<'_>> {
+    let (key, value) = line.split_once('=')?;
+    Some(Pair {
+        key: key.trim(),
+        value: value.trim(),
+    })
+}
+
+// Exercise 3
+// A `Words` walks over the words of a text one at a time, returning slices of
+// the original text. Implement `next_word`
+struct Words<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Words<'a> {
+    fn new(text: &'a str) -> Self {
+        Words { rest: text }
+    }
+
+    fn next_word(&mut self) -> Option<&'a str> {
+        let trimmed = self.rest.trim_start();
+        if trimmed.is_empty() {
+            return None;
+        }
+        let end = trimmed.find(' ').unwrap_or(trimmed.len());
+        let (word, rest) = trimmed.split_at(end);
+        self.rest = rest;
+        Some(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn excerpt_first_sentence() {
+        let novel = String::from("Call me Ishmael. Some years ago...");
+        let excerpt = Excerpt { part: &novel };
+        assert_eq!(excerpt.first_sentence(), "Call me Ishmael");
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn sentence_outlives_excerpt() {
+        let novel = String::from("No dots here");
+        let sentence;
+        {
+            let excerpt = Excerpt { part: &novel };
+            sentence = excerpt.first_sentence();
+        }
+        assert_eq!(sentence, "No dots here");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn parse_pair_works() {
+        assert_eq!(
+            parse_pair("name = ferris"),
+            Some(Pair {
+                key: "name",
+                value: "ferris"
+            })
+        );
+        assert_eq!(parse_pair("no separator"), None);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn words_borrow_the_text() {
+        let text = String::from("  hello   borrow checker ");
+        let mut words = Words::new(&text);
+        let first = words.next_word();
+        let second = words.next_word();
+        assert_eq!(first, Some("hello"));
+        assert_eq!(second, Some("borrow"));
+        assert_eq!(words.next_word(), Some("checker"));
+        assert_eq!(words.next_word(), None);
+    }
+}
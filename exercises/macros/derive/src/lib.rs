@@ -0,0 +1,93 @@
+//! Derive macros for the macros exercises, written against the standard
+//! `proc_macro` API only.
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+// Exercise 1
+// A derive macro receives the tokens of the item it is attached to and
+// returns new items to add next to it. Walk the tokens to find the struct's
+// name and its `{ ... }` body, collect the field names and emit
+//
+//     impl Name {
+//         pub fn field_names() -> &'static [&'static str] {
+//             &["first", "second"]
+//         }
+//     }
+//
+// Anything else, like an enum or a tuple struct, is reported with
+// `compile_error!`
+
+#[proc_macro_derive(FieldNames)]
+pub fn derive_field_names(input: TokenStream) -> TokenStream {
+    let expanded = match parse_struct(input) {
+        Ok((name, fields)) => {
+            let names: Vec<String> = fields.iter().map(|field| format!("{field:?}")).collect();
+            format!(
+                "impl {name} {{ pub fn field_names() -> &'static [&'static str] {{ &[{}] }} }}",
+                names.join(", ")
+            )
+        }
+        Err(message) => format!("compile_error!({message:?});"),
+    };
+    expanded.parse().expect("generated code is valid Rust")
+}
+
+// The name of a struct with named fields and its field names
+fn parse_struct(input: TokenStream) -> Result<(String, Vec<String>), String> {
+    let mut tokens = input.into_iter();
+    // Skip attributes and visibility up to the `struct` keyword
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => break,
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "enum" => {
+                return Err("`FieldNames` only supports structs".to_string());
+            }
+            Some(_) => {}
+            None => return Err("expected a struct".to_string()),
+        }
+    }
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name.to_string(),
+        _ => return Err("expected the name of the struct".to_string()),
+    };
+    match tokens.next() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => {
+            Ok((name, field_names(body.stream())))
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+            Err("`FieldNames` does not support generic structs".to_string())
+        }
+        _ => Err("`FieldNames` needs a struct with named fields".to_string()),
+    }
+}
+
+// Fields are `attributes visibility name: type`, separated by commas that
+// are not nested inside the `<...>` of a type
+fn field_names(body: TokenStream) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut last_ident = None;
+    let mut in_type = false;
+    let mut depth = 0;
+    for token in body {
+        match token {
+            TokenTree::Punct(punct) => match punct.as_char() {
+                ':' if !in_type => {
+                    in_type = true;
+                    names.extend(last_ident.take());
+                }
+                '<' => depth += 1,
+                // Skip the `>` of `->` in function pointer types
+                '>' if depth > 0 => depth -= 1,
+                ',' if depth == 0 => in_type = false,
+                _ => {}
+            },
+            TokenTree::Ident(ident) if !in_type => {
+                let ident = ident.to_string();
+                let ident = ident.strip_prefix("r#").unwrap_or(&ident);
+                last_ident = Some(ident.to_string());
+            }
+            _ => {}
+        }
+    }
+    names
+}
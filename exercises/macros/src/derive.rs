@@ -0,0 +1,50 @@
+use macros_derive::FieldNames;
+
+// Exercise 1
+// `#[derive(FieldNames)]` on a struct with named fields adds
+// `fn field_names() -> &'static [&'static str]`, listing the fields in
+// declaration order. Write it in `exercises/macros/derive/src/lib.rs`
+
+// The fields are only ever listed, never read
+#[allow(dead_code)]
+#[derive(FieldNames)]
+struct Account {
+    owner: String,
+    balance: i64,
+}
+
+#[derive(FieldNames)]
+struct Empty {}
+
+// Types with commas and attributes must not confuse the parser
+#[allow(dead_code)]
+#[derive(FieldNames)]
+pub struct Tricky {
+    /// Doc comments are attributes too
+    pub(crate) pairs: std::collections::HashMap<String, (u8, u8)>,
+    r#type: fn(u8, u8) -> u8,
+    last: Vec<Vec<char>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn lists_fields_in_order() {
+        assert_eq!(Account::field_names(), ["owner", "balance"]);
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn no_fields() {
+        assert!(Empty::field_names().is_empty());
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn skips_attributes_visibility_and_types() {
+        assert_eq!(Tricky::field_names(), ["pairs", "type", "last"]);
+    }
+}
@@ -0,0 +1,5 @@
+#![allow(dead_code, unused_macros)]
+
+mod hashmap;
+mod state;
+mod derive;
@@ -0,0 +1,5 @@
+#![allow(dead_code, unused_imports)]
+
+mod visibility;
+mod use_paths;
+mod library;
@@ -0,0 +1,19 @@
+// Exercise 1
+// The `Book` type moved here from `library.rs`
+pub struct Book {
+    title: String,
+    pub(crate) copies: u32,
+}
+
+impl Book {
+    pub fn new(title: &str, copies: u32) -> Book {
+        Book {
+            title: title.to_string(),
+            copies,
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+}
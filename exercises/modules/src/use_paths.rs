@@ -0,0 +1,75 @@
+mod shapes {
+    pub mod circle {
+        pub fn area(radius: f64) -> f64 {
+            super::PI_ISH * radius * radius
+        }
+    }
+
+    pub mod square {
+        pub fn area(side: f64) -> f64 {
+            side * side
+        }
+    }
+
+    const PI_ISH: f64 = 3.0;
+}
+
+// Exercise 1
+// Make me compile!
+// Bring both `area` functions into scope. They have the same name,
+// so rename them with `as`
+use shapes::circle::area as circle_area;
+use shapes::square::area as square_area;
+
+fn total(radius: f64, side: f64) -> f64 {
+    circle_area(radius) + square_area(side)
+}
+
+// Exercise 2
+// Re-export: users of `prelude` should reach everything they need through it
+// without knowing where it is defined
+mod prelude {
+    pub use super::shapes::circle;
+    pub use super::shapes::square::area as square_area;
+    pub use std::collections::HashMap as Map;
+}
+
+// Exercise 3
+// Use one nested `use` with braces instead of several lines
+use std::fmt::{self, Display};
+
+struct Meters(f64);
+
+impl Display for Meters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}m", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn renamed_imports() {
+        assert_eq!(total(1.0, 2.0), 7.0);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn call_through_the_prelude() {
+        use super::prelude::{circle, square_area, Map};
+        let mut areas = Map::new();
+        areas.insert("circle", circle::area(2.0));
+        areas.insert("square", square_area(3.0));
+        assert_eq!(areas["circle"], 12.0);
+        assert_eq!(areas["square"], 9.0);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn nested_use() {
+        assert_eq!(Meters(1.5).to_string(), "1.5m");
+    }
+}
@@ -0,0 +1,73 @@
+use std::ops::{Index, IndexMut};
+
+// A grid of cells stored row after row in one `Vec`
+struct Grid {
+    width: usize,
+    cells: Vec<char>,
+}
+
+impl Grid {
+    fn new(width: usize, height: usize) -> Grid {
+        Grid {
+            width,
+            cells: vec!['.'; width * height],
+        }
+    }
+
+    fn rows(&self) -> Vec<String> {
+        self.cells
+            .chunks(self.width)
+            .map(|row| row.iter().collect())
+            .collect()
+    }
+}
+
+// Exercise 1
+// Implement `Index<(usize, usize)>` so `grid[(x, y)]` reads a cell.
+// Like slices, indexing out of bounds panics
+impl Index<(usize, usize)> for Grid {
+    type Output = char;
+
+    fn index(&self, (x, y): (usize, usize)) -> &char {
+        assert!(x < self.width, "column {x} is out of the grid");
+        &self.cells[y * self.width + x]
+    }
+}
+
+// Exercise 2
+// Implement `IndexMut` so `grid[(x, y)] = '#'` writes a cell
+impl IndexMut<(usize, usize)> for Grid {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut char {
+        assert!(x < self.width, "column {x} is out of the grid");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn read_cells() {
+        let grid = Grid::new(3, 2);
+        assert_eq!(grid[(2, 1)], '.');
+    }
+
+    // Test for exercise 1
+    #[test]
+    #[should_panic(expected = "out of the grid")]
+    fn column_out_of_bounds() {
+        let grid = Grid::new(3, 2);
+        let _ = grid[(3, 0)];
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn write_cells() {
+        let mut grid = Grid::new(3, 2);
+        grid[(0, 0)] = '#';
+        grid[(2, 1)] = '@';
+        assert_eq!(grid.rows(), vec!["#..", "..@"]);
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+mod money;
+mod indexing;
+mod deref;
+mod ordering;
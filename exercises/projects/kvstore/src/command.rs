@@ -0,0 +1,103 @@
+//! Stage 2: text commands.
+
+use crate::store::Store;
+use std::fmt;
+
+// Stage 2
+// A command is one line of text: a verb in any case, then its arguments
+// separated by spaces. A value is the rest of the line, so it may contain
+// spaces itself:
+//
+//     SET name Ferris the crab
+//     GET name
+//     DEL name
+//     KEYS
+//
+// `run` applies a command to a store and returns the reply to print
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Set { key: String, value: String },
+    Get { key: String },
+    Del { key: String },
+    Keys,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand(String),
+    /// The command is missing arguments or has too many, with the usage
+    Usage(&'static str),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty command"),
+            ParseError::UnknownCommand(verb) => write!(f, "unknown command `{verb}`"),
+            ParseError::Usage(usage) => write!(f, "usage: {usage}"),
+        }
+    }
+}
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Command, ParseError> {
+        let line = line.trim();
+        let (verb, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let rest = rest.trim_start();
+        // A single key, with nothing after it
+        let key = |usage| match rest {
+            "" => Err(ParseError::Usage(usage)),
+            key if key.contains(' ') => Err(ParseError::Usage(usage)),
+            key => Ok(key.to_string()),
+        };
+
+        match verb.to_uppercase().as_str() {
+            "" => Err(ParseError::Empty),
+            "SET" => match rest.split_once(' ') {
+                Some((key, value)) => Ok(Command::Set {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                }),
+                None => Err(ParseError::Usage("SET <key> <value>")),
+            },
+            "GET" => Ok(Command::Get {
+                key: key("GET <key>")?,
+            }),
+            "DEL" => Ok(Command::Del {
+                key: key("DEL <key>")?,
+            }),
+            "KEYS" if rest.is_empty() => Ok(Command::Keys),
+            "KEYS" => Err(ParseError::Usage("KEYS")),
+            _ => Err(ParseError::UnknownCommand(verb.to_string())),
+        }
+    }
+
+    /// Apply the command to `store` and return the reply
+    pub fn run(&self, store: &mut Store) -> String {
+        match self {
+            Command::Set { key, value } => {
+                store.set(key, value);
+                "OK".to_string()
+            }
+            Command::Get { key } => store.get(key).unwrap_or("(nil)").to_string(),
+            Command::Del { key } => match store.remove(key) {
+                Some(_) => "1".to_string(),
+                None => "0".to_string(),
+            },
+            Command::Keys => store.keys().join("\n"),
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Set { key, value } => write!(f, "SET {key} {value}"),
+            Command::Get { key } => write!(f, "GET {key}"),
+            Command::Del { key } => write!(f, "DEL {key}"),
+            Command::Keys => write!(f, "KEYS"),
+        }
+    }
+}
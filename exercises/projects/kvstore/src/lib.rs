@@ -0,0 +1,26 @@
+//! A key-value store, built in three stages:
+//!
+//! 1. [`store`]: keep the data in memory
+//! 2. [`command`]: parse and run text commands like `SET name Ferris`
+//! 3. [`file`]: keep the data in a file, so it survives a restart
+//!
+//! Each stage has its own test suite in `tests/`, and the runner moves on to
+//! the next stage once the current one passes.
+
+// `--features solutions` builds the reference solutions instead, so they are
+// checked by the same test suites
+#[cfg(not(feature = "solutions"))]
+pub mod command;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/kvstore/src/command.rs"]
+pub mod command;
+#[cfg(not(feature = "solutions"))]
+pub mod file;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/kvstore/src/file.rs"]
+pub mod file;
+#[cfg(not(feature = "solutions"))]
+pub mod store;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/kvstore/src/store.rs"]
+pub mod store;
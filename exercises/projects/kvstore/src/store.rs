@@ -0,0 +1,46 @@
+//! Stage 1: an in-memory store.
+
+use std::collections::BTreeMap;
+
+// Stage 1
+// Keep the entries in a map. `keys` lists them in sorted order, which a
+// `BTreeMap` gives you for free
+
+/// Text keys mapped to text values, kept in memory.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Store {
+    entries: BTreeMap<String, String>,
+}
+
+impl Store {
+    pub fn new() -> Self {
+        Store::default()
+    }
+
+    /// Store `value` under `key`, returning the value it replaced
+    pub fn set(&mut self, key: &str, value: &str) -> Option<String> {
+        self.entries.insert(key.to_string(), value.to_string())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// Remove `key`, returning its value if it was there
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.entries.remove(key)
+    }
+
+    /// Every key, in sorted order
+    pub fn keys(&self) -> Vec<&str> {
+        self.entries.keys().map(String::as_str).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
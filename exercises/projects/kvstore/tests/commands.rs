@@ -0,0 +1,84 @@
+// Stage 2 of the key-value store project
+use kvstore::command::{Command, ParseError};
+use kvstore::store::Store;
+
+fn set(key: &str, value: &str) -> Command {
+    Command::Set {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn parses_every_command() {
+    assert_eq!(Command::parse("SET name Ferris"), Ok(set("name", "Ferris")));
+    assert_eq!(
+        Command::parse("get name"),
+        Ok(Command::Get {
+            key: "name".to_string()
+        })
+    );
+    assert_eq!(
+        Command::parse("Del name"),
+        Ok(Command::Del {
+            key: "name".to_string()
+        })
+    );
+    assert_eq!(Command::parse("  KEYS  "), Ok(Command::Keys));
+}
+
+#[test]
+fn values_keep_their_spaces() {
+    assert_eq!(
+        Command::parse("SET motto  borrow, don't copy"),
+        Ok(set("motto", " borrow, don't copy"))
+    );
+}
+
+#[test]
+fn rejects_bad_commands() {
+    assert_eq!(Command::parse(""), Err(ParseError::Empty));
+    assert_eq!(
+        Command::parse("PUT a b"),
+        Err(ParseError::UnknownCommand("PUT".to_string()))
+    );
+    assert_eq!(
+        Command::parse("SET lonely"),
+        Err(ParseError::Usage("SET <key> <value>"))
+    );
+    assert_eq!(Command::parse("GET"), Err(ParseError::Usage("GET <key>")));
+    assert_eq!(
+        Command::parse("DEL a b"),
+        Err(ParseError::Usage("DEL <key>"))
+    );
+    assert_eq!(Command::parse("KEYS a"), Err(ParseError::Usage("KEYS")));
+    assert_eq!(
+        ParseError::Usage("GET <key>").to_string(),
+        "usage: GET <key>"
+    );
+}
+
+#[test]
+fn runs_a_session() {
+    let mut store = Store::new();
+    let replies: Vec<String> = [
+        "SET b 2", "SET a 1", "GET a", "GET c", "KEYS", "DEL b", "DEL b",
+    ]
+    .iter()
+    .map(|line| Command::parse(line).unwrap().run(&mut store))
+    .collect();
+    assert_eq!(replies, ["OK", "OK", "1", "(nil)", "a\nb", "1", "0"]);
+}
+
+#[test]
+fn display_parses_back() {
+    for command in [
+        set("k", "a value"),
+        Command::Get {
+            key: "k".to_string(),
+        },
+        Command::Keys,
+    ] {
+        assert_eq!(Command::parse(&command.to_string()), Ok(command));
+    }
+}
@@ -0,0 +1,86 @@
+// Stage 3 of the key-value store project
+use kvstore::file::FileStore;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// A fresh path in the temporary directory, removed again when dropped
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new() -> TempFile {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "kvstore-{}-{}.log",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::SeqCst)
+        );
+        let path = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_file(&path);
+        TempFile(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn survives_a_restart() {
+    let file = TempFile::new();
+    {
+        let mut store = FileStore::open(&file.0).unwrap();
+        store.set("name", "Ferris").unwrap();
+        store.set("lang", "Rust").unwrap();
+        assert!(store.remove("lang").unwrap());
+        assert!(!store.remove("lang").unwrap());
+    }
+    let store = FileStore::open(&file.0).unwrap();
+    assert_eq!(store.get("name"), Some("Ferris"));
+    assert_eq!(store.get("lang"), None);
+}
+
+#[test]
+fn the_file_is_a_log_of_changes() {
+    let file = TempFile::new();
+    let mut store = FileStore::open(&file.0).unwrap();
+    store.set("a", "1").unwrap();
+    store.set("a", "2 and more").unwrap();
+    store.remove("a").unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&file.0).unwrap(),
+        "SET a 1\nSET a 2 and more\nDEL a\n"
+    );
+}
+
+#[test]
+fn compact_keeps_only_what_is_left() {
+    let file = TempFile::new();
+    let mut store = FileStore::open(&file.0).unwrap();
+    for value in ["1", "2", "3"] {
+        store.set("counter", value).unwrap();
+    }
+    store.set("gone", "soon").unwrap();
+    store.remove("gone").unwrap();
+    store.compact().unwrap();
+    assert_eq!(std::fs::read_to_string(&file.0).unwrap(), "SET counter 3\n");
+
+    // The store keeps logging to the new file
+    store.set("after", "compact").unwrap();
+    drop(store);
+    let store = FileStore::open(&file.0).unwrap();
+    assert_eq!(store.keys(), ["after", "counter"]);
+}
+
+#[test]
+fn corrupt_files_are_reported() {
+    let file = TempFile::new();
+    std::fs::write(&file.0, "SET a 1\nFROB a\n").unwrap();
+    let error = FileStore::open(&file.0).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().starts_with("line 2:"), "{error}");
+
+    std::fs::write(&file.0, "GET a\n").unwrap();
+    assert!(FileStore::open(&file.0).is_err());
+}
@@ -0,0 +1,39 @@
+// Stage 1 of the key-value store project
+use kvstore::store::Store;
+
+#[test]
+fn set_and_get() {
+    let mut store = Store::new();
+    assert!(store.is_empty());
+    assert_eq!(store.set("name", "Ferris"), None);
+    assert_eq!(store.get("name"), Some("Ferris"));
+    assert_eq!(store.get("missing"), None);
+    assert_eq!(store.len(), 1);
+}
+
+#[test]
+fn set_replaces() {
+    let mut store = Store::new();
+    store.set("lang", "C");
+    assert_eq!(store.set("lang", "Rust"), Some("C".to_string()));
+    assert_eq!(store.get("lang"), Some("Rust"));
+    assert_eq!(store.len(), 1);
+}
+
+#[test]
+fn remove() {
+    let mut store = Store::new();
+    store.set("a", "1");
+    assert_eq!(store.remove("a"), Some("1".to_string()));
+    assert_eq!(store.remove("a"), None);
+    assert!(store.is_empty());
+}
+
+#[test]
+fn keys_are_sorted() {
+    let mut store = Store::new();
+    for key in ["pear", "apple", "mango"] {
+        store.set(key, "fruit");
+    }
+    assert_eq!(store.keys(), ["apple", "mango", "pear"]);
+}
@@ -0,0 +1,27 @@
+//! A small `grep`, built in three stages like chapter 12 of the Rust book:
+//!
+//! 1. [`config`]: read the query, the file and the options from the arguments
+//!    and the environment
+//! 2. [`search`]: find the matching lines, with or without case
+//! 3. [`run`]: put it together, reading the file and reporting errors
+//!
+//! Each stage has its own test suite in `tests/`, and the runner moves on to
+//! the next stage once the current one passes.
+
+// `--features solutions` builds the reference solutions instead, so they are
+// checked by the same test suites
+#[cfg(not(feature = "solutions"))]
+pub mod config;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/minigrep/src/config.rs"]
+pub mod config;
+#[cfg(not(feature = "solutions"))]
+pub mod run;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/minigrep/src/run.rs"]
+pub mod run;
+#[cfg(not(feature = "solutions"))]
+pub mod search;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/minigrep/src/search.rs"]
+pub mod search;
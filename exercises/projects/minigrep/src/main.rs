@@ -0,0 +1,25 @@
+use minigrep::config::Config;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let config = match Config::build(std::env::args().skip(1), |key| std::env::var(key).ok()) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("minigrep: {err}");
+            eprintln!("{}", minigrep::config::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match minigrep::run::run(&config) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{line}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("minigrep: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Stage 1 of the minigrep project
+use minigrep::config::{Config, ConfigError};
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+fn no_env(_: &str) -> Option<String> {
+    None
+}
+
+#[test]
+fn query_and_file() {
+    assert_eq!(
+        Config::build(args("frog poem.txt"), no_env),
+        Ok(Config {
+            query: "frog".to_string(),
+            file_path: "poem.txt".to_string(),
+            ignore_case: false,
+        })
+    );
+}
+
+#[test]
+fn missing_and_extra_arguments() {
+    assert_eq!(
+        Config::build(args(""), no_env),
+        Err(ConfigError::MissingQuery)
+    );
+    assert_eq!(
+        Config::build(args("frog"), no_env),
+        Err(ConfigError::MissingFile)
+    );
+    assert_eq!(
+        Config::build(args("frog poem.txt extra"), no_env),
+        Err(ConfigError::UnexpectedArgument("extra".to_string()))
+    );
+    assert_eq!(
+        Config::build(args("--loud frog poem.txt"), no_env),
+        Err(ConfigError::UnknownOption("--loud".to_string()))
+    );
+}
+
+#[test]
+fn ignore_case_flag_anywhere() {
+    for line in [
+        "-i frog poem.txt",
+        "frog --ignore-case poem.txt",
+        "frog poem.txt -i",
+    ] {
+        let config = Config::build(args(line), no_env).unwrap();
+        assert!(config.ignore_case, "{line}");
+        assert_eq!(config.query, "frog");
+    }
+}
+
+#[test]
+fn ignore_case_from_the_environment() {
+    let env =
+        |value: &'static str| move |key: &str| (key == "IGNORE_CASE").then(|| value.to_string());
+    assert!(
+        Config::build(args("frog poem.txt"), env("1"))
+            .unwrap()
+            .ignore_case
+    );
+    assert!(
+        Config::build(args("frog poem.txt"), env(""))
+            .unwrap()
+            .ignore_case
+    );
+    assert!(
+        !Config::build(args("frog poem.txt"), env("0"))
+            .unwrap()
+            .ignore_case
+    );
+    // The flag wins over the environment
+    assert!(
+        Config::build(args("-i frog poem.txt"), env("0"))
+            .unwrap()
+            .ignore_case
+    );
+}
+
+#[test]
+fn errors_read_well() {
+    assert_eq!(
+        ConfigError::MissingFile.to_string(),
+        "missing the file to search"
+    );
+}
@@ -0,0 +1,46 @@
+// Stage 3 of the minigrep project
+use minigrep::config::Config;
+use minigrep::run::run;
+
+fn config(query: &str, file_path: &str, ignore_case: bool) -> Config {
+    Config {
+        query: query.to_string(),
+        file_path: file_path.to_string(),
+        ignore_case,
+    }
+}
+
+fn poem() -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("minigrep-{}.txt", std::process::id()));
+    std::fs::write(&path, "I'm nobody! Who are you?\nAre you nobody, too?\n").unwrap();
+    path
+}
+
+#[test]
+fn searches_a_file() {
+    let path = poem();
+    let path = path.to_str().unwrap();
+    assert_eq!(
+        run(&config("nobody", path, false)).unwrap(),
+        ["I'm nobody! Who are you?", "Are you nobody, too?"]
+    );
+    assert_eq!(
+        run(&config("are", path, false)).unwrap(),
+        ["I'm nobody! Who are you?"]
+    );
+    assert_eq!(
+        run(&config("are", path, true)).unwrap(),
+        ["I'm nobody! Who are you?", "Are you nobody, too?"]
+    );
+}
+
+#[test]
+fn missing_files_are_reported() {
+    let error = run(&config("x", "no/such/file.txt", false)).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("could not read no/such/file.txt"),
+        "{error}"
+    );
+}
@@ -0,0 +1,39 @@
+// Stage 2 of the minigrep project
+use minigrep::search::{search, search_case_insensitive};
+
+const POEM: &str = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+
+#[test]
+fn case_sensitive() {
+    assert_eq!(search("duct", POEM), ["safe, fast, productive."]);
+    assert_eq!(search("rust", POEM), ["Trust me."]);
+    assert!(search("Duct", POEM).is_empty());
+}
+
+#[test]
+fn case_insensitive() {
+    assert_eq!(
+        search_case_insensitive("rUsT", POEM),
+        ["Rust:", "Trust me."]
+    );
+}
+
+#[test]
+fn keeps_the_order_and_every_match() {
+    assert_eq!(
+        search("e", POEM),
+        ["safe, fast, productive.", "Pick three.", "Trust me."]
+    );
+    assert!(search("x", "").is_empty());
+}
+
+#[test]
+fn results_borrow_from_the_contents() {
+    let contents = String::from("one\ntwo");
+    let found = search("two", &contents);
+    assert!(std::ptr::eq(found[0], &contents[4..]));
+}
@@ -0,0 +1,28 @@
+//! A multithreaded web server, built in three stages like chapter 20 of the
+//! Rust book:
+//!
+//! 1. [`pool`]: a thread pool that runs jobs on a fixed number of workers
+//! 2. [`http`]: read a request line and write a response
+//! 3. [`server`]: accept connections on a port, hand them to the pool and
+//!    shut down cleanly
+//!
+//! Each stage has its own test suite in `tests/`, and the runner moves on to
+//! the next stage once the current one passes.
+
+// `--features solutions` builds the reference solutions instead, so they are
+// checked by the same test suites
+#[cfg(not(feature = "solutions"))]
+pub mod http;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/webserver/src/http.rs"]
+pub mod http;
+#[cfg(not(feature = "solutions"))]
+pub mod pool;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/webserver/src/pool.rs"]
+pub mod pool;
+#[cfg(not(feature = "solutions"))]
+pub mod server;
+#[cfg(feature = "solutions")]
+#[path = "../../../../solutions/projects/webserver/src/server.rs"]
+pub mod server;
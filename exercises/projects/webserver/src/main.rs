@@ -0,0 +1,11 @@
+use std::io::BufRead;
+use webserver::server::Server;
+
+fn main() -> std::io::Result<()> {
+    let server = Server::start("127.0.0.1:7878", 4)?;
+    println!("Listening on http://{}, press Enter to stop", server.addr());
+    std::io::stdin().lock().lines().next();
+    server.shutdown();
+    println!("Bye!");
+    Ok(())
+}
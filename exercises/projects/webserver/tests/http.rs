@@ -0,0 +1,43 @@
+// Stage 2 of the web server project
+use webserver::http::{BadRequest, Request, Response};
+
+#[test]
+fn parses_request_lines() {
+    assert_eq!(
+        Request::parse("GET /index.html HTTP/1.1\r\n"),
+        Ok(Request {
+            method: "GET".to_string(),
+            path: "/index.html".to_string(),
+        })
+    );
+    assert_eq!(Request::parse("POST / HTTP/1.0").unwrap().method, "POST");
+}
+
+#[test]
+fn rejects_bad_lines() {
+    for line in ["", "GET /", "GET / HTTP/1.1 extra", "GET / FTP/1.0"] {
+        assert_eq!(
+            Request::parse(line),
+            Err(BadRequest(line.to_string())),
+            "{line:?}"
+        );
+    }
+}
+
+#[test]
+fn writes_responses() {
+    assert_eq!(
+        Response::new(200, "Hello!").to_bytes(),
+        b"HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\nHello!"
+    );
+    assert_eq!(
+        Response::new(404, "").to_bytes(),
+        b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n"
+    );
+}
+
+#[test]
+fn content_length_counts_bytes() {
+    let response = String::from_utf8(Response::new(200, "chào").to_bytes()).unwrap();
+    assert!(response.contains("Content-Length: 5\r\n"), "{response}");
+}
@@ -0,0 +1,60 @@
+// Stage 1 of the web server project
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier};
+use std::time::Duration;
+use webserver::pool::{PoolCreationError, ThreadPool};
+
+#[test]
+fn needs_at_least_one_thread() {
+    assert!(matches!(ThreadPool::build(0), Err(PoolCreationError)));
+    assert_eq!(ThreadPool::build(3).unwrap().size(), 3);
+}
+
+#[test]
+fn runs_jobs_in_parallel() {
+    // Every job waits for all the others, so this only finishes when the
+    // four of them really run at the same time
+    let pool = ThreadPool::build(4).unwrap();
+    let barrier = Arc::new(Barrier::new(4));
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..4 {
+        let barrier = Arc::clone(&barrier);
+        let sender = sender.clone();
+        pool.execute(move || {
+            barrier.wait();
+            sender.send(()).unwrap();
+        });
+    }
+    for _ in 0..4 {
+        receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("the jobs did not run in parallel");
+    }
+}
+
+#[test]
+fn drop_waits_for_every_job() {
+    let done = Arc::new(AtomicUsize::new(0));
+    {
+        let pool = ThreadPool::build(2).unwrap();
+        for _ in 0..10 {
+            let done = Arc::clone(&done);
+            pool.execute(move || {
+                std::thread::sleep(Duration::from_millis(10));
+                done.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+    }
+    assert_eq!(done.load(Ordering::SeqCst), 10);
+}
+
+#[test]
+fn drop_stops_the_workers() {
+    let pool = ThreadPool::build(3).unwrap();
+    let (sender, receiver) = mpsc::channel();
+    pool.execute(move || sender.send(()).unwrap());
+    receiver.recv().unwrap();
+    // Must return instead of hanging on idle workers
+    drop(pool);
+}
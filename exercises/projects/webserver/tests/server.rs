@@ -0,0 +1,84 @@
+// Stage 3 of the web server project. These tests talk to a real server on
+// a free port of 127.0.0.1
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+use webserver::server::Server;
+
+fn request(addr: SocketAddr, request_line: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    write!(stream, "{request_line}\r\nHost: localhost\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+fn status_line(response: &str) -> &str {
+    response.lines().next().unwrap_or_default()
+}
+
+#[test]
+fn answers_requests() {
+    let server = Server::start("127.0.0.1:0", 2).unwrap();
+    let response = request(server.addr(), "GET / HTTP/1.1");
+    assert_eq!(status_line(&response), "HTTP/1.1 200 OK");
+    assert!(response.ends_with("\r\n\r\nHello!"), "{response:?}");
+    server.shutdown();
+}
+
+#[test]
+fn error_statuses() {
+    let server = Server::start("127.0.0.1:0", 2).unwrap();
+    let addr = server.addr();
+    assert_eq!(
+        status_line(&request(addr, "GET /missing HTTP/1.1")),
+        "HTTP/1.1 404 Not Found"
+    );
+    assert_eq!(
+        status_line(&request(addr, "DELETE / HTTP/1.1")),
+        "HTTP/1.1 405 Method Not Allowed"
+    );
+    assert_eq!(
+        status_line(&request(addr, "nonsense")),
+        "HTTP/1.1 400 Bad Request"
+    );
+    server.shutdown();
+}
+
+#[test]
+fn slow_requests_do_not_block_others() {
+    let server = Server::start("127.0.0.1:0", 4).unwrap();
+    let addr = server.addr();
+    let started = Instant::now();
+    let slow: Vec<_> = (0..3)
+        .map(|_| thread::spawn(move || request(addr, "GET /sleep HTTP/1.1")))
+        .collect();
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(
+        status_line(&request(addr, "GET / HTTP/1.1")),
+        "HTTP/1.1 200 OK"
+    );
+    assert!(started.elapsed() < Duration::from_millis(400));
+    for handle in slow {
+        assert_eq!(status_line(&handle.join().unwrap()), "HTTP/1.1 200 OK");
+    }
+    // Three half-second requests one after the other would take 1.5s
+    assert!(started.elapsed() < Duration::from_millis(1200));
+    server.shutdown();
+}
+
+#[test]
+fn shutdown_finishes_requests_in_flight() {
+    let server = Server::start("127.0.0.1:0", 2).unwrap();
+    let addr = server.addr();
+    let in_flight = thread::spawn(move || request(addr, "GET /sleep HTTP/1.1"));
+    thread::sleep(Duration::from_millis(100));
+    server.shutdown();
+    assert_eq!(status_line(&in_flight.join().unwrap()), "HTTP/1.1 200 OK");
+    // Nobody is listening any more
+    assert!(TcpStream::connect(addr).is_err());
+}
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// A value that writes its name to a shared log when it is dropped
+struct Noisy {
+    name: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+fn noisy(name: &'static str, log: &Rc<RefCell<Vec<&'static str>>>) -> Noisy {
+    Noisy {
+        name,
+        log: Rc::clone(log),
+    }
+}
+
+// Only ever dropped, its fields are never read
+#[allow(dead_code)]
+struct Pair {
+    first: Noisy,
+    second: Noisy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_log() -> Rc<RefCell<Vec<&'static str>>> {
+        Rc::new(RefCell::new(Vec::new()))
+    }
+
+    // Exercise 1
+    // Local variables are dropped at the end of their scope, in reverse
+    // order of declaration. Fill in the expected log
+    #[test]
+    fn locals_drop_in_reverse() {
+        let log = new_log();
+        {
+            let _a = noisy("a", &log);
+            let _b = noisy("b", &log);
+            let _c = noisy("c", &log);
+        }
+        assert_eq!(*log.borrow(), vec!["c", "b", "a"]);
+    }
+
+    // Exercise 2
+    // Struct fields and `Vec` items drop in declaration order instead
+    #[test]
+    fn fields_and_items_drop_in_order() {
+        let log = new_log();
+        drop(Pair {
+            first: noisy("first", &log),
+            second: noisy("second", &log),
+        });
+        drop(vec![noisy("x", &log), noisy("y", &log)]);
+        assert_eq!(*log.borrow(), vec!["first", "second", "x", "y"]);
+    }
+
+    // Exercise 3
+    // A temporary is dropped at the end of its statement, `_` drops right
+    // away, but `_name` lives to the end of the scope
+    #[test]
+    fn temporaries_and_underscores() {
+        let log = new_log();
+        {
+            let _kept = noisy("kept", &log);
+            let _ = noisy("ignored", &log);
+            let length = noisy("temporary", &log).name.len();
+            assert_eq!(length, 9);
+            log.borrow_mut().push("end of scope");
+        }
+        assert_eq!(
+            *log.borrow(),
+            vec!["ignored", "temporary", "end of scope", "kept"]
+        );
+    }
+
+    // Exercise 4
+    // Moving a value moves its drop: it happens wherever the new owner ends
+    #[test]
+    fn moves_move_the_drop() {
+        let log = new_log();
+        let outer;
+        {
+            let inner = noisy("moved", &log);
+            let _stays = noisy("stays", &log);
+            outer = inner;
+        }
+        log.borrow_mut().push("after inner scope");
+        drop(outer);
+        assert_eq!(*log.borrow(), vec!["stays", "after inner scope", "moved"]);
+    }
+}
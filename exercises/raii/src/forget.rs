@@ -0,0 +1,90 @@
+use std::cell::Cell;
+use std::mem;
+
+// A pool of connections: taking one hands out a `Connection`, and dropping
+// the `Connection` gives it back
+struct Pool {
+    free: Cell<u32>,
+}
+
+struct Connection<'a> {
+    pool: &'a Pool,
+}
+
+impl Pool {
+    fn take(&self) -> Option<Connection<'_>> {
+        let free = self.free.get();
+        if free == 0 {
+            return None;
+        }
+        self.free.set(free - 1);
+        Some(Connection { pool: self })
+    }
+}
+
+impl Drop for Connection<'_> {
+    fn drop(&mut self) {
+        self.pool.free.set(self.pool.free.get() + 1);
+    }
+}
+
+// Exercise 1
+// `mem::forget` takes ownership without running `Drop`. It is safe Rust,
+// so code can never rely on a destructor running for memory safety
+fn leak(connection: Connection<'_>) {
+    mem::forget(connection);
+}
+
+// Exercise 2
+// Sometimes skipping `Drop` is the point: hand the connection's slot over
+// for good, so the pool shrinks by one
+fn retire(connection: Connection<'_>) -> u32 {
+    let free = connection.pool.free.get();
+    mem::forget(connection);
+    free
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn drop_returns_the_connection() {
+        let pool = Pool { free: Cell::new(2) };
+        {
+            let _a = pool.take().unwrap();
+            let _b = pool.take().unwrap();
+            assert!(pool.take().is_none());
+        }
+        assert_eq!(pool.free.get(), 2);
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn forget_leaks_it() {
+        let pool = Pool { free: Cell::new(1) };
+        leak(pool.take().unwrap());
+        assert_eq!(pool.free.get(), 0);
+        assert!(pool.take().is_none());
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn retire_shrinks_the_pool() {
+        let pool = Pool { free: Cell::new(3) };
+        let connection = pool.take().unwrap();
+        assert_eq!(retire(connection), 2);
+        assert_eq!(pool.free.get(), 2);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn manually_drop_skips_drop_too() {
+        let pool = Pool { free: Cell::new(1) };
+        {
+            let _connection = mem::ManuallyDrop::new(pool.take().unwrap());
+        }
+        assert_eq!(pool.free.get(), 0);
+    }
+}
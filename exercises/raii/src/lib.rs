@@ -0,0 +1,5 @@
+#![allow(dead_code)]
+
+mod drop_order;
+mod scope_guard;
+mod forget;
@@ -0,0 +1,64 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Exercise 1
+// Make me compile!
+// `Rc` can not cross threads: share the numbers with `Arc` so every thread
+// can sum its own part of them
+fn parallel_sum(numbers: Vec<u64>, threads: usize) -> u64 {
+    let numbers = Arc::new(numbers);
+    let chunk = numbers.len().div_ceil(threads.max(1)).max(1);
+    let handles: Vec<_> = (0..threads)
+        .map(|i| {
+            let numbers = Arc::clone(&numbers);
+            thread::spawn(move || {
+                let start = (i * chunk).min(numbers.len());
+                let end = (start + chunk).min(numbers.len());
+                numbers[start..end].iter().sum::<u64>()
+            })
+        })
+        .collect();
+    handles.into_iter().map(|h| h.join().unwrap()).sum()
+}
+
+// Exercise 2
+// Count how many times each of `threads` threads increments the shared
+// counter `times` times. Wrap the counter so threads can change it
+fn shared_counter(threads: usize, times: usize) -> usize {
+    let counter = Arc::new(Mutex::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..times {
+                    *counter.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let total = *counter.lock().unwrap();
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn sum_in_parallel() {
+        let numbers: Vec<u64> = (1..=100).collect();
+        assert_eq!(parallel_sum(numbers.clone(), 4), 5050);
+        assert_eq!(parallel_sum(numbers, 7), 5050);
+        assert_eq!(parallel_sum(vec![], 3), 0);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn count_across_threads() {
+        assert_eq!(shared_counter(8, 1000), 8000);
+    }
+}
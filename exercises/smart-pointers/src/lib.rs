@@ -0,0 +1,7 @@
+#![allow(dead_code)]
+
+mod boxes;
+mod rc_refcell;
+mod arc;
+mod tree;
+mod borrow_panic;
@@ -0,0 +1,92 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Exercise 1
+// Several planets share one sun. Share it with `Rc` instead of cloning it
+#[derive(Debug)]
+struct Sun {
+    name: String,
+}
+
+struct Planet {
+    name: String,
+    sun: Rc<Sun>,
+}
+
+fn solar_system(sun: Sun, planets: &[&str]) -> Vec<Planet> {
+    let sun = Rc::new(sun);
+    planets
+        .iter()
+        .map(|name| Planet {
+            name: name.to_string(),
+            sun: Rc::clone(&sun),
+        })
+        .collect()
+}
+
+// Exercise 2
+// Make me compile!
+// A bank account shared by several owners: any of them can deposit
+#[derive(Debug, Default)]
+struct Account {
+    balance: RefCell<u64>,
+}
+
+impl Account {
+    fn deposit(&self, amount: u64) {
+        *self.balance.borrow_mut() += amount;
+    }
+
+    fn balance(&self) -> u64 {
+        *self.balance.borrow()
+    }
+}
+
+// Exercise 3
+// Every owner of the `Rc<RefCell<Vec<String>>>` logs to the same list.
+// Implement `log` so messages from every clone end up in it
+fn log(shared: &Rc<RefCell<Vec<String>>>, message: &str) {
+    shared.borrow_mut().push(message.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn planets_share_the_sun() {
+        let planets = solar_system(
+            Sun {
+                name: "Sol".to_string(),
+            },
+            &["Mercury", "Venus", "Earth"],
+        );
+        assert_eq!(planets[2].name, "Earth");
+        assert_eq!(planets[0].sun.name, "Sol");
+        assert!(Rc::ptr_eq(&planets[0].sun, &planets[1].sun));
+        assert_eq!(Rc::strong_count(&planets[0].sun), 3);
+        drop(planets);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn shared_account() {
+        let account = Rc::new(Account::default());
+        let alice = Rc::clone(&account);
+        let bob = Rc::clone(&account);
+        alice.deposit(10);
+        bob.deposit(5);
+        assert_eq!(account.balance(), 15);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn shared_log() {
+        let shared = Rc::new(RefCell::new(Vec::new()));
+        let other = Rc::clone(&shared);
+        log(&shared, "first");
+        log(&other, "second");
+        assert_eq!(*shared.borrow(), vec!["first", "second"]);
+    }
+}
@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// Exercise 1
+// A tree node owns its children and points back to its parent.
+// Children are owned with `Rc`, but the parent link must be a `Weak`:
+// two `Rc`s pointing at each other would never be freed
+#[derive(Debug)]
+struct Node {
+    value: i32,
+    parent: RefCell<Weak<Node>>,
+    children: RefCell<Vec<Rc<Node>>>,
+}
+
+fn leaf(value: i32) -> Rc<Node> {
+    Rc::new(Node {
+        value,
+        parent: RefCell::new(Weak::new()),
+        children: RefCell::new(Vec::new()),
+    })
+}
+
+// Exercise 2
+// Attach `child` under `parent`, setting both links
+fn add_child(parent: &Rc<Node>, child: Rc<Node>) {
+    *child.parent.borrow_mut() = Rc::downgrade(parent);
+    parent.children.borrow_mut().push(child);
+}
+
+// Exercise 3
+// Output: the values from `node` up to the root, following the parent links
+fn path_to_root(node: &Rc<Node>) -> Vec<i32> {
+    let mut path = vec![node.value];
+    let mut current = node.parent.borrow().upgrade();
+    while let Some(parent) = current {
+        path.push(parent.value);
+        current = parent.parent.borrow().upgrade();
+    }
+    path
+}
+
+// Exercise 4
+// Output: the sum of every value in the subtree starting at `node`
+fn subtree_sum(node: &Node) -> i32 {
+    node.value
+        + node
+            .children
+            .borrow()
+            .iter()
+            .map(|child| subtree_sum(child))
+            .sum::<i32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> (Rc<Node>, Rc<Node>) {
+        let root = leaf(1);
+        let branch = leaf(2);
+        let deep = leaf(3);
+        add_child(&root, Rc::clone(&branch));
+        add_child(&root, leaf(4));
+        add_child(&branch, Rc::clone(&deep));
+        (root, deep)
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn children_know_their_parent() {
+        let (root, _) = sample();
+        let first = Rc::clone(&root.children.borrow()[0]);
+        assert_eq!(first.parent.borrow().upgrade().unwrap().value, 1);
+        assert!(root.parent.borrow().upgrade().is_none());
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn walk_up_the_tree() {
+        let (_root, deep) = sample();
+        assert_eq!(path_to_root(&deep), vec![3, 2, 1]);
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn sum_a_subtree() {
+        let (root, _) = sample();
+        assert_eq!(subtree_sum(&root), 10);
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn parent_links_do_not_keep_nodes_alive() {
+        let (root, deep) = sample();
+        assert_eq!(Rc::strong_count(&root), 1);
+        assert_eq!(Rc::weak_count(&root), 2);
+        drop(root);
+        assert_eq!(path_to_root(&deep), vec![3]);
+    }
+}
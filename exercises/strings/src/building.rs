@@ -0,0 +1,106 @@
+// Exercise 1
+// Output: every word followed by a comma, built in one `String`.
+// `s = s + ...` or `format!` in a loop allocates a new string every time:
+// reserve the space once and `push_str` into it
+fn join_words(words: &[&str]) -> String {
+    let capacity = words.iter().map(|word| word.len() + 1).sum();
+    let mut joined = String::with_capacity(capacity);
+    for word in words {
+        joined.push_str(word);
+        joined.push(',');
+    }
+    joined
+}
+
+// Exercise 2
+// `format!` is clearer for one-off strings mixing text and values
+fn label(name: &str, count: u32) -> String {
+    format!("{name}: {count} item{}", if count == 1 { "" } else { "s" })
+}
+
+// Exercise 3
+// Write into an existing `String`: `use std::fmt::Write` gives `write!`
+// for strings, without making a new one for each line
+fn table(rows: &[(&str, u32)]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    for (name, value) in rows {
+        let _ = writeln!(out, "{name:<6}{value:>4}");
+    }
+    out
+}
+
+// Exercise 4
+// Input: a sentence
+// Output: the sentence with every word capitalized
+fn capitalize_words(sentence: &str) -> String {
+    let mut out = String::with_capacity(sentence.len());
+    for (i, word) in sentence.split(' ').enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            out.extend(first.to_uppercase());
+            out.push_str(chars.as_str());
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_support::assert_allocations_at_most;
+
+    test_support::count_allocations!();
+
+    // Test for exercise 1
+    #[test]
+    fn join_with_push_str() {
+        let words = ["a", "bb", "ccc"];
+        let joined = join_words(&words);
+        assert_eq!(joined, "a,bb,ccc,");
+        assert_eq!(joined.capacity(), joined.len());
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn join_allocates_once() {
+        let words = ["one"; 100];
+        let joined = assert_allocations_at_most(1, || join_words(&words));
+        assert_eq!(joined.len(), 400);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn format_a_label() {
+        assert_eq!(label("apples", 1), "apples: 1 item");
+        assert_eq!(label("pears", 3), "pears: 3 items");
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn write_a_table() {
+        assert_eq!(
+            table(&[("tea", 3), ("coffee", 12)]),
+            "tea      3\ncoffee  12\n"
+        );
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn capitalize() {
+        assert_eq!(capitalize_words("hello rust world"), "Hello Rust World");
+        assert_eq!(capitalize_words("ärger über ß"), "Ärger Über SS");
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn capitalize_allocates_once() {
+        let sentence = "allocate the output once and fill it in place";
+        let capitalized = assert_allocations_at_most(1, || capitalize_words(sentence));
+        assert_eq!(capitalized, "Allocate The Output Once And Fill It In Place");
+    }
+}
@@ -0,0 +1,5 @@
+#![allow(dead_code)]
+
+mod owned_and_borrowed;
+mod utf8;
+mod building;
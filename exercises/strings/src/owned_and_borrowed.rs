@@ -0,0 +1,70 @@
+// Exercise 1
+// Make me compile!
+// Accept both `String` and `&str` arguments by taking a `&str`
+fn shout(text: &str) -> String {
+    text.to_uppercase()
+}
+
+// Exercise 2
+// Input: a file path like "src/main.rs"
+// Output: the file name, borrowed from the path without copying
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+// Exercise 3
+// Return an owned `String`: the borrowed `name` does not live long enough
+// to be returned inside the greeting
+fn greeting(name: &str) -> String {
+    let mut greeting = String::from("Hi ");
+    greeting.push_str(name);
+    greeting
+}
+
+// Exercise 4
+// Slices work on arrays too: return the middle part, without the first
+// and the last item
+fn middle(items: &[i32]) -> &[i32] {
+    if items.len() < 2 {
+        return &[];
+    }
+    &items[1..items.len() - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn shout_any_string() {
+        let owned = String::from("hello");
+        assert_eq!(shout(&owned), "HELLO");
+        assert_eq!(shout("world"), "WORLD");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn file_name_is_a_slice() {
+        let path = String::from("exercises/strings/src/utf8.rs");
+        assert_eq!(file_name(&path), "utf8.rs");
+        assert_eq!(file_name("README.md"), "README.md");
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn owned_greeting() {
+        let greeting = {
+            let name = String::from("Ferris");
+            greeting(&name)
+        };
+        assert_eq!(greeting, "Hi Ferris");
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn middle_slice() {
+        assert_eq!(middle(&[1, 2, 3, 4]), &[2, 3]);
+        assert_eq!(middle(&[1]), &[] as &[i32]);
+    }
+}
@@ -0,0 +1,96 @@
+//! Helpers shared by the exercise tests.
+//!
+//! [`CountingAllocator`] counts the allocations made by the current thread, so
+//! a test can check that an implementation stays within a budget:
+//!
+//! ```
+//! test_support::count_allocations!();
+//!
+//! let words = test_support::assert_allocations_at_most(1, || {
+//!     let mut words = Vec::with_capacity(2);
+//!     words.push("a");
+//!     words.push("b");
+//!     words
+//! });
+//! assert_eq!(words, ["a", "b"]);
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting every allocation and reallocation. Install
+/// it with [`count_allocations!`]
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Install [`CountingAllocator`] as the `#[global_allocator]`. A binary has
+/// only one, so only one file per test target may use the macro
+#[macro_export]
+macro_rules! count_allocations {
+    () => {
+        #[global_allocator]
+        static COUNTING_ALLOCATOR: $crate::CountingAllocator = $crate::CountingAllocator;
+    };
+}
+
+/// Run `f`, returning its result and how many allocations it made
+pub fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    (value, ALLOCATIONS.with(Cell::get) - before)
+}
+
+/// Run `f` and panic if it made more than `limit` allocations
+#[track_caller]
+pub fn assert_allocations_at_most<T>(limit: usize, f: impl FnOnce() -> T) -> T {
+    let (value, count) = allocations(f);
+    assert!(
+        count <= limit,
+        "expected at most {limit} allocation(s), but there were {count}"
+    );
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    count_allocations!();
+
+    #[test]
+    fn counts_allocations_and_growth() {
+        let (_, count) = allocations(|| {
+            let mut s = String::new();
+            s.push_str("grows");
+            s.push_str(" twice, to fit more than the first allocation");
+            s
+        });
+        assert_eq!(count, 2);
+        assert_eq!(allocations(|| 1 + 1), (2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 0 allocation(s), but there were 1")]
+    fn over_the_limit() {
+        assert_allocations_at_most(0, || Box::new(1));
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+pub mod stack;
+mod unit_tests;
+mod properties;
+mod catch_the_bug;
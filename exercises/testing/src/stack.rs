@@ -0,0 +1,102 @@
+//! A small stack used by the testing exercises.
+
+// Exercise 1
+// Every method of `Stack` needs an example in its documentation.
+// `cargo test -p testing --doc` compiles and runs them like tests
+
+/// A last-in, first-out stack with a maximum size.
+///
+/// ```
+/// use testing::stack::Stack;
+///
+/// let mut stack = Stack::with_capacity(2);
+/// stack.push(1).unwrap();
+/// stack.push(2).unwrap();
+/// assert_eq!(stack.pop(), Some(2));
+/// ```
+#[derive(Debug)]
+pub struct Stack<T> {
+    items: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> Stack<T> {
+    /// An empty stack holding at most `capacity` items.
+    ///
+    /// ```
+    /// # use testing::stack::Stack;
+    /// let stack: Stack<char> = Stack::with_capacity(3);
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Stack {
+            items: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Push `item` on top, or give it back when the stack is full.
+    ///
+    /// ```
+    /// # use testing::stack::Stack;
+    /// let mut stack = Stack::with_capacity(1);
+    /// assert_eq!(stack.push('a'), Ok(()));
+    /// assert_eq!(stack.push('b'), Err('b'));
+    /// ```
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.items.len() == self.capacity {
+            return Err(item);
+        }
+        self.items.push(item);
+        Ok(())
+    }
+
+    /// Take the top item off, `None` when the stack is empty.
+    ///
+    /// ```
+    /// # use testing::stack::Stack;
+    /// let mut stack: Stack<u8> = Stack::with_capacity(1);
+    /// assert_eq!(stack.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// The top item, without taking it off.
+    ///
+    /// ```
+    /// # use testing::stack::Stack;
+    /// let mut stack = Stack::with_capacity(2);
+    /// stack.push("bottom").unwrap();
+    /// stack.push("top").unwrap();
+    /// assert_eq!(stack.peek(), Some(&"top"));
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// How many items are on the stack.
+    ///
+    /// ```
+    /// # use testing::stack::Stack;
+    /// let mut stack = Stack::with_capacity(5);
+    /// stack.push(1).unwrap();
+    /// assert_eq!(stack.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the stack has no items.
+    ///
+    /// ```
+    /// # use testing::stack::Stack;
+    /// let mut stack = Stack::with_capacity(1);
+    /// stack.push(()).unwrap();
+    /// assert!(!stack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
@@ -0,0 +1,83 @@
+// Exercise 1
+// Make me compile!
+// A `Vec` holds values of one type. Store the different animals as
+// `Box<dyn Animal>` so they fit in the same list
+trait Animal {
+    fn name(&self) -> String;
+    fn sound(&self) -> String;
+}
+
+struct Dog;
+struct Cat {
+    lives: u8,
+}
+
+impl Animal for Dog {
+    fn name(&self) -> String {
+        "dog".to_string()
+    }
+
+    fn sound(&self) -> String {
+        "woof".to_string()
+    }
+}
+
+impl Animal for Cat {
+    fn name(&self) -> String {
+        format!("cat with {} lives", self.lives)
+    }
+
+    fn sound(&self) -> String {
+        "meow".to_string()
+    }
+}
+
+fn zoo() -> Vec<Box<dyn Animal>> {
+    vec![Box::new(Dog), Box::new(Cat { lives: 9 })]
+}
+
+// Exercise 2
+// Output: "<name> says <sound>" for every animal
+fn chorus(animals: &[Box<dyn Animal>]) -> Vec<String> {
+    animals
+        .iter()
+        .map(|animal| format!("{} says {}", animal.name(), animal.sound()))
+        .collect()
+}
+
+// Exercise 3
+// Borrowed trait objects work too: pick the animal by name, without boxing
+fn loudest(prefer_dog: bool) -> &'static dyn Animal {
+    if prefer_dog {
+        &Dog
+    } else {
+        &Cat { lives: 1 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn mixed_zoo() {
+        assert_eq!(zoo().len(), 2);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn everyone_sings() {
+        assert_eq!(
+            chorus(&zoo()),
+            vec!["dog says woof", "cat with 9 lives says meow"]
+        );
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn borrowed_trait_objects() {
+        assert_eq!(loudest(true).sound(), "woof");
+        assert_eq!(loudest(false).name(), "cat with 1 lives");
+    }
+}
@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+mod dyn_dispatch;
+mod object_safety;
+mod static_vs_dynamic;
+mod registry;
@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+
+// Exercise 1
+// `Describe` has no implementation for any type yet.
+// Add a single blanket impl so that every type implementing `Debug` gets it,
+// returning "<value in {:?} form> of type <type name>"
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl<T: Debug> Describe for T {
+    fn describe(&self) -> String {
+        format!("{self:?} of type {}", std::any::type_name::<T>())
+    }
+}
+
+// Exercise 2
+// Implement `Double` once for every type that can be cloned and added to itself
+trait Double {
+    fn double(&self) -> Self;
+}
+
+impl<T: Clone + std::ops::Add<Output = T>> Double for T {
+    fn double(&self) -> T {
+        self.clone() + self.clone()
+    }
+}
+
+// Exercise 3
+// A blanket impl over another trait: everything that is `Loud` is also `Speak`
+trait Loud {
+    fn shout(&self) -> String;
+}
+
+trait Speak {
+    fn speak(&self) -> String;
+}
+
+impl<T: Loud> Speak for T {
+    fn speak(&self) -> String {
+        self.shout().to_lowercase()
+    }
+}
+
+struct Dog;
+
+impl Loud for Dog {
+    fn shout(&self) -> String {
+        "WOOF".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn everything_debug_describes_itself() {
+        assert_eq!(42.describe(), "42 of type i32");
+        assert_eq!(
+            Some("x").describe(),
+            "Some(\"x\") of type core::option::Option<&str>"
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn double_numbers_and_strings() {
+        assert_eq!(21.double(), 42);
+        assert_eq!(1.5.double(), 3.0);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn loud_things_speak() {
+        assert_eq!(Dog.speak(), "woof");
+    }
+}
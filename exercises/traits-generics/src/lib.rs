@@ -0,0 +1,6 @@
+#![allow(dead_code)]
+
+mod traits;
+mod generics;
+mod blanket;
+mod toy_machine;
@@ -0,0 +1,90 @@
+use state_machine::StateMachine;
+
+// Exercise 1
+// A traffic light cycles Red -> Green -> Yellow -> Red on every `Timer` event.
+// `Emergency` turns any light to Red, and `Timer` is the only way out of Red.
+// Implement the final project's `StateMachine` trait for `TrafficLight`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Light {
+    Red,
+    Green,
+    Yellow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Signal {
+    Timer,
+    Emergency,
+}
+
+struct TrafficLight;
+
+impl StateMachine for TrafficLight {
+    type State = Light;
+    type Transition = Signal;
+
+    fn next_state(starting_state: &Light, t: &Signal) -> Light {
+        match (starting_state, t) {
+            (_, Signal::Emergency) => Light::Red,
+            (Light::Red, Signal::Timer) => Light::Green,
+            (Light::Green, Signal::Timer) => Light::Yellow,
+            (Light::Yellow, Signal::Timer) => Light::Red,
+        }
+    }
+}
+
+// Exercise 2
+// Make me generic!
+// Run any state machine from `start` through every transition in turn
+fn run<M: StateMachine>(start: M::State, transitions: &[M::Transition]) -> M::State {
+    transitions
+        .iter()
+        .fold(start, |state, t| M::next_state(&state, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn timer_cycles_the_light() {
+        assert_eq!(
+            TrafficLight::next_state(&Light::Red, &Signal::Timer),
+            Light::Green
+        );
+        assert_eq!(
+            TrafficLight::next_state(&Light::Green, &Signal::Timer),
+            Light::Yellow
+        );
+        assert_eq!(
+            TrafficLight::next_state(&Light::Yellow, &Signal::Timer),
+            Light::Red
+        );
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn emergency_turns_red() {
+        for light in [Light::Red, Light::Green, Light::Yellow] {
+            assert_eq!(
+                TrafficLight::next_state(&light, &Signal::Emergency),
+                Light::Red
+            );
+        }
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn run_any_machine() {
+        use state_machine::garage_door::{DoorEvent, DoorState, GarageDoor};
+
+        let light = run::<TrafficLight>(Light::Red, &[Signal::Timer, Signal::Timer]);
+        assert_eq!(light, Light::Yellow);
+        let door = run::<GarageDoor>(
+            DoorState::Closed,
+            &[DoorEvent::Button, DoorEvent::ReachedTop],
+        );
+        assert_eq!(door, DoorState::Open);
+    }
+}
@@ -0,0 +1,7 @@
+// `eject` must not exist while there is no card
+#[path = "../src/session.rs"]
+mod session;
+
+pub fn eject() {
+    session::Session::new().eject();
+}
@@ -0,0 +1,9 @@
+// `from` must not exist once it is set
+#[path = "../src/builder.rs"]
+mod builder;
+
+pub fn send() {
+    builder::EmailBuilder::new()
+        .from("alice@example.com")
+        .from("eve@example.com");
+}
@@ -0,0 +1,7 @@
+// `withdraw` must not exist before the PIN was accepted
+#[path = "../src/session.rs"]
+mod session;
+
+pub fn withdraw() {
+    session::Session::new().insert_card(1234).withdraw(50);
+}
@@ -0,0 +1,141 @@
+//! The email builder again, with the missing-field checks moved to compile time.
+
+// Exercise 1
+// The builder's type parameters record which required fields are set. They
+// start as `NoFrom` and `NoTo`, `from` and `to` swap them for `HasFrom` and
+// `HasTo`, and `build` only exists once both are set. It returns an `Email`
+// directly: there is nothing left to go wrong at runtime.
+//
+// The files in `compile-fail/` make the mistakes the types must catch. The
+// runner checks that each one fails to compile with the errors in its
+// `.stderr` file
+
+/// A finished email.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Email {
+    pub from: String,
+    pub to: String,
+    pub subject: Option<String>,
+}
+
+/// `from` is not set yet.
+#[derive(Debug)]
+pub struct NoFrom;
+/// `from` is set.
+#[derive(Debug)]
+pub struct HasFrom(String);
+/// `to` is not set yet.
+#[derive(Debug)]
+pub struct NoTo;
+/// `to` is set.
+#[derive(Debug)]
+pub struct HasTo(String);
+
+/// Builds an [`Email`], in any order.
+///
+/// ```
+/// use typestate::builder::EmailBuilder;
+///
+/// let email = EmailBuilder::new()
+///     .to("bob@example.com")
+///     .from("alice@example.com")
+///     .build();
+/// assert_eq!(email.to, "bob@example.com");
+/// ```
+#[derive(Debug)]
+pub struct EmailBuilder<F, T> {
+    from: F,
+    to: T,
+    subject: Option<String>,
+}
+
+impl EmailBuilder<NoFrom, NoTo> {
+    pub fn new() -> Self {
+        EmailBuilder {
+            from: NoFrom,
+            to: NoTo,
+            subject: None,
+        }
+    }
+}
+
+impl Default for EmailBuilder<NoFrom, NoTo> {
+    fn default() -> Self {
+        EmailBuilder::new()
+    }
+}
+
+impl<T> EmailBuilder<NoFrom, T> {
+    pub fn from(self, from: &str) -> EmailBuilder<HasFrom, T> {
+        EmailBuilder {
+            from: HasFrom(from.to_string()),
+            to: self.to,
+            subject: self.subject,
+        }
+    }
+}
+
+impl<F> EmailBuilder<F, NoTo> {
+    pub fn to(self, to: &str) -> EmailBuilder<F, HasTo> {
+        EmailBuilder {
+            from: self.from,
+            to: HasTo(to.to_string()),
+            subject: self.subject,
+        }
+    }
+}
+
+impl<F, T> EmailBuilder<F, T> {
+    /// Optional, so it is available in every state.
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.to_string());
+        self
+    }
+}
+
+impl EmailBuilder<HasFrom, HasTo> {
+    pub fn build(self) -> Email {
+        Email {
+            from: self.from.0,
+            to: self.to.0,
+            subject: self.subject,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn builds_in_any_order() {
+        let expected = Email {
+            from: "alice@example.com".to_string(),
+            to: "bob@example.com".to_string(),
+            subject: Some("hi".to_string()),
+        };
+        let first = EmailBuilder::new()
+            .from("alice@example.com")
+            .to("bob@example.com")
+            .subject("hi")
+            .build();
+        let second = EmailBuilder::new()
+            .subject("hi")
+            .to("bob@example.com")
+            .from("alice@example.com")
+            .build();
+        assert_eq!(first, expected);
+        assert_eq!(second, expected);
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn subject_is_optional() {
+        let email = EmailBuilder::default()
+            .from("alice@example.com")
+            .to("bob@example.com")
+            .build();
+        assert_eq!(email.subject, None);
+    }
+}
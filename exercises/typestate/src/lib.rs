@@ -0,0 +1,5 @@
+#![allow(dead_code)]
+
+mod runtime_builder;
+pub mod builder;
+pub mod session;
@@ -0,0 +1,88 @@
+#[derive(Debug, PartialEq, Eq)]
+struct Email {
+    from: String,
+    to: String,
+    subject: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum MissingField {
+    From,
+    To,
+}
+
+// Exercise 1
+// Every setter takes the builder by value and hands it back, so calls chain.
+// `build` checks that `from` and `to` were set, `subject` is optional
+#[derive(Debug, Default)]
+struct EmailBuilder {
+    from: Option<String>,
+    to: Option<String>,
+    subject: Option<String>,
+}
+
+impl EmailBuilder {
+    fn new() -> Self {
+        EmailBuilder::default()
+    }
+
+    fn from(mut self, from: &str) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    fn to(mut self, to: &str) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    fn subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.to_string());
+        self
+    }
+
+    fn build(self) -> Result<Email, MissingField> {
+        Ok(Email {
+            from: self.from.ok_or(MissingField::From)?,
+            to: self.to.ok_or(MissingField::To)?,
+            subject: self.subject,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn builds_in_any_order() {
+        let email = EmailBuilder::new()
+            .to("bob@example.com")
+            .subject("hi")
+            .from("alice@example.com")
+            .build();
+        assert_eq!(
+            email,
+            Ok(Email {
+                from: "alice@example.com".to_string(),
+                to: "bob@example.com".to_string(),
+                subject: Some("hi".to_string()),
+            })
+        );
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn missing_fields_fail_at_runtime() {
+        // This compiles fine, the mistake only shows up when it runs
+        assert_eq!(
+            EmailBuilder::new().to("bob@example.com").build(),
+            Err(MissingField::From)
+        );
+        assert_eq!(
+            EmailBuilder::new().from("alice@example.com").build(),
+            Err(MissingField::To)
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! A card session where every step can only happen in the right state.
+
+// Exercise 1
+// `Session<S>` is only ever in one of the states below. Give each state
+// just the methods that make sense in it:
+// + `Idle`: `insert_card`
+// + `CardInserted`: `enter_pin`, which either authenticates or, after a wrong
+//   PIN, gives the session back with one attempt less. The third wrong PIN
+//   keeps the card and returns to `Idle`
+// + `Authenticated`: `withdraw`
+// + every state but `Idle`: `eject`
+//
+// The runner checks that the files in `compile-fail/` that skip a step fail
+// to compile
+
+/// Waiting for a card.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Idle;
+
+/// A card is in, its PIN has not been checked yet.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CardInserted {
+    pin: u16,
+    attempts_left: u8,
+}
+
+/// The PIN was right.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Authenticated {
+    withdrawn: u32,
+}
+
+/// What happened after a PIN was entered.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PinResult {
+    Accepted(Session<Authenticated>),
+    Rejected(Session<CardInserted>),
+    CardKept(Session<Idle>),
+}
+
+/// A card session in the state `S`.
+///
+/// ```
+/// use typestate::session::{PinResult, Session};
+///
+/// let session = Session::new().insert_card(1234);
+/// let PinResult::Accepted(session) = session.enter_pin(1234) else {
+///     panic!("the PIN is right");
+/// };
+/// let session = session.withdraw(50);
+/// assert_eq!(session.withdrawn(), 50);
+/// session.eject();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Session<S> {
+    state: S,
+}
+
+const PIN_ATTEMPTS: u8 = 3;
+
+impl Session<Idle> {
+    pub fn new() -> Self {
+        Session { state: Idle }
+    }
+
+    /// Read a card whose PIN is `pin`.
+    pub fn insert_card(self, pin: u16) -> Session<CardInserted> {
+        Session {
+            state: CardInserted {
+                pin,
+                attempts_left: PIN_ATTEMPTS,
+            },
+        }
+    }
+}
+
+impl Default for Session<Idle> {
+    fn default() -> Self {
+        Session::new()
+    }
+}
+
+impl Session<CardInserted> {
+    pub fn attempts_left(&self) -> u8 {
+        self.state.attempts_left
+    }
+
+    pub fn enter_pin(self, pin: u16) -> PinResult {
+        if pin == self.state.pin {
+            return PinResult::Accepted(Session {
+                state: Authenticated { withdrawn: 0 },
+            });
+        }
+        match self.state.attempts_left - 1 {
+            0 => PinResult::CardKept(Session::new()),
+            attempts_left => PinResult::Rejected(Session {
+                state: CardInserted {
+                    attempts_left,
+                    ..self.state
+                },
+            }),
+        }
+    }
+
+    pub fn eject(self) -> Session<Idle> {
+        Session::new()
+    }
+}
+
+impl Session<Authenticated> {
+    pub fn withdraw(self, amount: u32) -> Self {
+        Session {
+            state: Authenticated {
+                withdrawn: self.state.withdrawn + amount,
+            },
+        }
+    }
+
+    pub fn withdrawn(&self) -> u32 {
+        self.state.withdrawn
+    }
+
+    pub fn eject(self) -> Session<Idle> {
+        Session::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authenticated() -> Session<Authenticated> {
+        match Session::new().insert_card(1234).enter_pin(1234) {
+            PinResult::Accepted(session) => session,
+            other => panic!("expected the PIN to be accepted, got {other:?}"),
+        }
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn withdrawals_add_up() {
+        let session = authenticated().withdraw(20).withdraw(30);
+        assert_eq!(session.withdrawn(), 50);
+        assert_eq!(session.eject(), Session::new());
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn wrong_pin_costs_an_attempt() {
+        let PinResult::Rejected(session) = Session::new().insert_card(1234).enter_pin(1111) else {
+            panic!("the PIN is wrong");
+        };
+        assert_eq!(session.attempts_left(), 2);
+        assert!(matches!(session.enter_pin(1234), PinResult::Accepted(_)));
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn third_wrong_pin_keeps_the_card() {
+        let mut session = Session::new().insert_card(1234);
+        for attempts_left in [2, 1] {
+            session = match session.enter_pin(0) {
+                PinResult::Rejected(session) => session,
+                other => panic!("expected a rejection, got {other:?}"),
+            };
+            assert_eq!(session.attempts_left(), attempts_left);
+        }
+        assert_eq!(session.enter_pin(0), PinResult::CardKept(Session::new()));
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn card_can_be_ejected_before_the_pin() {
+        assert_eq!(Session::new().insert_card(1234).eject(), Session::new());
+    }
+}
@@ -0,0 +1,21 @@
+//! Digital pins in the style of `embedded-hal`, cut down to what a keypad
+//! needs. A board support crate implements them for its GPIO pins.
+//!
+//! Unlike `embedded-hal` the operations cannot fail: pins on a
+//! microcontroller rarely can, and it keeps the keypad code short.
+
+/// A pin the keypad drives, one per row
+pub trait OutputPin {
+    /// Drive the pin low, selecting its row
+    fn set_low(&mut self);
+
+    /// Drive the pin high, releasing its row
+    fn set_high(&mut self);
+}
+
+/// A pin the keypad reads, one per column. It is pulled up, so it reads low
+/// while a key connects it to a selected row
+pub trait InputPin {
+    /// Whether the pin reads low
+    fn is_low(&self) -> bool;
+}
@@ -0,0 +1,35 @@
+//! PIN entry on a matrix keypad, without `std`.
+//!
+//! A [`MatrixKeypad`] scans rows and columns of GPIO pins and reports each
+//! [`Key`] press once. Feeding the presses to a [`PinEntry`] machine collects
+//! the digits into a PIN:
+//!
+//! ```
+//! use keypad::sim::Matrix;
+//! use keypad::{Entry, Key, MatrixKeypad, PinEntry, PHONE_LAYOUT};
+//! use state_machine_core::Runner;
+//!
+//! let matrix = Matrix::new();
+//! let mut keypad = MatrixKeypad::new(matrix.rows(), matrix.cols(), PHONE_LAYOUT);
+//! let mut entry = Runner::<PinEntry>::new(Entry::default());
+//!
+//! for key in [Key::Digit(4), Key::Digit(2), Key::Digit(4), Key::Digit(2), Key::Enter] {
+//!     matrix.press(key);
+//!     if let Some(key) = keypad.poll() {
+//!         entry.step(&key);
+//!     }
+//!     matrix.release();
+//!     keypad.poll();
+//! }
+//! assert_eq!(*entry.state(), Entry::Submitted(4242));
+//! ```
+
+#![cfg_attr(not(test), no_std)]
+
+pub mod hal;
+mod matrix;
+mod pin_entry;
+pub mod sim;
+
+pub use matrix::{Key, MatrixKeypad, PHONE_LAYOUT};
+pub use pin_entry::{Entry, PinEntry, PIN_LENGTH};
@@ -0,0 +1,120 @@
+use crate::hal::{InputPin, OutputPin};
+
+/// A key on the keypad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// `0` to `9`
+    Digit(u8),
+    /// Submit what was typed
+    Enter,
+    /// Start over
+    Clear,
+    /// Give up
+    Cancel,
+}
+
+/// The 4x3 layout of a phone or cash machine keypad
+pub const PHONE_LAYOUT: [[Key; 3]; 4] = [
+    [Key::Digit(1), Key::Digit(2), Key::Digit(3)],
+    [Key::Digit(4), Key::Digit(5), Key::Digit(6)],
+    [Key::Digit(7), Key::Digit(8), Key::Digit(9)],
+    [Key::Clear, Key::Digit(0), Key::Enter],
+];
+
+/// A keypad wired as a matrix: a key connects its row to its column
+pub struct MatrixKeypad<R, C, const ROWS: usize, const COLS: usize> {
+    rows: [R; ROWS],
+    cols: [C; COLS],
+    layout: [[Key; COLS]; ROWS],
+    held: Option<Key>,
+}
+
+impl<R: OutputPin, C: InputPin, const ROWS: usize, const COLS: usize>
+    MatrixKeypad<R, C, ROWS, COLS>
+{
+    /// Take over the row and column pins. Every row starts released
+    pub fn new(mut rows: [R; ROWS], cols: [C; COLS], layout: [[Key; COLS]; ROWS]) -> Self {
+        for row in rows.iter_mut() {
+            row.set_high();
+        }
+        MatrixKeypad {
+            rows,
+            cols,
+            layout,
+            held: None,
+        }
+    }
+
+    /// The key held down right now, if any. With several, the first in
+    /// scanning order
+    pub fn scan(&mut self) -> Option<Key> {
+        for (r, row) in self.rows.iter_mut().enumerate() {
+            row.set_low();
+            let col = self.cols.iter().position(InputPin::is_low);
+            row.set_high();
+            if let Some(c) = col {
+                return Some(self.layout[r][c]);
+            }
+        }
+        None
+    }
+
+    /// The key that was just pressed. Holding it down reports it only once,
+    /// so call this regularly, for example from a timer
+    pub fn poll(&mut self) -> Option<Key> {
+        let key = self.scan();
+        let pressed = if key == self.held { None } else { key };
+        self.held = key;
+        pressed
+    }
+
+    /// Give the pins back
+    pub fn release(self) -> ([R; ROWS], [C; COLS]) {
+        (self.rows, self.cols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::Matrix;
+
+    #[test]
+    fn scans_the_pressed_key() {
+        let matrix = Matrix::new();
+        let mut keypad = MatrixKeypad::new(matrix.rows(), matrix.cols(), PHONE_LAYOUT);
+        assert_eq!(keypad.scan(), None);
+        for row in PHONE_LAYOUT {
+            for key in row {
+                matrix.press(key);
+                assert_eq!(keypad.scan(), Some(key));
+            }
+        }
+        matrix.release();
+        assert_eq!(keypad.scan(), None);
+    }
+
+    #[test]
+    fn reports_a_held_key_once() {
+        let matrix = Matrix::new();
+        let mut keypad = MatrixKeypad::new(matrix.rows(), matrix.cols(), PHONE_LAYOUT);
+        matrix.press(Key::Digit(5));
+        assert_eq!(keypad.poll(), Some(Key::Digit(5)));
+        assert_eq!(keypad.poll(), None);
+        matrix.press(Key::Digit(6));
+        assert_eq!(keypad.poll(), Some(Key::Digit(6)));
+        matrix.release();
+        assert_eq!(keypad.poll(), None);
+        matrix.press(Key::Digit(6));
+        assert_eq!(keypad.poll(), Some(Key::Digit(6)));
+    }
+
+    #[test]
+    fn rows_are_released_between_scans() {
+        let matrix = Matrix::new();
+        let mut keypad = MatrixKeypad::new(matrix.rows(), matrix.cols(), PHONE_LAYOUT);
+        matrix.press(Key::Enter);
+        keypad.scan();
+        assert_eq!(matrix.selected(), None);
+    }
+}
@@ -0,0 +1,107 @@
+use state_machine_core::StateMachine;
+
+/// How many digits a PIN has
+pub const PIN_LENGTH: u8 = 4;
+
+/// Collects digits into a PIN. It keeps no more than the PIN itself, so it
+/// fits on the smallest microcontroller
+pub struct PinEntry;
+
+/// Where the entry is at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entry {
+    /// `digits` of the PIN typed so far, making up `value`
+    Typing { value: u16, digits: u8 },
+    /// Enter was pressed on a complete PIN
+    Submitted(u16),
+    /// The customer gave up
+    Cancelled,
+}
+
+impl Default for Entry {
+    fn default() -> Self {
+        Entry::Typing {
+            value: 0,
+            digits: 0,
+        }
+    }
+}
+
+impl StateMachine for PinEntry {
+    type State = Entry;
+    type Transition = crate::Key;
+
+    fn next_state(starting_state: &Entry, t: &crate::Key) -> Entry {
+        use crate::Key;
+        match (*starting_state, *t) {
+            (Entry::Typing { .. }, Key::Cancel) => Entry::Cancelled,
+            (Entry::Typing { .. }, Key::Clear) => Entry::default(),
+            (Entry::Typing { value, digits }, Key::Enter) if digits == PIN_LENGTH => {
+                Entry::Submitted(value)
+            }
+            (Entry::Typing { value, digits }, Key::Digit(digit)) if digits < PIN_LENGTH => {
+                Entry::Typing {
+                    value: value * 10 + u16::from(digit),
+                    digits: digits + 1,
+                }
+            }
+            // Enter on a short PIN, digits past the end, or a finished entry
+            (state, _) => state,
+        }
+    }
+
+    fn is_terminal(state: &Entry) -> bool {
+        !matches!(state, Entry::Typing { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Key;
+    use state_machine_core::run;
+
+    fn keys(typed: &str) -> impl Iterator<Item = Key> + '_ {
+        typed.chars().map(|c| match c {
+            '#' => Key::Enter,
+            '*' => Key::Clear,
+            'x' => Key::Cancel,
+            digit => Key::Digit(digit.to_digit(10).unwrap() as u8),
+        })
+    }
+
+    fn entry(typed: &str) -> Entry {
+        let keys: Vec<Key> = keys(typed).collect();
+        run::<PinEntry>(Entry::default(), &keys)
+    }
+
+    #[test]
+    fn submits_a_complete_pin() {
+        assert_eq!(entry("1234#"), Entry::Submitted(1234));
+        assert_eq!(entry("0007#"), Entry::Submitted(7));
+    }
+
+    #[test]
+    fn enter_needs_every_digit() {
+        assert_eq!(
+            entry("12#"),
+            Entry::Typing {
+                value: 12,
+                digits: 2
+            }
+        );
+        assert_eq!(entry("12#34#"), Entry::Submitted(1234));
+    }
+
+    #[test]
+    fn extra_digits_are_ignored() {
+        assert_eq!(entry("123456#"), Entry::Submitted(1234));
+    }
+
+    #[test]
+    fn clear_and_cancel() {
+        assert_eq!(entry("99*1234#"), Entry::Submitted(1234));
+        assert_eq!(entry("12x34#"), Entry::Cancelled);
+        assert_eq!(entry("1234#x"), Entry::Submitted(1234));
+    }
+}
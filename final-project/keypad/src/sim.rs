@@ -0,0 +1,90 @@
+//! A keypad of simulated pins, to run the keypad code on the host. Pressing
+//! a key connects its row to its column, as the real wiring does.
+
+use crate::hal::{InputPin, OutputPin};
+use crate::{Key, PHONE_LAYOUT};
+use core::cell::Cell;
+
+const ROWS: usize = PHONE_LAYOUT.len();
+const COLS: usize = PHONE_LAYOUT[0].len();
+
+/// The wiring of a simulated keypad with the [`PHONE_LAYOUT`]
+#[derive(Debug, Default)]
+pub struct Matrix {
+    pressed: Cell<Option<(usize, usize)>>,
+    selected: Cell<Option<usize>>,
+}
+
+impl Matrix {
+    pub fn new() -> Self {
+        Matrix::default()
+    }
+
+    /// Hold `key` down, releasing any other
+    pub fn press(&self, key: Key) {
+        let position = PHONE_LAYOUT.iter().enumerate().find_map(|(r, row)| {
+            let c = row.iter().position(|&k| k == key)?;
+            Some((r, c))
+        });
+        self.pressed.set(position);
+    }
+
+    /// Let go of the key held down
+    pub fn release(&self) {
+        self.pressed.set(None);
+    }
+
+    /// The row driven low right now
+    pub fn selected(&self) -> Option<usize> {
+        self.selected.get()
+    }
+
+    /// The row pins, to hand to a [`MatrixKeypad`](crate::MatrixKeypad)
+    pub fn rows(&self) -> [Row<'_>; ROWS] {
+        core::array::from_fn(|index| Row {
+            matrix: self,
+            index,
+        })
+    }
+
+    /// The column pins
+    pub fn cols(&self) -> [Col<'_>; COLS] {
+        core::array::from_fn(|index| Col {
+            matrix: self,
+            index,
+        })
+    }
+}
+
+/// A simulated row pin
+pub struct Row<'a> {
+    matrix: &'a Matrix,
+    index: usize,
+}
+
+impl OutputPin for Row<'_> {
+    fn set_low(&mut self) {
+        self.matrix.selected.set(Some(self.index));
+    }
+
+    fn set_high(&mut self) {
+        if self.matrix.selected.get() == Some(self.index) {
+            self.matrix.selected.set(None);
+        }
+    }
+}
+
+/// A simulated column pin
+pub struct Col<'a> {
+    matrix: &'a Matrix,
+    index: usize,
+}
+
+impl InputPin for Col<'_> {
+    fn is_low(&self) -> bool {
+        match (self.matrix.pressed.get(), self.matrix.selected.get()) {
+            (Some((row, col)), Some(selected)) => row == selected && col == self.index,
+            _ => false,
+        }
+    }
+}
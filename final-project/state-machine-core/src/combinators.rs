@@ -0,0 +1,73 @@
+use crate::StateMachine;
+use core::marker::PhantomData;
+
+/// Two machines fed the same transitions side by side. The combined state is
+/// the pair of their states, and it is terminal once both are
+///
+/// ```
+/// use state_machine_core::{run, Parallel, StateMachine};
+///
+/// struct Sum;
+/// struct Count;
+///
+/// impl StateMachine for Sum {
+///     type State = u32;
+///     type Transition = u32;
+///
+///     fn next_state(sum: &u32, n: &u32) -> u32 {
+///         sum + n
+///     }
+/// }
+///
+/// impl StateMachine for Count {
+///     type State = u32;
+///     type Transition = u32;
+///
+///     fn next_state(count: &u32, _: &u32) -> u32 {
+///         count + 1
+///     }
+/// }
+///
+/// assert_eq!(run::<Parallel<Sum, Count>>((0, 0), &[4, 5, 6]), (15, 3));
+/// ```
+pub struct Parallel<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> StateMachine for Parallel<A, B>
+where
+    A: StateMachine,
+    B: StateMachine<Transition = A::Transition>,
+{
+    type State = (A::State, B::State);
+    type Transition = A::Transition;
+
+    fn next_state((a, b): &Self::State, t: &Self::Transition) -> Self::State {
+        (A::next_state(a, t), B::next_state(b, t))
+    }
+
+    fn is_terminal((a, b): &Self::State) -> bool {
+        A::is_terminal(a) && B::is_terminal(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{Saturating, Switch};
+
+    type Both = Parallel<Saturating, Switch>;
+
+    #[test]
+    fn both_machines_see_every_transition() {
+        assert_eq!(crate::run::<Both>((0, false), &[1, 0, 1]), (2, false));
+        assert_eq!(crate::run::<Both>((0, false), &[2, 2]), (3, false));
+    }
+
+    #[test]
+    fn terminal_once_both_are() {
+        // The switch can always be flipped again
+        assert!(!Both::is_terminal(&(3, true)));
+        type Counters = Parallel<Saturating, Saturating>;
+        assert!(!Counters::is_terminal(&(3, 1)));
+        assert!(Counters::is_terminal(&(3, 3)));
+    }
+}
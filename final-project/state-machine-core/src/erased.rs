@@ -0,0 +1,169 @@
+use crate::{Runner, StateMachine};
+use core::fmt::Debug;
+
+/// A running machine whose state and transition types are hidden, so that
+/// machines of different types can be held together, for example in a
+/// `Vec<Box<dyn DynMachine<E>>>`, and all be fed the same events `E`
+pub trait DynMachine<E> {
+    /// Feed `event` to the machine, returning whether it took a transition.
+    /// Events it has no transition for, or any event once it is terminal,
+    /// are ignored
+    fn handle(&mut self, event: &E) -> bool;
+
+    /// The current state, for display
+    fn state(&self) -> &dyn Debug;
+
+    /// How many transitions the machine went through
+    fn steps(&self) -> usize;
+
+    /// Whether the machine reached a state it can never leave
+    fn is_terminal(&self) -> bool;
+}
+
+/// A [`Runner`] for `M` that turns shared events `E` into `M`'s transitions
+///
+/// ```
+/// use state_machine_core::{Adapted, DynMachine, StateMachine};
+///
+/// struct Bell;
+/// struct Lamp;
+///
+/// impl StateMachine for Bell {
+///     type State = u32;
+///     type Transition = ();
+///
+///     fn next_state(rings: &u32, _: &()) -> u32 {
+///         rings + 1
+///     }
+/// }
+///
+/// impl StateMachine for Lamp {
+///     type State = bool;
+///     type Transition = bool;
+///
+///     fn next_state(_: &bool, on: &bool) -> bool {
+///         *on
+///     }
+/// }
+///
+/// enum Event {
+///     Ring,
+///     Light(bool),
+/// }
+///
+/// let mut house: Vec<Box<dyn DynMachine<Event>>> = vec![
+///     Box::new(Adapted::<Bell, Event>::new(0, |e| matches!(e, Event::Ring).then_some(()))),
+///     Box::new(Adapted::<Lamp, Event>::new(false, |e| match e {
+///         Event::Light(on) => Some(*on),
+///         Event::Ring => None,
+///     })),
+/// ];
+/// for event in [Event::Ring, Event::Light(true), Event::Ring] {
+///     for machine in house.iter_mut() {
+///         machine.handle(&event);
+///     }
+/// }
+/// let states: Vec<String> = house.iter().map(|m| format!("{:?}", m.state())).collect();
+/// assert_eq!(states, ["2", "true"]);
+/// ```
+pub struct Adapted<M: StateMachine, E> {
+    runner: Runner<M>,
+    translate: fn(&E) -> Option<M::Transition>,
+}
+
+impl<M: StateMachine, E> Adapted<M, E> {
+    /// Start `M` in `state`. `translate` gives the transition for an event,
+    /// or `None` when the event does not concern this machine
+    pub fn new(state: M::State, translate: fn(&E) -> Option<M::Transition>) -> Self {
+        Adapted {
+            runner: Runner::new(state),
+            translate,
+        }
+    }
+
+    /// The runner underneath, with the state in its own type
+    pub fn runner(&self) -> &Runner<M> {
+        &self.runner
+    }
+}
+
+impl<M, E> DynMachine<E> for Adapted<M, E>
+where
+    M: StateMachine,
+    M::State: Debug,
+{
+    fn handle(&mut self, event: &E) -> bool {
+        match (self.translate)(event) {
+            Some(t) if !self.runner.is_terminal() => {
+                self.runner.step(&t);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn state(&self) -> &dyn Debug {
+        self.runner.state()
+    }
+
+    fn steps(&self) -> usize {
+        self.runner.steps()
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.runner.is_terminal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{Saturating, Switch};
+
+    enum Event {
+        Add(u8),
+        Flip,
+    }
+
+    fn machines() -> Vec<Box<dyn DynMachine<Event>>> {
+        vec![
+            Box::new(Adapted::<Saturating, Event>::new(0, |e| match e {
+                Event::Add(n) => Some(*n),
+                Event::Flip => None,
+            })),
+            Box::new(Adapted::<Switch, Event>::new(false, |e| match e {
+                Event::Flip => Some(1),
+                Event::Add(_) => None,
+            })),
+        ]
+    }
+
+    #[test]
+    fn each_machine_takes_its_own_events() {
+        let mut machines = machines();
+        let handled: Vec<bool> = machines
+            .iter_mut()
+            .map(|machine| machine.handle(&Event::Add(2)))
+            .collect();
+        assert_eq!(handled, [true, false]);
+        for machine in machines.iter_mut() {
+            machine.handle(&Event::Flip);
+        }
+        let states: Vec<String> = machines
+            .iter()
+            .map(|machine| format!("{:?}", machine.state()))
+            .collect();
+        assert_eq!(states, ["2", "true"]);
+        assert_eq!(machines[0].steps(), 1);
+        assert_eq!(machines[1].steps(), 1);
+    }
+
+    #[test]
+    fn terminal_machines_ignore_events() {
+        let mut machines = machines();
+        assert!(machines[0].handle(&Event::Add(5)));
+        assert!(machines[0].is_terminal());
+        assert!(!machines[0].handle(&Event::Add(1)));
+        assert!(!machines[1].is_terminal());
+    }
+}
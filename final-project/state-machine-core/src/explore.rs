@@ -0,0 +1,151 @@
+use crate::StateMachine;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Write};
+use std::hash::Hash;
+
+/// Every state reachable from `start` through any sequence of the transitions
+/// in `alphabet`, `start` first and then in breadth-first order
+///
+/// ```
+/// use state_machine_core::{reachable, StateMachine};
+///
+/// struct Mod3;
+///
+/// impl StateMachine for Mod3 {
+///     type State = u8;
+///     type Transition = u8;
+///
+///     fn next_state(n: &u8, step: &u8) -> u8 {
+///         (n + step) % 3
+///     }
+/// }
+///
+/// assert_eq!(reachable::<Mod3>(0, &[1]), [0, 1, 2]);
+/// assert_eq!(reachable::<Mod3>(0, &[0]), [0]);
+/// ```
+pub fn reachable<M>(start: M::State, alphabet: &[M::Transition]) -> Vec<M::State>
+where
+    M: StateMachine,
+    M::State: Clone + Eq + Hash,
+{
+    edges::<M>(start, alphabet).0
+}
+
+/// The reachable part of the machine as a Graphviz `digraph` named `name`,
+/// with one node per state and one edge per transition that changes the state.
+/// Terminal states are drawn with a double circle
+///
+/// ```
+/// use state_machine_core::{to_dot, StateMachine};
+///
+/// struct Door;
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// enum State { Open, Closed }
+///
+/// #[derive(Debug)]
+/// enum Push { Door }
+///
+/// impl StateMachine for Door {
+///     type State = State;
+///     type Transition = Push;
+///
+///     fn next_state(state: &State, _: &Push) -> State {
+///         match state {
+///             State::Open => State::Closed,
+///             State::Closed => State::Open,
+///         }
+///     }
+/// }
+///
+/// let dot = to_dot::<Door>("door", State::Open, &[Push::Door]);
+/// assert!(dot.contains("\"Open\" -> \"Closed\" [label=\"Door\"];"));
+/// ```
+pub fn to_dot<M>(name: &str, start: M::State, alphabet: &[M::Transition]) -> String
+where
+    M: StateMachine,
+    M::State: Clone + Eq + Hash + Debug,
+    M::Transition: Debug,
+{
+    let (states, edges) = edges::<M>(start, alphabet);
+    let mut dot = format!("digraph {name:?} {{\n");
+    for state in &states {
+        let shape = if M::is_terminal(state) {
+            "doublecircle"
+        } else {
+            "circle"
+        };
+        let _ = writeln!(dot, "    {:?} [shape={shape}];", format!("{state:?}"));
+    }
+    for (from, t, to) in edges {
+        let _ = writeln!(
+            dot,
+            "    {:?} -> {:?} [label={:?}];",
+            format!("{:?}", states[from]),
+            format!("{:?}", states[to]),
+            format!("{:?}", alphabet[t])
+        );
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+// Breadth-first search from `start`: the states in the order they are found,
+// and every (from, transition, to) edge between distinct states, by index
+type Edges = Vec<(usize, usize, usize)>;
+
+fn edges<M>(start: M::State, alphabet: &[M::Transition]) -> (Vec<M::State>, Edges)
+where
+    M: StateMachine,
+    M::State: Clone + Eq + Hash,
+{
+    let mut states = vec![start.clone()];
+    let mut index = HashMap::from([(start, 0)]);
+    let mut edges = Vec::new();
+    let mut queue = VecDeque::from([0]);
+    while let Some(from) = queue.pop_front() {
+        for (t, transition) in alphabet.iter().enumerate() {
+            let next = M::next_state(&states[from], transition);
+            let to = match index.get(&next) {
+                Some(&to) => to,
+                None => {
+                    states.push(next.clone());
+                    index.insert(next, states.len() - 1);
+                    queue.push_back(states.len() - 1);
+                    states.len() - 1
+                }
+            };
+            if to != from {
+                edges.push((from, t, to));
+            }
+        }
+    }
+    (states, edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{Saturating, Switch};
+
+    #[test]
+    fn reachable_in_breadth_first_order() {
+        assert_eq!(reachable::<Saturating>(0, &[2, 1]), [0, 2, 1, 3]);
+        assert_eq!(reachable::<Saturating>(3, &[1]), [3]);
+        assert_eq!(reachable::<Switch>(false, &[0, 1]), [false, true]);
+    }
+
+    #[test]
+    fn dot_export() {
+        assert_eq!(
+            to_dot::<Saturating>("count", 1, &[0, 2]),
+            concat!(
+                "digraph \"count\" {\n",
+                "    \"1\" [shape=circle];\n",
+                "    \"3\" [shape=doublecircle];\n",
+                "    \"1\" -> \"3\" [label=\"2\"];\n",
+                "}\n"
+            )
+        );
+    }
+}
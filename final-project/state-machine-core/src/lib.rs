@@ -0,0 +1,115 @@
+//! The state machine abstraction shared by the final project, its examples and
+//! the exercises that build on it.
+//!
+//! + [`StateMachine`] describes a machine by its states and transitions
+//! + [`run`], [`Runner`] and [`trace`] drive a machine through transitions
+//! + [`Parallel`] combines two machines fed the same transitions
+//! + [`DynMachine`] drives machines of different types through shared events
+//! + [`table_machine!`] defines a machine dispatched through a lookup table
+//! + `reachable` and `to_dot` explore and export a machine's state space,
+//!   `SimulationPool` runs many machines on several threads and `given` writes
+//!   tests as scenarios, with the default `std` feature
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![warn(missing_docs)]
+
+mod combinators;
+mod erased;
+#[cfg(feature = "std")]
+mod explore;
+#[cfg(feature = "std")]
+mod pool;
+mod runner;
+#[cfg(feature = "std")]
+mod scenario;
+mod table;
+
+pub use combinators::Parallel;
+pub use erased::{Adapted, DynMachine};
+#[cfg(feature = "std")]
+pub use explore::{reachable, to_dot};
+#[cfg(feature = "std")]
+pub use pool::SimulationPool;
+pub use runner::{trace, Runner, Trace};
+#[cfg(feature = "std")]
+pub use scenario::{given, Scenario};
+
+/// A state machine - Generic over the transition type
+pub trait StateMachine {
+    /// The states that can be occupied by this machine
+    type State;
+
+    /// The transitions that can be made between states
+    type Transition;
+
+    /// Calculate the resulting state when this state undergoes the given transition
+    fn next_state(starting_state: &Self::State, t: &Self::Transition) -> Self::State;
+
+    /// Whether the machine has reached a state it can never leave.
+    /// Machines that can always keep going use the default.
+    fn is_terminal(_state: &Self::State) -> bool {
+        false
+    }
+}
+
+/// Run the machine `M` from `start` through every transition in turn
+///
+/// ```
+/// use state_machine_core::{run, StateMachine};
+///
+/// struct Counter;
+///
+/// impl StateMachine for Counter {
+///     type State = u32;
+///     type Transition = u32;
+///
+///     fn next_state(count: &u32, step: &u32) -> u32 {
+///         count + step
+///     }
+/// }
+///
+/// assert_eq!(run::<Counter>(0, &[1, 2, 3]), 6);
+/// ```
+pub fn run<M: StateMachine>(start: M::State, transitions: &[M::Transition]) -> M::State {
+    transitions
+        .iter()
+        .fold(start, |state, t| M::next_state(&state, t))
+}
+
+// Small machines for the tests of every module
+#[cfg(test)]
+mod fixtures {
+    use super::StateMachine;
+
+    /// Counts up to 3 and stays there
+    pub struct Saturating;
+
+    impl StateMachine for Saturating {
+        type State = u8;
+        type Transition = u8;
+
+        fn next_state(count: &u8, step: &u8) -> u8 {
+            count.saturating_add(*step).min(3)
+        }
+
+        fn is_terminal(count: &u8) -> bool {
+            *count == 3
+        }
+    }
+
+    /// A light switch, flipped by `true` and left alone by `false`
+    pub struct Switch;
+
+    impl StateMachine for Switch {
+        type State = bool;
+        type Transition = u8;
+
+        fn next_state(on: &bool, step: &u8) -> bool {
+            if *step > 0 {
+                !on
+            } else {
+                *on
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{run, StateMachine};
+use std::num::NonZeroUsize;
+use std::thread;
+
+/// Runs many independent machines at once, split evenly over a fixed number
+/// of threads. Each simulation is a start state and the transitions to apply
+///
+/// ```
+/// use state_machine_core::{SimulationPool, StateMachine};
+///
+/// struct Counter;
+///
+/// impl StateMachine for Counter {
+///     type State = u32;
+///     type Transition = u32;
+///
+///     fn next_state(count: &u32, step: &u32) -> u32 {
+///         count + step
+///     }
+/// }
+///
+/// let steps: Vec<u32> = (1..=100).collect();
+/// let simulations: Vec<(u32, &[u32])> = (0..1_000).map(|start| (start, &steps[..])).collect();
+/// let ends = SimulationPool::new(4).run::<Counter>(&simulations);
+/// assert_eq!(ends[0], 5_050);
+/// assert_eq!(ends[999], 6_049);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulationPool {
+    threads: NonZeroUsize,
+}
+
+impl SimulationPool {
+    /// A pool of `threads` threads, at least one
+    pub fn new(threads: usize) -> Self {
+        SimulationPool {
+            threads: NonZeroUsize::new(threads).unwrap_or(NonZeroUsize::MIN),
+        }
+    }
+
+    /// A thread per core, or a single thread when the core count is unknown
+    pub fn per_core() -> Self {
+        SimulationPool {
+            threads: thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+        }
+    }
+
+    /// How many threads a run uses at most
+    pub fn threads(&self) -> usize {
+        self.threads.get()
+    }
+
+    /// Run every simulation to its end, returning the final states in the
+    /// order of `simulations`
+    pub fn run<M>(&self, simulations: &[(M::State, &[M::Transition])]) -> Vec<M::State>
+    where
+        M: StateMachine,
+        M::State: Clone + Send + Sync,
+        M::Transition: Sync,
+    {
+        if simulations.is_empty() {
+            return Vec::new();
+        }
+        let chunk = simulations.len().div_ceil(self.threads());
+        thread::scope(|scope| {
+            let workers: Vec<_> = simulations
+                .chunks(chunk)
+                .map(|part| {
+                    scope.spawn(move || {
+                        part.iter()
+                            .map(|(start, transitions)| run::<M>(start.clone(), transitions))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("a simulation panicked"))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Saturating;
+
+    #[test]
+    fn same_results_as_running_one_by_one() {
+        let scripts: Vec<Vec<u8>> = (0..50).map(|n| vec![n % 2, n % 3, 1]).collect();
+        let simulations: Vec<(u8, &[u8])> = scripts
+            .iter()
+            .enumerate()
+            .map(|(n, script)| ((n % 4) as u8, &script[..]))
+            .collect();
+        let expected: Vec<u8> = simulations
+            .iter()
+            .map(|(start, script)| run::<Saturating>(*start, script))
+            .collect();
+        for threads in [0, 1, 3, 8, 64] {
+            let pool = SimulationPool::new(threads);
+            assert_eq!(pool.run::<Saturating>(&simulations), expected, "{threads}");
+        }
+    }
+
+    #[test]
+    fn no_simulations() {
+        assert!(SimulationPool::per_core().run::<Saturating>(&[]).is_empty());
+        assert_eq!(SimulationPool::new(0).threads(), 1);
+    }
+}
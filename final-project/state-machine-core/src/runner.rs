@@ -0,0 +1,195 @@
+use crate::StateMachine;
+use core::borrow::Borrow;
+
+/// Owns the current state of a machine and steps it one transition at a time,
+/// for code that receives transitions as they happen instead of in a slice
+///
+/// ```
+/// use state_machine_core::{Runner, StateMachine};
+///
+/// struct Toggle;
+///
+/// impl StateMachine for Toggle {
+///     type State = bool;
+///     type Transition = ();
+///
+///     fn next_state(on: &bool, _: &()) -> bool {
+///         !on
+///     }
+/// }
+///
+/// let mut light = Runner::<Toggle>::new(false);
+/// assert!(*light.step(&()));
+/// assert_eq!(light.steps(), 1);
+/// ```
+pub struct Runner<M: StateMachine> {
+    state: M::State,
+    steps: usize,
+}
+
+impl<M: StateMachine> Runner<M> {
+    /// Start the machine in `state`
+    pub fn new(state: M::State) -> Self {
+        Runner { state, steps: 0 }
+    }
+
+    /// The current state
+    pub fn state(&self) -> &M::State {
+        &self.state
+    }
+
+    /// How many transitions the machine went through
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Apply `t`, returning the new state. A terminal machine does not move
+    /// any more and the transition is not counted
+    pub fn step(&mut self, t: &M::Transition) -> &M::State {
+        if !M::is_terminal(&self.state) {
+            self.state = M::next_state(&self.state, t);
+            self.steps += 1;
+        }
+        &self.state
+    }
+
+    /// Apply the transitions in order, returning the final state. Reaching a
+    /// terminal state with transitions left over stops the batch, and the
+    /// error is the index of the first transition that was not applied
+    ///
+    /// ```
+    /// use state_machine_core::{Runner, StateMachine};
+    ///
+    /// struct Countdown;
+    ///
+    /// impl StateMachine for Countdown {
+    ///     type State = u32;
+    ///     type Transition = u32;
+    ///
+    ///     fn next_state(left: &u32, step: &u32) -> u32 {
+    ///         left.saturating_sub(*step)
+    ///     }
+    ///
+    ///     fn is_terminal(left: &u32) -> bool {
+    ///         *left == 0
+    ///     }
+    /// }
+    ///
+    /// let mut rocket = Runner::<Countdown>::new(10);
+    /// assert_eq!(rocket.apply_all(&[3, 3]), Ok(&4));
+    /// assert_eq!(rocket.apply_all([1, 3, 2, 5]), Err(2));
+    /// assert_eq!(rocket.steps(), 4);
+    /// ```
+    pub fn apply_all<I>(&mut self, transitions: I) -> Result<&M::State, usize>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<M::Transition>,
+    {
+        for (index, t) in transitions.into_iter().enumerate() {
+            if M::is_terminal(&self.state) {
+                return Err(index);
+            }
+            self.step(t.borrow());
+        }
+        Ok(&self.state)
+    }
+
+    /// Whether the machine reached a state it can never leave
+    pub fn is_terminal(&self) -> bool {
+        M::is_terminal(&self.state)
+    }
+
+    /// Stop running and keep the final state
+    pub fn into_state(self) -> M::State {
+        self.state
+    }
+}
+
+/// Every state the machine goes through, from the one after the first
+/// transition to the one after the last. Returned by [`trace`]
+pub struct Trace<'a, M: StateMachine> {
+    state: Option<M::State>,
+    transitions: core::slice::Iter<'a, M::Transition>,
+}
+
+impl<M: StateMachine> Iterator for Trace<'_, M>
+where
+    M::State: Clone,
+{
+    type Item = M::State;
+
+    fn next(&mut self) -> Option<M::State> {
+        let t = self.transitions.next()?;
+        let next = M::next_state(self.state.as_ref()?, t);
+        self.state = Some(next.clone());
+        Some(next)
+    }
+}
+
+/// Run the machine `M` from `start`, yielding the state after each transition
+///
+/// ```
+/// use state_machine_core::{trace, StateMachine};
+///
+/// struct Counter;
+///
+/// impl StateMachine for Counter {
+///     type State = u32;
+///     type Transition = u32;
+///
+///     fn next_state(count: &u32, step: &u32) -> u32 {
+///         count + step
+///     }
+/// }
+///
+/// let states: Vec<u32> = trace::<Counter>(0, &[1, 2, 3]).collect();
+/// assert_eq!(states, [1, 3, 6]);
+/// ```
+pub fn trace<M: StateMachine>(start: M::State, transitions: &[M::Transition]) -> Trace<'_, M> {
+    Trace {
+        state: Some(start),
+        transitions: transitions.iter(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Saturating;
+
+    #[test]
+    fn runner_stops_at_a_terminal_state() {
+        let mut runner = Runner::<Saturating>::new(0);
+        assert_eq!(*runner.step(&2), 2);
+        assert!(!runner.is_terminal());
+        assert_eq!(*runner.step(&2), 3);
+        assert!(runner.is_terminal());
+        assert_eq!(*runner.step(&1), 3);
+        assert_eq!(runner.steps(), 2);
+        assert_eq!(runner.into_state(), 3);
+    }
+
+    #[test]
+    fn apply_all_reports_where_it_stopped() {
+        let mut runner = Runner::<Saturating>::new(0);
+        assert_eq!(runner.apply_all([1, 1].iter()), Ok(&2));
+        assert_eq!(runner.apply_all(Vec::<u8>::new()), Ok(&2));
+        // Reaching the terminal state on the last transition is not an error
+        assert_eq!(runner.apply_all([1]), Ok(&3));
+        assert_eq!(runner.apply_all([1, 1]), Err(0));
+        assert_eq!(runner.steps(), 3);
+
+        let mut runner = Runner::<Saturating>::new(0);
+        assert_eq!(runner.apply_all([2, 0, 1, 2, 2]), Err(3));
+        assert_eq!(*runner.state(), 3);
+    }
+
+    #[test]
+    fn trace_matches_run() {
+        let steps = [1, 0, 1, 1, 1];
+        let states: Vec<u8> = trace::<Saturating>(0, &steps).collect();
+        assert_eq!(states, [1, 1, 2, 3, 3]);
+        assert_eq!(states.last(), Some(&crate::run::<Saturating>(0, &steps)));
+        assert_eq!(trace::<Saturating>(0, &[]).count(), 0);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Runner, StateMachine};
+use std::fmt::{Debug, Write};
+
+/// Start a test scenario for `M` in `state`, to be followed by transitions
+/// with [`Scenario::when`] and checks with [`Scenario::then_state`] or
+/// [`Scenario::then`]. A failing check panics with the whole scenario:
+///
+/// ```should_panic
+/// use state_machine_core::{given, StateMachine};
+///
+/// struct Door;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum State {
+///     Open,
+///     Closed,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Push;
+///
+/// impl StateMachine for Door {
+///     type State = State;
+///     type Transition = Push;
+///
+///     fn next_state(state: &State, _: &Push) -> State {
+///         match state {
+///             State::Open => State::Closed,
+///             State::Closed => State::Open,
+///         }
+///     }
+/// }
+///
+/// given::<Door>(State::Open)
+///     .when(Push)
+///     .then_state(State::Closed)
+///     .when(Push)
+///     .then_state(State::Closed);
+/// // given Open
+/// //  when Push
+/// //  then the state is Closed
+/// //  when Push
+/// //  then the state should be Closed, but it is Open
+/// ```
+pub fn given<M>(state: M::State) -> Scenario<M>
+where
+    M: StateMachine,
+    M::State: Debug,
+{
+    Scenario {
+        story: format!("given {state:?}\n"),
+        runner: Runner::new(state),
+    }
+}
+
+/// A machine being taken through a test scenario, started by [`given`]
+pub struct Scenario<M: StateMachine> {
+    runner: Runner<M>,
+    // Every step so far, for the failure message
+    story: String,
+}
+
+impl<M> Scenario<M>
+where
+    M: StateMachine,
+    M::State: Debug,
+    M::Transition: Debug,
+{
+    /// Apply `t`
+    pub fn when(mut self, t: M::Transition) -> Self {
+        let _ = writeln!(self.story, " when {t:?}");
+        self.runner.step(&t);
+        self
+    }
+
+    /// Apply each transition in turn
+    pub fn when_all(self, transitions: impl IntoIterator<Item = M::Transition>) -> Self {
+        transitions.into_iter().fold(self, Scenario::when)
+    }
+
+    /// Check that the machine is in `expected`
+    #[track_caller]
+    pub fn then_state(mut self, expected: M::State) -> Self
+    where
+        M::State: PartialEq,
+    {
+        if *self.runner.state() != expected {
+            self.fail(format!(
+                "the state should be {expected:?}, but it is {:?}",
+                self.runner.state()
+            ));
+        }
+        let _ = writeln!(self.story, " then the state is {expected:?}");
+        self
+    }
+
+    /// Check that the state is `what`, as decided by `check`
+    #[track_caller]
+    pub fn then(mut self, what: &str, check: impl FnOnce(&M::State) -> bool) -> Self {
+        if !check(self.runner.state()) {
+            self.fail(format!(
+                "the state should be {what}, but it is {:?}",
+                self.runner.state()
+            ));
+        }
+        let _ = writeln!(self.story, " then the state is {what}");
+        self
+    }
+
+    /// The state the scenario ended in, for checks of its own
+    pub fn into_state(self) -> M::State {
+        self.runner.into_state()
+    }
+
+    #[track_caller]
+    fn fail(&self, message: String) -> ! {
+        panic!("{} then {message}", self.story)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Saturating;
+
+    #[test]
+    fn passing_scenario() {
+        let end = given::<Saturating>(0)
+            .when(1)
+            .then_state(1)
+            .when_all([1, 1, 1])
+            .then("terminal", Saturating::is_terminal)
+            .into_state();
+        assert_eq!(end, 3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "given 0\n when 2\n then the state is 2\n when 0\n then the state should be 3, but it is 2"
+    )]
+    fn failure_tells_the_story() {
+        given::<Saturating>(0)
+            .when(2)
+            .then_state(2)
+            .when(0)
+            .then_state(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "then the state should be terminal, but it is 1")]
+    fn failed_predicate() {
+        given::<Saturating>(0)
+            .when(1)
+            .then("terminal", Saturating::is_terminal);
+    }
+}
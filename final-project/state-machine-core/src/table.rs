@@ -0,0 +1,158 @@
+/// Define a machine with fieldless state and transition enums whose next
+/// state is looked up in a table, indexed by state then transition, instead
+/// of found by a `match`. The table is built at compile time from the rules,
+/// and a pair without a rule keeps the machine where it is.
+///
+/// The generated machine has `STATES` and `TRANSITIONS` constants listing
+/// every variant, ready for `reachable` or `to_dot`. States listed under the
+/// optional `terminal` are terminal.
+///
+/// ```
+/// use state_machine_core::{run, table_machine};
+///
+/// table_machine! {
+///     /// A coin-operated turnstile
+///     pub Turnstile {
+///         states: Gate { Locked, Unlocked, Broken },
+///         terminal: { Broken },
+///         transitions: Action { Coin, Push, Kick },
+///         Locked + Coin => Unlocked,
+///         Unlocked + Push => Locked,
+///         Locked + Kick => Broken,
+///     }
+/// }
+///
+/// assert_eq!(run::<Turnstile>(Gate::Locked, &[Action::Coin, Action::Push]), Gate::Locked);
+/// assert_eq!(run::<Turnstile>(Gate::Locked, &[Action::Kick, Action::Coin]), Gate::Broken);
+/// assert_eq!(Turnstile::STATES.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! table_machine {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $machine:ident {
+            states: $state:ident { $($variant:ident),+ $(,)? },
+            $(terminal: { $($end:ident),* $(,)? },)?
+            transitions: $event:ident { $($action:ident),+ $(,)? },
+            $($from:ident + $on:ident => $to:ident),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $machine;
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $state {
+            $($variant),+
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $event {
+            $($action),+
+        }
+
+        impl $machine {
+            /// Every state, in declaration order
+            $vis const STATES: &'static [$state] = &[$($state::$variant),+];
+
+            /// Every transition, in declaration order
+            $vis const TRANSITIONS: &'static [$event] = &[$($event::$action),+];
+
+            const NEXT: [[$state; $machine::TRANSITIONS.len()]; $machine::STATES.len()] = {
+                let mut next = [[$machine::STATES[0]; $machine::TRANSITIONS.len()];
+                    $machine::STATES.len()];
+                // Without a rule the machine stays where it is
+                let mut s = 0;
+                while s < next.len() {
+                    let mut t = 0;
+                    while t < next[s].len() {
+                        next[s][t] = $machine::STATES[s];
+                        t += 1;
+                    }
+                    s += 1;
+                }
+                $(next[$state::$from as usize][$event::$on as usize] = $state::$to;)*
+                next
+            };
+
+            const TERMINAL: [bool; $machine::STATES.len()] = {
+                #[allow(unused_mut)]
+                let mut terminal = [false; $machine::STATES.len()];
+                $($(terminal[$state::$end as usize] = true;)*)?
+                terminal
+            };
+        }
+
+        impl $crate::StateMachine for $machine {
+            type State = $state;
+            type Transition = $event;
+
+            fn next_state(starting_state: &$state, t: &$event) -> $state {
+                $machine::NEXT[*starting_state as usize][*t as usize]
+            }
+
+            fn is_terminal(state: &$state) -> bool {
+                $machine::TERMINAL[*state as usize]
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{run, StateMachine};
+
+    table_machine! {
+        Light {
+            states: Level { Off, Dim, Bright },
+            transitions: Press { Up, Down },
+            Off + Up => Dim,
+            Dim + Up => Bright,
+            Bright + Down => Dim,
+            Dim + Down => Off,
+        }
+    }
+
+    table_machine! {
+        Fuse {
+            states: Wire { Intact, Blown },
+            terminal: { Blown },
+            transitions: Current { Normal, Surge },
+            Intact + Surge => Blown,
+        }
+    }
+
+    // The same light written with a `match`, as the table must behave
+    fn matched(level: Level, press: Press) -> Level {
+        match (level, press) {
+            (Level::Off, Press::Up) | (Level::Bright, Press::Down) => Level::Dim,
+            (Level::Dim, Press::Up) | (Level::Bright, Press::Up) => Level::Bright,
+            (Level::Dim, Press::Down) | (Level::Off, Press::Down) => Level::Off,
+        }
+    }
+
+    #[test]
+    fn table_agrees_with_a_match() {
+        for &level in Light::STATES {
+            for &press in Light::TRANSITIONS {
+                assert_eq!(Light::next_state(&level, &press), matched(level, press));
+            }
+        }
+    }
+
+    #[test]
+    fn pairs_without_a_rule_stay_put() {
+        assert_eq!(Light::next_state(&Level::Off, &Press::Down), Level::Off);
+        assert_eq!(Light::next_state(&Level::Bright, &Press::Up), Level::Bright);
+        assert!(Light::STATES.iter().all(|level| !Light::is_terminal(level)));
+    }
+
+    #[test]
+    fn terminal_states() {
+        assert!(Fuse::is_terminal(&Wire::Blown));
+        assert!(!Fuse::is_terminal(&Wire::Intact));
+        assert_eq!(
+            run::<Fuse>(Wire::Intact, &[Current::Normal, Current::Surge]),
+            Wire::Blown
+        );
+    }
+}
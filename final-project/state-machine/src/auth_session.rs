@@ -0,0 +1,262 @@
+//! A reusable login session: identify, prove it with a credential, stay authenticated.
+//!
+//! This is the swipe-card / enter-PIN / authenticated flow of the ATM, generic over
+//! the credential type. Presenting an identity (swiping a card) tells the session
+//! which credential to expect, too many wrong credentials lock the session, and a
+//! session that sees no activity for `timeout_ticks` ticks falls back to waiting.
+
+use super::StateMachine;
+
+/// Where the session currently is in the authentication flow
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuthPhase<Credential> {
+    /// Nobody has identified themselves yet
+    Waiting,
+    /// An identity was presented and the matching credential is expected
+    Authenticating {
+        expected: Credential,
+        failed_attempts: u32,
+    },
+    /// The correct credential was supplied
+    Authenticated,
+    /// Too many wrong credentials, only an operator reset gets out of here
+    LockedOut,
+}
+
+/// Inputs driving the session
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuthEvent<Credential> {
+    /// Identify (e.g. swipe a card) carrying the credential that must be supplied
+    Identify(Credential),
+    /// Supply a credential (e.g. a PIN)
+    Submit(Credential),
+    /// One unit of time passed without user activity
+    Tick,
+    /// The user ends the session (e.g. takes the card back)
+    End,
+    /// An operator clears a lockout
+    Reset,
+}
+
+/// The authentication session together with its policy
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AuthSession<Credential> {
+    pub phase: AuthPhase<Credential>,
+    /// Wrong credentials tolerated before locking out
+    pub max_attempts: u32,
+    /// Idle ticks tolerated before the session times out
+    pub timeout_ticks: u32,
+    /// Ticks since the last user activity
+    pub idle_ticks: u32,
+}
+
+impl<Credential> AuthSession<Credential> {
+    /// A waiting session with the given lockout and timeout policy
+    pub fn new(max_attempts: u32, timeout_ticks: u32) -> Self {
+        AuthSession {
+            phase: AuthPhase::Waiting,
+            max_attempts,
+            timeout_ticks,
+            idle_ticks: 0,
+        }
+    }
+
+    pub fn is_authenticated(&self) -> bool {
+        matches!(self.phase, AuthPhase::Authenticated)
+    }
+
+    pub fn is_locked_out(&self) -> bool {
+        matches!(self.phase, AuthPhase::LockedOut)
+    }
+
+    fn with_phase(&self, phase: AuthPhase<Credential>) -> Self {
+        AuthSession {
+            phase,
+            max_attempts: self.max_attempts,
+            timeout_ticks: self.timeout_ticks,
+            idle_ticks: 0,
+        }
+    }
+}
+
+impl<Credential: Clone + PartialEq> StateMachine for AuthSession<Credential> {
+    type State = AuthSession<Credential>;
+    type Transition = AuthEvent<Credential>;
+
+    fn next_state(
+        starting_state: &AuthSession<Credential>,
+        t: &AuthEvent<Credential>,
+    ) -> AuthSession<Credential> {
+        match (&starting_state.phase, t) {
+            (AuthPhase::LockedOut, AuthEvent::Reset) => {
+                starting_state.with_phase(AuthPhase::Waiting)
+            }
+            (AuthPhase::LockedOut, _) => starting_state.clone(),
+
+            (AuthPhase::Waiting, AuthEvent::Identify(expected)) => {
+                starting_state.with_phase(AuthPhase::Authenticating {
+                    expected: expected.clone(),
+                    failed_attempts: 0,
+                })
+            }
+            (AuthPhase::Waiting, _) => starting_state.clone(),
+
+            (_, AuthEvent::End) => starting_state.with_phase(AuthPhase::Waiting),
+            (_, AuthEvent::Tick) => {
+                let idle_ticks = starting_state.idle_ticks + 1;
+                if idle_ticks >= starting_state.timeout_ticks {
+                    starting_state.with_phase(AuthPhase::Waiting)
+                } else {
+                    AuthSession {
+                        idle_ticks,
+                        ..starting_state.clone()
+                    }
+                }
+            }
+
+            (
+                AuthPhase::Authenticating {
+                    expected,
+                    failed_attempts,
+                },
+                AuthEvent::Submit(supplied),
+            ) => {
+                if supplied == expected {
+                    starting_state.with_phase(AuthPhase::Authenticated)
+                } else if failed_attempts + 1 >= starting_state.max_attempts {
+                    starting_state.with_phase(AuthPhase::LockedOut)
+                } else {
+                    starting_state.with_phase(AuthPhase::Authenticating {
+                        expected: expected.clone(),
+                        failed_attempts: failed_attempts + 1,
+                    })
+                }
+            }
+
+            // Identifying again mid-session, submitting once authenticated, or
+            // resetting a session that is not locked are all ignored
+            (
+                AuthPhase::Authenticating { .. } | AuthPhase::Authenticated,
+                AuthEvent::Identify(_) | AuthEvent::Submit(_) | AuthEvent::Reset,
+            ) => starting_state.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::given;
+
+    type Session = AuthSession<u16>;
+
+    fn run(start: Session, events: &[AuthEvent<u16>]) -> Session {
+        crate::run::<Session>(start, events)
+    }
+
+    fn authenticating(failed_attempts: u32) -> AuthPhase<u16> {
+        AuthPhase::Authenticating {
+            expected: 1234,
+            failed_attempts,
+        }
+    }
+
+    #[test]
+    fn identify_then_correct_credential() {
+        given::<Session>(Session::new(3, 10))
+            .when(AuthEvent::Identify(1234))
+            .then("authenticating", |s| s.phase == authenticating(0))
+            .when(AuthEvent::Submit(1234))
+            .then("authenticated", Session::is_authenticated);
+    }
+
+    #[test]
+    fn submit_without_identify_is_ignored() {
+        given::<Session>(Session::new(3, 10))
+            .when(AuthEvent::Submit(1234))
+            .then_state(Session::new(3, 10));
+    }
+
+    #[test]
+    fn wrong_then_correct_credential() {
+        given::<Session>(Session::new(3, 10))
+            .when(AuthEvent::Identify(1234))
+            .when(AuthEvent::Submit(1111))
+            .then("one attempt down", |s| s.phase == authenticating(1))
+            .when(AuthEvent::Submit(1234))
+            .then("authenticated", Session::is_authenticated);
+    }
+
+    #[test]
+    fn too_many_wrong_credentials_lock_out() {
+        given::<Session>(Session::new(2, 10))
+            .when_all([
+                AuthEvent::Identify(1234),
+                AuthEvent::Submit(1),
+                AuthEvent::Submit(2),
+            ])
+            .then("locked out", Session::is_locked_out);
+    }
+
+    #[test]
+    fn lockout_survives_everything_but_reset() {
+        let locked = run(
+            Session::new(1, 10),
+            &[AuthEvent::Identify(1234), AuthEvent::Submit(1)],
+        );
+        given::<Session>(locked.clone())
+            .when_all([
+                AuthEvent::End,
+                AuthEvent::Tick,
+                AuthEvent::Identify(1234),
+                AuthEvent::Submit(1234),
+            ])
+            .then_state(locked)
+            .when(AuthEvent::Reset)
+            .then_state(Session::new(1, 10));
+    }
+
+    #[test]
+    fn idle_session_times_out() {
+        given::<Session>(Session::new(3, 2))
+            .when(AuthEvent::Identify(1234))
+            .when(AuthEvent::Submit(1234))
+            .when(AuthEvent::Tick)
+            .then("still authenticated", Session::is_authenticated)
+            .when(AuthEvent::Tick)
+            .then("waiting", |s| s.phase == AuthPhase::Waiting);
+    }
+
+    #[test]
+    fn activity_restarts_the_timeout() {
+        given::<Session>(Session::new(3, 2))
+            .when(AuthEvent::Identify(1234))
+            .when(AuthEvent::Tick)
+            .when(AuthEvent::Submit(1111))
+            .when(AuthEvent::Tick)
+            .then("idle for one tick", |s| s.idle_ticks == 1)
+            .then("authenticating", |s| s.phase == authenticating(1));
+    }
+
+    #[test]
+    fn end_returns_to_waiting() {
+        given::<Session>(Session::new(3, 10))
+            .when_all([
+                AuthEvent::Identify(1234),
+                AuthEvent::Submit(1234),
+                AuthEvent::End,
+            ])
+            .then_state(Session::new(3, 10));
+    }
+
+    #[test]
+    fn works_with_any_credential_type() {
+        let start: AuthSession<String> = AuthSession::new(3, 10);
+        let events = [
+            AuthEvent::Identify("hunter2".to_string()),
+            AuthEvent::Submit("hunter2".to_string()),
+        ];
+        let end = crate::run::<AuthSession<String>>(start, &events);
+        assert!(end.is_authenticated());
+    }
+}
@@ -0,0 +1,242 @@
+//! A two-player chess clock.
+//!
+//! Each side has its own time budget, counted in ticks. Only the running side's
+//! budget goes down on a `Tick`, and pressing your button ends your move, adds the
+//! increment to your budget and starts the opponent's clock. When a budget reaches
+//! zero the flag falls: the game is over and the clock ignores every further input.
+
+use super::StateMachine;
+
+/// A player, by the colour of their pieces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    White,
+    Black,
+}
+
+impl Side {
+    /// The other player
+    pub fn opponent(self) -> Side {
+        match self {
+            Side::White => Side::Black,
+            Side::Black => Side::White,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Side::White => 0,
+            Side::Black => 1,
+        }
+    }
+}
+
+/// Whose time runs, if anyone's
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockStatus {
+    /// Waiting for Black to press and start White's clock
+    NotStarted,
+    /// The given side is thinking and its time runs
+    Running(Side),
+    /// Stopped by the arbiter while the given side was thinking
+    Paused(Side),
+    /// The given side ran out of time
+    FlagFell(Side),
+}
+
+/// Inputs from the players, the arbiter and the passing time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockEvent {
+    /// A side presses its button to end its move
+    Press(Side),
+    /// One unit of time passed
+    Tick,
+    /// The arbiter stops the running clock
+    Pause,
+    /// The arbiter restarts a paused clock for the side that was thinking
+    Resume,
+}
+
+/// The clock machine, which is its own state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChessClock {
+    /// Ticks left for White and Black
+    pub remaining: [u32; 2],
+    /// Ticks added to a side's budget after each of its moves
+    pub increment: u32,
+    /// Whose time runs, or why it does not
+    pub status: ClockStatus,
+}
+
+impl ChessClock {
+    /// A clock giving both sides `budget` ticks plus `increment` per move
+    pub fn new(budget: u32, increment: u32) -> Self {
+        ChessClock {
+            remaining: [budget, budget],
+            increment,
+            status: ClockStatus::NotStarted,
+        }
+    }
+
+    /// The ticks `side` has left
+    pub fn remaining(&self, side: Side) -> u32 {
+        self.remaining[side.index()]
+    }
+}
+
+impl StateMachine for ChessClock {
+    type State = ChessClock;
+    type Transition = ClockEvent;
+
+    fn next_state(starting_state: &ChessClock, t: &ClockEvent) -> ChessClock {
+        let mut next = starting_state.clone();
+
+        match (starting_state.status, t) {
+            // Black presses first to start White's clock, without an increment
+            (ClockStatus::NotStarted, ClockEvent::Press(Side::Black)) => {
+                next.status = ClockStatus::Running(Side::White);
+            }
+            (ClockStatus::Running(side), ClockEvent::Press(pressed)) if side == *pressed => {
+                let left = &mut next.remaining[side.index()];
+                *left = left.saturating_add(starting_state.increment);
+                next.status = ClockStatus::Running(side.opponent());
+            }
+            (ClockStatus::Running(side), ClockEvent::Tick) => {
+                let left = &mut next.remaining[side.index()];
+                *left = left.saturating_sub(1);
+                if *left == 0 {
+                    next.status = ClockStatus::FlagFell(side);
+                }
+            }
+            (ClockStatus::Running(side), ClockEvent::Pause) => {
+                next.status = ClockStatus::Paused(side);
+            }
+            (ClockStatus::Paused(side), ClockEvent::Resume) => {
+                next.status = ClockStatus::Running(side);
+            }
+            _ => {}
+        }
+
+        next
+    }
+
+    fn is_terminal(state: &ChessClock) -> bool {
+        matches!(state.status, ClockStatus::FlagFell(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_EVENTS: [ClockEvent; 5] = [
+        ClockEvent::Press(Side::White),
+        ClockEvent::Press(Side::Black),
+        ClockEvent::Tick,
+        ClockEvent::Pause,
+        ClockEvent::Resume,
+    ];
+
+    fn run(start: ChessClock, events: &[ClockEvent]) -> ChessClock {
+        crate::run::<ChessClock>(start, events)
+    }
+
+    #[test]
+    fn black_starts_whites_clock() {
+        let clock = run(ChessClock::new(10, 0), &[ClockEvent::Press(Side::White)]);
+        assert_eq!(clock.status, ClockStatus::NotStarted);
+        let clock = run(clock, &[ClockEvent::Press(Side::Black)]);
+        assert_eq!(clock.status, ClockStatus::Running(Side::White));
+    }
+
+    #[test]
+    fn ticks_only_count_down_the_running_side() {
+        let clock = run(
+            ChessClock::new(10, 0),
+            &[
+                ClockEvent::Tick,
+                ClockEvent::Press(Side::Black),
+                ClockEvent::Tick,
+                ClockEvent::Tick,
+                ClockEvent::Press(Side::White),
+                ClockEvent::Tick,
+            ],
+        );
+        assert_eq!(clock.remaining(Side::White), 8);
+        assert_eq!(clock.remaining(Side::Black), 9);
+        assert_eq!(clock.status, ClockStatus::Running(Side::Black));
+    }
+
+    #[test]
+    fn only_the_running_side_can_press() {
+        let start = run(ChessClock::new(10, 0), &[ClockEvent::Press(Side::Black)]);
+        assert_eq!(run(start.clone(), &[ClockEvent::Press(Side::Black)]), start);
+    }
+
+    #[test]
+    fn increment_is_added_after_each_move() {
+        let clock = run(
+            ChessClock::new(10, 3),
+            &[
+                ClockEvent::Press(Side::Black),
+                ClockEvent::Tick,
+                ClockEvent::Press(Side::White),
+            ],
+        );
+        assert_eq!(clock.remaining(Side::White), 12);
+        assert_eq!(clock.remaining(Side::Black), 10);
+    }
+
+    #[test]
+    fn increment_stops_at_the_largest_budget() {
+        let clock = run(
+            ChessClock::new(u32::MAX - 1, 5),
+            &[
+                ClockEvent::Press(Side::Black),
+                ClockEvent::Press(Side::White),
+            ],
+        );
+        assert_eq!(clock.remaining(Side::White), u32::MAX);
+        assert_eq!(clock.status, ClockStatus::Running(Side::Black));
+    }
+
+    #[test]
+    fn pause_freezes_time() {
+        let clock = run(
+            ChessClock::new(10, 0),
+            &[
+                ClockEvent::Press(Side::Black),
+                ClockEvent::Pause,
+                ClockEvent::Tick,
+                ClockEvent::Press(Side::White),
+                ClockEvent::Resume,
+            ],
+        );
+        assert_eq!(clock.remaining(Side::White), 10);
+        assert_eq!(clock.status, ClockStatus::Running(Side::White));
+    }
+
+    #[test]
+    fn flag_falls_when_time_runs_out() {
+        let clock = run(
+            ChessClock::new(2, 0),
+            &[ClockEvent::Press(Side::Black), ClockEvent::Tick],
+        );
+        assert!(!ChessClock::is_terminal(&clock));
+        let clock = run(clock, &[ClockEvent::Tick]);
+        assert_eq!(clock.status, ClockStatus::FlagFell(Side::White));
+        assert!(ChessClock::is_terminal(&clock));
+    }
+
+    #[test]
+    fn terminal_state_ignores_every_event() {
+        let flagged = run(
+            ChessClock::new(1, 5),
+            &[ClockEvent::Press(Side::Black), ClockEvent::Tick],
+        );
+        assert!(ChessClock::is_terminal(&flagged));
+        for event in ALL_EVENTS {
+            assert_eq!(ChessClock::next_state(&flagged, &event), flagged);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! A coffee machine that keeps track of the water, beans and cups left inside.
+//!
+//! Like the cash inside an ATM, every resource is finite: a brew only happens when
+//! there is enough of everything, otherwise the machine stays exactly as it was.
+//! After a fixed number of brews the machine must be descaled before it brews again.
+
+use super::StateMachine;
+
+/// Millilitres of water used by one cup of coffee
+pub const WATER_PER_CUP: u32 = 200;
+/// Grams of beans used by one cup of coffee
+pub const BEANS_PER_CUP: u32 = 18;
+/// Number of brews allowed between two descaling cycles
+pub const BREWS_PER_DESCALE: u32 = 10;
+
+/// Whether the machine is available for brewing or busy with maintenance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoffeeMode {
+    /// Ready to brew as long as resources last
+    Ready,
+    /// Running the descaling cycle, nothing can be brewed
+    Descaling,
+}
+
+/// Everything the user or the operator can do with the machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoffeeAction {
+    /// Brew one cup of coffee
+    Brew,
+    /// Pour the given amount of water (ml) into the tank
+    RefillWater(u32),
+    /// Pour the given amount of beans (g) into the hopper
+    RefillBeans(u32),
+    /// Stack the given number of cups into the dispenser
+    RefillCups(u32),
+    /// Enter the descale-maintenance state
+    StartDescale,
+    /// Leave the descale-maintenance state
+    FinishDescale,
+}
+
+/// The full state of the coffee machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoffeeMachine {
+    /// Water left in the tank (ml)
+    pub water: u32,
+    /// Beans left in the hopper (g)
+    pub beans: u32,
+    /// Cups left in the dispenser
+    pub cups: u32,
+    /// Brews made since the last descaling cycle
+    pub brews_since_descale: u32,
+    /// Current working mode
+    pub mode: CoffeeMode,
+}
+
+impl CoffeeMachine {
+    /// A freshly descaled machine holding the given resources
+    pub fn new(water: u32, beans: u32, cups: u32) -> Self {
+        CoffeeMachine {
+            water,
+            beans,
+            cups,
+            brews_since_descale: 0,
+            mode: CoffeeMode::Ready,
+        }
+    }
+
+    /// Whether the next `Brew` would actually produce a cup
+    pub fn can_brew(&self) -> bool {
+        self.mode == CoffeeMode::Ready
+            && self.water >= WATER_PER_CUP
+            && self.beans >= BEANS_PER_CUP
+            && self.cups > 0
+            && !self.needs_descale()
+    }
+
+    /// Whether the brew counter has reached the descaling interval
+    pub fn needs_descale(&self) -> bool {
+        self.brews_since_descale >= BREWS_PER_DESCALE
+    }
+}
+
+impl StateMachine for CoffeeMachine {
+    type State = CoffeeMachine;
+    type Transition = CoffeeAction;
+
+    fn next_state(starting_state: &CoffeeMachine, t: &CoffeeAction) -> CoffeeMachine {
+        let mut next = starting_state.clone();
+
+        match (starting_state.mode, t) {
+            (CoffeeMode::Ready, CoffeeAction::Brew) => {
+                if starting_state.can_brew() {
+                    next.water -= WATER_PER_CUP;
+                    next.beans -= BEANS_PER_CUP;
+                    next.cups -= 1;
+                    next.brews_since_descale += 1;
+                }
+            }
+            (CoffeeMode::Ready, CoffeeAction::RefillWater(amount)) => {
+                next.water = next.water.saturating_add(*amount);
+            }
+            (CoffeeMode::Ready, CoffeeAction::RefillBeans(amount)) => {
+                next.beans = next.beans.saturating_add(*amount);
+            }
+            (CoffeeMode::Ready, CoffeeAction::RefillCups(amount)) => {
+                next.cups = next.cups.saturating_add(*amount);
+            }
+            (CoffeeMode::Ready, CoffeeAction::StartDescale) => {
+                next.mode = CoffeeMode::Descaling;
+            }
+            (CoffeeMode::Descaling, CoffeeAction::FinishDescale) => {
+                next.mode = CoffeeMode::Ready;
+                next.brews_since_descale = 0;
+            }
+            // Finishing a descale that never started, or anything but finishing
+            // while descaling, leaves the machine untouched
+            (CoffeeMode::Ready, CoffeeAction::FinishDescale) | (CoffeeMode::Descaling, _) => {}
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_machine() -> CoffeeMachine {
+        CoffeeMachine::new(1_000, 100, 5)
+    }
+
+    #[test]
+    fn brew_consumes_resources() {
+        let end = CoffeeMachine::next_state(&full_machine(), &CoffeeAction::Brew);
+        let expected = CoffeeMachine {
+            water: 1_000 - WATER_PER_CUP,
+            beans: 100 - BEANS_PER_CUP,
+            cups: 4,
+            brews_since_descale: 1,
+            mode: CoffeeMode::Ready,
+        };
+        assert_eq!(end, expected);
+    }
+
+    #[test]
+    fn brew_refused_without_water() {
+        let start = CoffeeMachine::new(WATER_PER_CUP - 1, 100, 5);
+        let end = CoffeeMachine::next_state(&start, &CoffeeAction::Brew);
+        assert_eq!(end, start);
+    }
+
+    #[test]
+    fn brew_refused_without_beans() {
+        let start = CoffeeMachine::new(1_000, BEANS_PER_CUP - 1, 5);
+        let end = CoffeeMachine::next_state(&start, &CoffeeAction::Brew);
+        assert_eq!(end, start);
+    }
+
+    #[test]
+    fn brew_refused_without_cups() {
+        let start = CoffeeMachine::new(1_000, 100, 0);
+        let end = CoffeeMachine::next_state(&start, &CoffeeAction::Brew);
+        assert_eq!(end, start);
+    }
+
+    #[test]
+    fn refills_add_resources() {
+        let start = CoffeeMachine::new(0, 0, 0);
+        let refills = [
+            CoffeeAction::RefillWater(500),
+            CoffeeAction::RefillBeans(40),
+            CoffeeAction::RefillCups(3),
+        ];
+        let end = crate::run::<CoffeeMachine>(start, &refills);
+        assert_eq!(end, CoffeeMachine::new(500, 40, 3));
+    }
+
+    #[test]
+    fn refill_saturates_instead_of_overflowing() {
+        let start = CoffeeMachine::new(u32::MAX - 1, 0, 0);
+        let end = CoffeeMachine::next_state(&start, &CoffeeAction::RefillWater(10));
+        assert_eq!(end.water, u32::MAX);
+    }
+
+    #[test]
+    fn brew_refused_once_descale_is_due() {
+        let start = CoffeeMachine {
+            brews_since_descale: BREWS_PER_DESCALE,
+            ..full_machine()
+        };
+        assert!(start.needs_descale());
+        let end = CoffeeMachine::next_state(&start, &CoffeeAction::Brew);
+        assert_eq!(end, start);
+    }
+
+    #[test]
+    fn descaling_blocks_everything_but_finish() {
+        let start = CoffeeMachine {
+            mode: CoffeeMode::Descaling,
+            ..full_machine()
+        };
+        for t in [
+            CoffeeAction::Brew,
+            CoffeeAction::RefillWater(10),
+            CoffeeAction::RefillBeans(10),
+            CoffeeAction::RefillCups(10),
+            CoffeeAction::StartDescale,
+        ] {
+            assert_eq!(CoffeeMachine::next_state(&start, &t), start);
+        }
+    }
+
+    #[test]
+    fn finish_descale_resets_counter() {
+        let start = CoffeeMachine {
+            brews_since_descale: BREWS_PER_DESCALE,
+            mode: CoffeeMode::Descaling,
+            ..full_machine()
+        };
+        let end = CoffeeMachine::next_state(&start, &CoffeeAction::FinishDescale);
+        assert_eq!(end, full_machine());
+        assert!(end.can_brew());
+    }
+
+    #[test]
+    fn finish_descale_while_ready_does_nothing() {
+        let start = CoffeeMachine {
+            brews_since_descale: 3,
+            ..full_machine()
+        };
+        let end = CoffeeMachine::next_state(&start, &CoffeeAction::FinishDescale);
+        assert_eq!(end, start);
+    }
+}
@@ -0,0 +1,403 @@
+//! A streaming CSV/TSV record parser.
+//!
+//! The parser is a state machine fed one character at a time. Fields may be quoted
+//! with `"`, in which case they can contain delimiters, line breaks and doubled
+//! quotes (`""` stands for one `"`). Records end with `\n`, `\r\n` or a lone `\r`,
+//! and blank lines are skipped. [`records`] wraps it all into an iterator over any
+//! [`BufRead`], reporting malformed records without stopping at them.
+
+use super::StateMachine;
+use std::collections::VecDeque;
+use std::io::{self, BufRead};
+
+/// What went wrong while reading a record. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvError {
+    /// A `"` in the middle of an unquoted field
+    UnexpectedQuote { line: usize, column: usize },
+    /// Something other than a delimiter or line break right after a closing quote
+    TextAfterQuote { line: usize, column: usize },
+    /// The input ended inside a quoted field that started on `line`
+    UnterminatedQuote { line: usize },
+    /// The underlying reader failed
+    Io(io::ErrorKind),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Nothing of the current field has been read yet
+    FieldStart,
+    Unquoted,
+    Quoted,
+    /// A `"` inside a quoted field: either the closing quote or the first half of `""`
+    QuoteInQuoted,
+    /// Recovering from an error by dropping the rest of the line
+    Skipping,
+}
+
+/// The parser state: mode, partially read record and position in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvParser {
+    delimiter: char,
+    mode: Mode,
+    field: String,
+    record: Vec<String>,
+    line: usize,
+    column: usize,
+    quote_line: usize,
+    /// The last character was a `\r`, so a `\n` now belongs to the same line
+    after_cr: bool,
+    /// The record or error completed by the last character fed through `StateMachine`
+    pub emitted: Option<Result<Vec<String>, CsvError>>,
+}
+
+impl CsvParser {
+    /// A parser splitting fields at `delimiter`
+    pub fn new(delimiter: char) -> Self {
+        CsvParser {
+            delimiter,
+            mode: Mode::FieldStart,
+            field: String::new(),
+            record: Vec::new(),
+            line: 1,
+            column: 0,
+            quote_line: 1,
+            after_cr: false,
+            emitted: None,
+        }
+    }
+
+    /// Comma separated values
+    pub fn csv() -> Self {
+        CsvParser::new(',')
+    }
+
+    /// Tab separated values
+    pub fn tsv() -> Self {
+        CsvParser::new('\t')
+    }
+
+    /// Feed one character, returning the record or error it completes, if any
+    pub fn feed(&mut self, c: char) -> Option<Result<Vec<String>, CsvError>> {
+        self.column += 1;
+        let crlf = self.after_cr && c == '\n';
+        self.after_cr = c == '\r';
+        let emitted = match (self.mode, c) {
+            // The `\r` already ended the record and counted the line. Inside
+            // quotes the `\n` is kept, but still on the same line
+            (Mode::Quoted, '\n') if crlf => {
+                self.field.push('\n');
+                self.column = 0;
+                return None;
+            }
+            (_, '\n') if crlf => {
+                self.column = 0;
+                return None;
+            }
+
+            (Mode::Skipping, '\n' | '\r') => {
+                self.reset_record();
+                None
+            }
+            (Mode::Skipping, _) => None,
+
+            (Mode::Quoted, '"') => {
+                self.mode = Mode::QuoteInQuoted;
+                None
+            }
+            (Mode::Quoted, c) => {
+                self.field.push(c);
+                None
+            }
+            (Mode::QuoteInQuoted, '"') => {
+                self.field.push('"');
+                self.mode = Mode::Quoted;
+                None
+            }
+
+            (Mode::FieldStart, '"') => {
+                self.mode = Mode::Quoted;
+                self.quote_line = self.line;
+                None
+            }
+            (Mode::Unquoted, '"') => self.fail(CsvError::UnexpectedQuote {
+                line: self.line,
+                column: self.column,
+            }),
+
+            (_, c) if c == self.delimiter => {
+                self.end_field();
+                None
+            }
+            (_, '\n' | '\r') => {
+                let blank_line = self.mode == Mode::FieldStart && self.record.is_empty();
+                self.end_field();
+                let record = std::mem::take(&mut self.record);
+                (!blank_line).then_some(Ok(record))
+            }
+
+            (Mode::QuoteInQuoted, _) => self.fail(CsvError::TextAfterQuote {
+                line: self.line,
+                column: self.column,
+            }),
+            (Mode::FieldStart | Mode::Unquoted, c) => {
+                self.field.push(c);
+                self.mode = Mode::Unquoted;
+                None
+            }
+        };
+
+        if c == '\n' || c == '\r' {
+            self.line += 1;
+            self.column = 0;
+        }
+        emitted
+    }
+
+    /// Signal the end of the input, returning the last record or error, if any
+    pub fn finish(&mut self) -> Option<Result<Vec<String>, CsvError>> {
+        let emitted = match self.mode {
+            Mode::Quoted => Some(Err(CsvError::UnterminatedQuote {
+                line: self.quote_line,
+            })),
+            Mode::FieldStart if self.record.is_empty() => None,
+            Mode::Skipping => None,
+            Mode::FieldStart | Mode::Unquoted | Mode::QuoteInQuoted => {
+                self.end_field();
+                Some(Ok(std::mem::take(&mut self.record)))
+            }
+        };
+        self.reset_record();
+        emitted
+    }
+
+    fn end_field(&mut self) {
+        self.record.push(std::mem::take(&mut self.field));
+        self.mode = Mode::FieldStart;
+    }
+
+    fn reset_record(&mut self) {
+        self.field.clear();
+        self.record.clear();
+        self.mode = Mode::FieldStart;
+    }
+
+    fn fail(&mut self, error: CsvError) -> Option<Result<Vec<String>, CsvError>> {
+        self.mode = Mode::Skipping;
+        Some(Err(error))
+    }
+}
+
+impl StateMachine for CsvParser {
+    type State = CsvParser;
+    type Transition = char;
+
+    fn next_state(starting_state: &CsvParser, t: &char) -> CsvParser {
+        let mut next = starting_state.clone();
+        next.emitted = next.feed(*t);
+        next
+    }
+}
+
+/// Iterator over the records of a reader, see [`records`]
+pub struct Records<R> {
+    reader: R,
+    parser: CsvParser,
+    line: String,
+    ready: VecDeque<Result<Vec<String>, CsvError>>,
+    done: bool,
+}
+
+/// Parse the records of `reader` as they are read, using `parser`'s delimiter
+pub fn records<R: BufRead>(reader: R, parser: CsvParser) -> Records<R> {
+    Records {
+        reader,
+        parser,
+        line: String::new(),
+        ready: VecDeque::new(),
+        done: false,
+    }
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = Result<Vec<String>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.ready.is_empty() && !self.done {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    self.ready.extend(self.parser.finish());
+                }
+                Ok(_) => {
+                    for c in self.line.chars() {
+                        self.ready.extend(self.parser.feed(c));
+                    }
+                }
+                Err(error) => {
+                    self.done = true;
+                    self.ready.push_back(Err(CsvError::Io(error.kind())));
+                }
+            }
+        }
+        self.ready.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str, parser: CsvParser) -> Vec<Result<Vec<String>, CsvError>> {
+        records(input.as_bytes(), parser).collect()
+    }
+
+    fn ok(fields: &[&str]) -> Result<Vec<String>, CsvError> {
+        Ok(fields.iter().map(|field| field.to_string()).collect())
+    }
+
+    #[test]
+    fn simple_records() {
+        assert_eq!(
+            parse("a,b,c\n1,2,3\n", CsvParser::csv()),
+            vec![ok(&["a", "b", "c"]), ok(&["1", "2", "3"])]
+        );
+    }
+
+    #[test]
+    fn missing_trailing_newline() {
+        assert_eq!(
+            parse("a,b\nc,d", CsvParser::csv()),
+            vec![ok(&["a", "b"]), ok(&["c", "d"])]
+        );
+    }
+
+    #[test]
+    fn empty_fields_are_kept() {
+        assert_eq!(
+            parse(",a,,\n", CsvParser::csv()),
+            vec![ok(&["", "a", "", ""])]
+        );
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        assert_eq!(
+            parse("\na\n\n\nb\n", CsvParser::csv()),
+            vec![ok(&["a"]), ok(&["b"])]
+        );
+    }
+
+    #[test]
+    fn tabs_split_tsv() {
+        assert_eq!(
+            parse("name\tage\nAn, Binh\t30\n", CsvParser::tsv()),
+            vec![ok(&["name", "age"]), ok(&["An, Binh", "30"])]
+        );
+    }
+
+    #[test]
+    fn quoted_fields_hide_delimiters_and_newlines() {
+        assert_eq!(
+            parse("\"a,b\",\"line\nbreak\",c\n", CsvParser::csv()),
+            vec![ok(&["a,b", "line\nbreak", "c"])]
+        );
+    }
+
+    #[test]
+    fn doubled_quotes_are_escaped_quotes() {
+        assert_eq!(
+            parse("\"say \"\"hi\"\"\",\"\"\n", CsvParser::csv()),
+            vec![ok(&["say \"hi\"", ""])]
+        );
+    }
+
+    #[test]
+    fn crlf_and_lone_cr_end_records() {
+        assert_eq!(
+            parse("a,b\r\nc,d\re,f\r\n", CsvParser::csv()),
+            vec![ok(&["a", "b"]), ok(&["c", "d"]), ok(&["e", "f"])]
+        );
+    }
+
+    #[test]
+    fn crlf_inside_quotes_is_kept() {
+        assert_eq!(
+            parse("\"a\r\nb\"\r\n", CsvParser::csv()),
+            vec![ok(&["a\r\nb"])]
+        );
+    }
+
+    #[test]
+    fn quote_inside_unquoted_field() {
+        assert_eq!(
+            parse("ab\"c,d\nnext,line\n", CsvParser::csv()),
+            vec![
+                Err(CsvError::UnexpectedQuote { line: 1, column: 3 }),
+                ok(&["next", "line"])
+            ]
+        );
+    }
+
+    #[test]
+    fn text_after_closing_quote() {
+        assert_eq!(
+            parse("x\n\"ab\"c,d\n", CsvParser::csv()),
+            vec![
+                ok(&["x"]),
+                Err(CsvError::TextAfterQuote { line: 2, column: 5 })
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_quote_reports_where_it_started() {
+        assert_eq!(
+            parse("a\nb,\"open\nstill open\n", CsvParser::csv()),
+            vec![ok(&["a"]), Err(CsvError::UnterminatedQuote { line: 2 })]
+        );
+    }
+
+    #[test]
+    fn crlf_counts_as_one_line() {
+        assert_eq!(
+            parse("a\r\n\"b\r\nc", CsvParser::csv()),
+            vec![ok(&["a"]), Err(CsvError::UnterminatedQuote { line: 2 })]
+        );
+    }
+
+    #[test]
+    fn crlf_inside_quotes_counts_as_one_line() {
+        assert_eq!(
+            parse("\"a\r\nb\"\r\nx\"y\n", CsvParser::csv()),
+            vec![
+                ok(&["a\r\nb"]),
+                Err(CsvError::UnexpectedQuote { line: 3, column: 2 })
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_is_an_io_error() {
+        let input: &[u8] = b"a,b\n\xff\n";
+        let result: Vec<_> = records(input, CsvParser::csv()).collect();
+        assert_eq!(
+            result,
+            vec![
+                ok(&["a", "b"]),
+                Err(CsvError::Io(io::ErrorKind::InvalidData))
+            ]
+        );
+    }
+
+    #[test]
+    fn driven_through_the_state_machine_trait() {
+        let state = "a,b".chars().fold(CsvParser::csv(), |state, c| {
+            CsvParser::next_state(&state, &c)
+        });
+        assert_eq!(state.emitted, None);
+        let state = CsvParser::next_state(&state, &'\n');
+        assert_eq!(state.emitted, Some(ok(&["a", "b"])));
+    }
+}
@@ -0,0 +1,197 @@
+//! The classic garage door opener.
+//!
+//! The remote has a single button, and what it does depends entirely on the state
+//! the door is in: it starts the motor, stops it, or reverses the last direction.
+//! Limit switches report when the door is fully open or closed, and a safety beam
+//! reports obstructions so a closing door never comes down on anything.
+
+use super::StateMachine;
+
+/// The direction the motor was running before the door was stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+/// Every position the door can be in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DoorState {
+    Open,
+    Closed,
+    Opening,
+    Closing,
+    /// Halfway, remembering which way it was moving
+    Stopped(Direction),
+}
+
+/// Inputs coming from the remote and the sensors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DoorEvent {
+    /// The single remote button was pressed
+    Button,
+    /// The safety beam detected something in the doorway
+    Obstruction,
+    /// The upper limit switch was hit
+    ReachedTop,
+    /// The lower limit switch was hit
+    ReachedBottom,
+}
+
+/// The garage door opener machine
+pub struct GarageDoor;
+
+impl StateMachine for GarageDoor {
+    type State = DoorState;
+    type Transition = DoorEvent;
+
+    fn next_state(starting_state: &DoorState, t: &DoorEvent) -> DoorState {
+        match (starting_state, t) {
+            // The button starts, stops or reverses the motor
+            (DoorState::Closed, DoorEvent::Button) => DoorState::Opening,
+            (DoorState::Open, DoorEvent::Button) => DoorState::Closing,
+            (DoorState::Opening, DoorEvent::Button) => DoorState::Stopped(Direction::Up),
+            (DoorState::Closing, DoorEvent::Button) => DoorState::Stopped(Direction::Down),
+            (DoorState::Stopped(Direction::Up), DoorEvent::Button) => DoorState::Closing,
+            (DoorState::Stopped(Direction::Down), DoorEvent::Button) => DoorState::Opening,
+
+            // Limit switches only matter while the motor runs towards them
+            (DoorState::Opening, DoorEvent::ReachedTop) => DoorState::Open,
+            (DoorState::Closing, DoorEvent::ReachedBottom) => DoorState::Closed,
+
+            // Safety first: a closing door reverses when something is in the way
+            (DoorState::Closing, DoorEvent::Obstruction) => DoorState::Opening,
+
+            // Every other combination is ignored
+            (state, _) => *state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_STATES: [DoorState; 6] = [
+        DoorState::Open,
+        DoorState::Closed,
+        DoorState::Opening,
+        DoorState::Closing,
+        DoorState::Stopped(Direction::Up),
+        DoorState::Stopped(Direction::Down),
+    ];
+
+    const ALL_EVENTS: [DoorEvent; 4] = [
+        DoorEvent::Button,
+        DoorEvent::Obstruction,
+        DoorEvent::ReachedTop,
+        DoorEvent::ReachedBottom,
+    ];
+
+    fn reachable_from(start: DoorState) -> Vec<DoorState> {
+        crate::reachable::<GarageDoor>(start, &ALL_EVENTS)
+    }
+
+    #[test]
+    fn full_cycle_with_limit_switches() {
+        let script = [
+            (DoorEvent::Button, DoorState::Opening),
+            (DoorEvent::ReachedTop, DoorState::Open),
+            (DoorEvent::Button, DoorState::Closing),
+            (DoorEvent::ReachedBottom, DoorState::Closed),
+        ];
+        let mut state = DoorState::Closed;
+        for (event, expected) in script {
+            state = GarageDoor::next_state(&state, &event);
+            assert_eq!(state, expected);
+        }
+    }
+
+    #[test]
+    fn button_always_changes_state() {
+        for state in ALL_STATES {
+            assert_ne!(GarageDoor::next_state(&state, &DoorEvent::Button), state);
+        }
+    }
+
+    #[test]
+    fn stopped_door_reverses_direction() {
+        assert_eq!(
+            GarageDoor::next_state(&DoorState::Stopped(Direction::Up), &DoorEvent::Button),
+            DoorState::Closing
+        );
+        assert_eq!(
+            GarageDoor::next_state(&DoorState::Stopped(Direction::Down), &DoorEvent::Button),
+            DoorState::Opening
+        );
+    }
+
+    #[test]
+    fn obstruction_reverses_closing_door() {
+        assert_eq!(
+            GarageDoor::next_state(&DoorState::Closing, &DoorEvent::Obstruction),
+            DoorState::Opening
+        );
+    }
+
+    #[test]
+    fn every_state_is_reachable() {
+        for start in ALL_STATES {
+            assert_eq!(
+                reachable_from(start).len(),
+                ALL_STATES.len(),
+                "from {start:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn obstruction_never_moves_door_down() {
+        for state in ALL_STATES {
+            let next = GarageDoor::next_state(&state, &DoorEvent::Obstruction);
+            assert!(
+                next == state || !matches!(next, DoorState::Closing | DoorState::Closed),
+                "{state:?} moved to {next:?} on obstruction"
+            );
+        }
+    }
+
+    #[test]
+    fn limit_switches_only_stop_a_moving_door() {
+        for state in ALL_STATES {
+            let top = GarageDoor::next_state(&state, &DoorEvent::ReachedTop);
+            let bottom = GarageDoor::next_state(&state, &DoorEvent::ReachedBottom);
+            match state {
+                DoorState::Opening => assert_eq!(top, DoorState::Open),
+                _ => assert_eq!(top, state),
+            }
+            match state {
+                DoorState::Closing => assert_eq!(bottom, DoorState::Closed),
+                _ => assert_eq!(bottom, state),
+            }
+        }
+    }
+
+    #[test]
+    fn door_only_closes_through_closing() {
+        for state in ALL_STATES {
+            for event in ALL_EVENTS {
+                let next = GarageDoor::next_state(&state, &event);
+                if next == DoorState::Closed && state != DoorState::Closed {
+                    assert_eq!(
+                        (state, event),
+                        (DoorState::Closing, DoorEvent::ReachedBottom)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_never_terminal() {
+        // The default `is_terminal` of the trait
+        for state in ALL_STATES {
+            assert!(!GarageDoor::is_terminal(&state), "{state:?}");
+        }
+    }
+}
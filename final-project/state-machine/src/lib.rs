@@ -0,0 +1,17 @@
+//! State machines for the final project.
+//!
+//! Every machine implements the [`StateMachine`] trait from `state-machine-core` and lives in its own module.
+
+pub mod auth_session;
+pub mod chess_clock;
+pub mod coffee_machine;
+pub mod csv;
+pub mod garage_door;
+pub mod regex;
+pub mod rock_paper_scissors;
+pub mod washing_machine;
+
+pub use state_machine_core::{
+    given, reachable, run, table_machine, to_dot, trace, Adapted, DynMachine, Parallel, Runner,
+    SimulationPool, StateMachine,
+};
@@ -0,0 +1,530 @@
+//! Regular expressions compiled into finite automata.
+//!
+//! A pattern goes through the textbook pipeline: it is parsed, turned into an NFA
+//! with Thompson's construction, determinized with the subset construction and can
+//! then be minimized. The resulting DFA is driven one character at a time through
+//! the [`StateMachine`] trait implemented by [`DfaCursor`].
+//!
+//! Supported syntax: literal characters, `.` (any character), `|`, `*`, `+`, `?`,
+//! parentheses for grouping and `\` to escape any of the above. A pattern matches
+//! the whole input, never a part of it.
+
+use super::StateMachine;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+/// Why a pattern could not be compiled, with the character position of the problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegexError {
+    /// A `(` without its `)` or the other way around
+    UnbalancedParen(usize),
+    /// `*`, `+` or `?` with nothing to repeat
+    NothingToRepeat(usize),
+    /// The pattern ends with a lone `\`
+    TrailingEscape(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Ast {
+    Empty,
+    Char(char),
+    Any,
+    Concat(Box<Ast>, Box<Ast>),
+    Alt(Box<Ast>, Box<Ast>),
+    Star(Box<Ast>),
+    Plus(Box<Ast>),
+    Optional(Box<Ast>),
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn parse(pattern: &str) -> Result<Ast, RegexError> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+        };
+        let ast = parser.alternation()?;
+        match parser.peek() {
+            None => Ok(ast),
+            Some(_) => Err(RegexError::UnbalancedParen(parser.pos)),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn alternation(&mut self) -> Result<Ast, RegexError> {
+        let mut ast = self.concatenation()?;
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            let right = self.concatenation()?;
+            ast = Ast::Alt(Box::new(ast), Box::new(right));
+        }
+        Ok(ast)
+    }
+
+    fn concatenation(&mut self) -> Result<Ast, RegexError> {
+        let mut ast = Ast::Empty;
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let next = self.repetition()?;
+            ast = match ast {
+                Ast::Empty => next,
+                ast => Ast::Concat(Box::new(ast), Box::new(next)),
+            };
+        }
+        Ok(ast)
+    }
+
+    fn repetition(&mut self) -> Result<Ast, RegexError> {
+        let mut ast = self.atom()?;
+        while let Some(c) = self.peek() {
+            ast = match c {
+                '*' => Ast::Star(Box::new(ast)),
+                '+' => Ast::Plus(Box::new(ast)),
+                '?' => Ast::Optional(Box::new(ast)),
+                _ => break,
+            };
+            self.pos += 1;
+        }
+        Ok(ast)
+    }
+
+    fn atom(&mut self) -> Result<Ast, RegexError> {
+        let start = self.pos;
+        // `concatenation` only calls us when there is a character left
+        let c = self.chars[start];
+        self.pos += 1;
+        match c {
+            '.' => Ok(Ast::Any),
+            '*' | '+' | '?' => Err(RegexError::NothingToRepeat(start)),
+            '\\' => match self.peek() {
+                Some(escaped) => {
+                    self.pos += 1;
+                    Ok(Ast::Char(escaped))
+                }
+                None => Err(RegexError::TrailingEscape(start)),
+            },
+            '(' => {
+                let inner = self.alternation()?;
+                if self.peek() != Some(')') {
+                    return Err(RegexError::UnbalancedParen(start));
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            c => Ok(Ast::Char(c)),
+        }
+    }
+}
+
+/// What an NFA edge consumes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Symbol {
+    Char(char),
+    Any,
+}
+
+#[derive(Debug, Default, Clone)]
+struct NfaNode {
+    epsilon: Vec<usize>,
+    edges: Vec<(Symbol, usize)>,
+}
+
+/// A Thompson NFA with a single start and a single accepting node
+#[derive(Debug, Clone)]
+pub struct Nfa {
+    nodes: Vec<NfaNode>,
+    start: usize,
+    accept: usize,
+}
+
+impl Nfa {
+    /// Parse `pattern` and build its NFA
+    pub fn compile(pattern: &str) -> Result<Nfa, RegexError> {
+        let ast = Parser::parse(pattern)?;
+        let mut nfa = Nfa {
+            nodes: Vec::new(),
+            start: 0,
+            accept: 0,
+        };
+        let (start, accept) = nfa.fragment(&ast);
+        nfa.start = start;
+        nfa.accept = accept;
+        Ok(nfa)
+    }
+
+    /// Number of NFA nodes, start and accept included. An NFA has no dead state
+    pub fn state_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn node(&mut self) -> usize {
+        self.nodes.push(NfaNode::default());
+        self.nodes.len() - 1
+    }
+
+    // Builds the fragment for `ast` and returns its (entry, exit) nodes
+    fn fragment(&mut self, ast: &Ast) -> (usize, usize) {
+        match ast {
+            Ast::Empty => {
+                let (entry, exit) = (self.node(), self.node());
+                self.nodes[entry].epsilon.push(exit);
+                (entry, exit)
+            }
+            Ast::Char(c) => self.single(Symbol::Char(*c)),
+            Ast::Any => self.single(Symbol::Any),
+            Ast::Concat(left, right) => {
+                let (entry, middle) = self.fragment(left);
+                let (next, exit) = self.fragment(right);
+                self.nodes[middle].epsilon.push(next);
+                (entry, exit)
+            }
+            Ast::Alt(left, right) => {
+                let (entry, exit) = (self.node(), self.node());
+                for branch in [left, right] {
+                    let (inner_entry, inner_exit) = self.fragment(branch);
+                    self.nodes[entry].epsilon.push(inner_entry);
+                    self.nodes[inner_exit].epsilon.push(exit);
+                }
+                (entry, exit)
+            }
+            Ast::Star(inner) | Ast::Plus(inner) | Ast::Optional(inner) => {
+                let (entry, exit) = (self.node(), self.node());
+                let (inner_entry, inner_exit) = self.fragment(inner);
+                self.nodes[entry].epsilon.push(inner_entry);
+                self.nodes[inner_exit].epsilon.push(exit);
+                if !matches!(ast, Ast::Plus(_)) {
+                    self.nodes[entry].epsilon.push(exit);
+                }
+                if !matches!(ast, Ast::Optional(_)) {
+                    self.nodes[inner_exit].epsilon.push(inner_entry);
+                }
+                (entry, exit)
+            }
+        }
+    }
+
+    fn single(&mut self, symbol: Symbol) -> (usize, usize) {
+        let (entry, exit) = (self.node(), self.node());
+        self.nodes[entry].edges.push((symbol, exit));
+        (entry, exit)
+    }
+
+    fn closure(&self, seeds: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+        let mut set = BTreeSet::new();
+        let mut stack: Vec<usize> = seeds.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            if set.insert(node) {
+                stack.extend(&self.nodes[node].epsilon);
+            }
+        }
+        set
+    }
+
+    fn alphabet(&self) -> Vec<char> {
+        let chars: BTreeSet<char> = self
+            .nodes
+            .iter()
+            .flat_map(|node| &node.edges)
+            .filter_map(|(symbol, _)| match symbol {
+                Symbol::Char(c) => Some(*c),
+                Symbol::Any => None,
+            })
+            .collect();
+        chars.into_iter().collect()
+    }
+
+    /// Determinize with the subset construction
+    pub fn to_dfa(&self) -> Dfa {
+        let alphabet = self.alphabet();
+        // One column per alphabet character plus one for every other character
+        let columns = alphabet.len() + 1;
+
+        let start = self.closure([self.start]);
+        let mut ids = HashMap::from([(start.clone(), 0)]);
+        let mut sets = vec![start];
+        let mut transitions = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(id) = queue.pop_front() {
+            let mut row = Vec::with_capacity(columns);
+            for column in 0..columns {
+                let wanted = alphabet.get(column).copied();
+                let targets = sets[id].iter().flat_map(|&node| {
+                    self.nodes[node]
+                        .edges
+                        .iter()
+                        .filter(move |(symbol, _)| match symbol {
+                            Symbol::Any => true,
+                            Symbol::Char(c) => wanted == Some(*c),
+                        })
+                        .map(|(_, target)| *target)
+                });
+                let set = self.closure(targets);
+                let target = match ids.get(&set) {
+                    Some(&existing) => existing,
+                    None => {
+                        let new_id = sets.len();
+                        ids.insert(set.clone(), new_id);
+                        sets.push(set);
+                        queue.push_back(new_id);
+                        new_id
+                    }
+                };
+                row.push(target);
+            }
+            transitions.push((id, row));
+        }
+
+        transitions.sort_by_key(|(id, _)| *id);
+        Dfa {
+            accepting: sets.iter().map(|set| set.contains(&self.accept)).collect(),
+            transitions: transitions.into_iter().map(|(_, row)| row).collect(),
+            alphabet,
+            start: 0,
+        }
+    }
+}
+
+/// A complete deterministic automaton: every state has a target for every character
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dfa {
+    /// Characters with their own column, sorted
+    alphabet: Vec<char>,
+    /// `transitions[state][column]`, the last column covers characters outside the alphabet
+    transitions: Vec<Vec<usize>>,
+    accepting: Vec<bool>,
+    start: usize,
+}
+
+impl Dfa {
+    /// Parse `pattern`, build its NFA and determinize it
+    pub fn compile(pattern: &str) -> Result<Dfa, RegexError> {
+        Ok(Nfa::compile(pattern)?.to_dfa())
+    }
+
+    /// Number of DFA states, including the dead state whenever some input can reach it
+    pub fn state_count(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Whether the whole of `input` matches the pattern
+    pub fn matches(&self, input: &str) -> bool {
+        input
+            .chars()
+            .fold(self.cursor(), |cursor, c| {
+                DfaCursor::next_state(&cursor, &c)
+            })
+            .is_accepting()
+    }
+
+    /// A cursor sitting on the start state
+    pub fn cursor(&self) -> DfaCursor<'_> {
+        DfaCursor {
+            dfa: self,
+            current: self.start,
+        }
+    }
+
+    fn column(&self, c: char) -> usize {
+        self.alphabet
+            .binary_search(&c)
+            .unwrap_or(self.alphabet.len())
+    }
+
+    /// The equivalent DFA with the fewest states, by Moore's partition refinement
+    pub fn minimize(&self) -> Dfa {
+        // Start from accepting vs non-accepting and split until nothing changes
+        let mut class: Vec<usize> = self.accepting.iter().map(|&a| usize::from(a)).collect();
+        loop {
+            let mut signatures = HashMap::new();
+            let refined: Vec<usize> = (0..self.state_count())
+                .map(|state| {
+                    let signature = (
+                        class[state],
+                        self.transitions[state]
+                            .iter()
+                            .map(|&target| class[target])
+                            .collect::<Vec<_>>(),
+                    );
+                    let next_id = signatures.len();
+                    *signatures.entry(signature).or_insert(next_id)
+                })
+                .collect();
+            let stable = signatures.len() == class.iter().collect::<BTreeSet<_>>().len();
+            class = refined;
+            if stable {
+                break;
+            }
+        }
+
+        let count = class.iter().max().map_or(0, |max| max + 1);
+        let mut transitions = vec![Vec::new(); count];
+        let mut accepting = vec![false; count];
+        for state in 0..self.state_count() {
+            transitions[class[state]] = self.transitions[state]
+                .iter()
+                .map(|&target| class[target])
+                .collect();
+            accepting[class[state]] = self.accepting[state];
+        }
+
+        Dfa {
+            alphabet: self.alphabet.clone(),
+            transitions,
+            accepting,
+            start: class[self.start],
+        }
+    }
+}
+
+/// The state of a running DFA: the automaton and the node it is currently on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DfaCursor<'a> {
+    dfa: &'a Dfa,
+    current: usize,
+}
+
+impl DfaCursor<'_> {
+    /// Whether the input consumed so far matches the pattern
+    pub fn is_accepting(&self) -> bool {
+        self.dfa.accepting[self.current]
+    }
+}
+
+impl<'a> StateMachine for DfaCursor<'a> {
+    type State = DfaCursor<'a>;
+    type Transition = char;
+
+    fn next_state(starting_state: &DfaCursor<'a>, t: &char) -> DfaCursor<'a> {
+        let dfa = starting_state.dfa;
+        DfaCursor {
+            dfa,
+            current: dfa.transitions[starting_state.current][dfa.column(*t)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(pattern: &str, matching: &[&str], rejected: &[&str]) {
+        let dfa = Dfa::compile(pattern).unwrap();
+        let minimal = dfa.minimize();
+        for input in matching {
+            assert!(dfa.matches(input), "{pattern} should match {input:?}");
+            assert!(
+                minimal.matches(input),
+                "minimal {pattern} should match {input:?}"
+            );
+        }
+        for input in rejected {
+            assert!(!dfa.matches(input), "{pattern} should reject {input:?}");
+            assert!(
+                !minimal.matches(input),
+                "minimal {pattern} should reject {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn literals_match_whole_input() {
+        check("abc", &["abc"], &["", "ab", "abcd", "xabc"]);
+    }
+
+    #[test]
+    fn alternation() {
+        check("cat|dog", &["cat", "dog"], &["", "ca", "catdog", "cog"]);
+    }
+
+    #[test]
+    fn repetition_operators() {
+        check("ab*", &["a", "ab", "abbb"], &["", "b", "aba"]);
+        check("ab+", &["ab", "abbb"], &["a", "b"]);
+        check("ab?c", &["ac", "abc"], &["abbc", "a"]);
+    }
+
+    #[test]
+    fn groups_and_any() {
+        check("(a|b)*c", &["c", "abc", "bbac"], &["", "ab", "cc"]);
+        check("a.c", &["abc", "a-c", "aac", "aéc"], &["ac", "abbc"]);
+        check(".*", &["", "anything at all"], &[]);
+    }
+
+    #[test]
+    fn empty_pattern_and_branches() {
+        check("", &[""], &["a"]);
+        check("a|", &["a", ""], &["aa"]);
+        check("()", &[""], &["a"]);
+    }
+
+    #[test]
+    fn escaped_operators_are_literals() {
+        check(r"a\*", &["a*"], &["a", "aa"]);
+        check(r"\(\)", &["()"], &[""]);
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        assert_eq!(Dfa::compile("(ab"), Err(RegexError::UnbalancedParen(0)));
+        assert_eq!(Dfa::compile("ab)"), Err(RegexError::UnbalancedParen(2)));
+        assert_eq!(Dfa::compile("*a"), Err(RegexError::NothingToRepeat(0)));
+        assert_eq!(Dfa::compile("a|+"), Err(RegexError::NothingToRepeat(2)));
+        assert_eq!(Dfa::compile(r"ab\"), Err(RegexError::TrailingEscape(2)));
+    }
+
+    #[test]
+    fn driven_through_the_state_machine_trait() {
+        let dfa = Dfa::compile("ab").unwrap();
+        let after_a = DfaCursor::next_state(&dfa.cursor(), &'a');
+        assert!(!after_a.is_accepting());
+        let after_ab = DfaCursor::next_state(&after_a, &'b');
+        assert!(after_ab.is_accepting());
+        assert!(!DfaCursor::next_state(&after_ab, &'b').is_accepting());
+    }
+
+    #[test]
+    fn minimize_classic_example() {
+        // The dragon book's (a|b)*abb: subset construction gives five live states,
+        // the minimal DFA has four plus the dead state for other characters
+        let dfa = Dfa::compile("(a|b)*abb").unwrap();
+        let minimal = dfa.minimize();
+        assert!(dfa.state_count() > minimal.state_count());
+        assert_eq!(minimal.state_count(), 5);
+        check(
+            "(a|b)*abb",
+            &["abb", "aabb", "babb", "ababb"],
+            &["ab", "abba", "abbc"],
+        );
+    }
+
+    #[test]
+    fn equivalent_patterns_minimize_to_the_same_size() {
+        let sizes: Vec<usize> = ["a*", "(a*)*", "a*a*", "(a|a)*", "a?a*"]
+            .iter()
+            .map(|pattern| Dfa::compile(pattern).unwrap().minimize().state_count())
+            .collect();
+        // One accepting state looping on `a` and the dead state
+        assert!(sizes.iter().all(|&size| size == 2), "{sizes:?}");
+    }
+
+    #[test]
+    fn minimizing_twice_changes_nothing() {
+        let once = Dfa::compile("(ab|ac)*d?").unwrap().minimize();
+        assert_eq!(once.minimize().state_count(), once.state_count());
+    }
+
+    #[test]
+    fn nfa_is_larger_than_pattern() {
+        let nfa = Nfa::compile("a|b").unwrap();
+        assert_eq!(nfa.state_count(), 6);
+    }
+}
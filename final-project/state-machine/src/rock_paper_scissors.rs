@@ -0,0 +1,211 @@
+//! A two-player rock-paper-scissors match.
+//!
+//! Both players choose simultaneously: each player has an independent move slot
+//! (two parallel regions of the machine), and a round is only scored once both
+//! slots are filled. The match is played as best-of-N rounds, draws do not count.
+
+use super::StateMachine;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Player {
+    One,
+    Two,
+}
+
+impl Player {
+    fn index(self) -> usize {
+        match self {
+            Player::One => 0,
+            Player::Two => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    /// Whether this move wins against the other one
+    pub fn beats(self, other: Move) -> bool {
+        matches!(
+            (self, other),
+            (Move::Rock, Move::Scissors)
+                | (Move::Paper, Move::Rock)
+                | (Move::Scissors, Move::Paper)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpsAction {
+    /// A player locks in their move for the current round
+    Play(Player, Move),
+    /// Start a new match with the same configuration once the current one is over
+    Rematch,
+}
+
+/// The full state of a match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpsGame {
+    /// Maximum number of decisive rounds in the match
+    pub best_of: u32,
+    /// Rounds won by each player
+    pub wins: [u32; 2],
+    /// Moves locked in for the current round, one slot per player
+    pub pending: [Option<Move>; 2],
+    /// Rounds resolved so far, draws included
+    pub rounds_played: u32,
+}
+
+impl RpsGame {
+    /// A fresh best-of-`best_of` match
+    pub fn new(best_of: u32) -> Self {
+        RpsGame {
+            best_of,
+            wins: [0, 0],
+            pending: [None, None],
+            rounds_played: 0,
+        }
+    }
+
+    /// Rounds a player has to win to take the match
+    pub fn wins_needed(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// The match winner, once somebody has won enough rounds
+    pub fn winner(&self) -> Option<Player> {
+        if self.wins[0] >= self.wins_needed() {
+            Some(Player::One)
+        } else if self.wins[1] >= self.wins_needed() {
+            Some(Player::Two)
+        } else {
+            None
+        }
+    }
+}
+
+impl StateMachine for RpsGame {
+    type State = RpsGame;
+    type Transition = RpsAction;
+
+    fn next_state(starting_state: &RpsGame, t: &RpsAction) -> RpsGame {
+        let mut next = starting_state.clone();
+
+        match t {
+            RpsAction::Rematch => {
+                if starting_state.winner().is_some() {
+                    next = RpsGame::new(starting_state.best_of);
+                }
+            }
+            RpsAction::Play(player, chosen) => {
+                // No more moves after the match is decided, and no changing your mind
+                if starting_state.winner().is_some() || next.pending[player.index()].is_some() {
+                    return next;
+                }
+                next.pending[player.index()] = Some(*chosen);
+
+                if let [Some(first), Some(second)] = next.pending {
+                    if first.beats(second) {
+                        next.wins[0] += 1;
+                    } else if second.beats(first) {
+                        next.wins[1] += 1;
+                    }
+                    next.rounds_played += 1;
+                    next.pending = [None, None];
+                }
+            }
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_round(game: &RpsGame, one: Move, two: Move) -> RpsGame {
+        let halfway = RpsGame::next_state(game, &RpsAction::Play(Player::One, one));
+        RpsGame::next_state(&halfway, &RpsAction::Play(Player::Two, two))
+    }
+
+    #[test]
+    fn every_move_beats_exactly_one_other() {
+        let moves = [Move::Rock, Move::Paper, Move::Scissors];
+        for m in moves {
+            assert_eq!(moves.iter().filter(|other| m.beats(**other)).count(), 1);
+            assert!(!m.beats(m));
+        }
+    }
+
+    #[test]
+    fn round_waits_for_both_players() {
+        let start = RpsGame::new(3);
+        let end = RpsGame::next_state(&start, &RpsAction::Play(Player::Two, Move::Rock));
+        assert_eq!(end.pending, [None, Some(Move::Rock)]);
+        assert_eq!(end.rounds_played, 0);
+    }
+
+    #[test]
+    fn move_order_does_not_matter() {
+        let start = RpsGame::new(3);
+        let one_first = play_round(&start, Move::Paper, Move::Rock);
+        let halfway = RpsGame::next_state(&start, &RpsAction::Play(Player::Two, Move::Rock));
+        let two_first = RpsGame::next_state(&halfway, &RpsAction::Play(Player::One, Move::Paper));
+        assert_eq!(one_first, two_first);
+        assert_eq!(one_first.wins, [1, 0]);
+    }
+
+    #[test]
+    fn cannot_change_move_once_locked_in() {
+        let start = RpsGame::new(3);
+        let locked = RpsGame::next_state(&start, &RpsAction::Play(Player::One, Move::Rock));
+        let end = RpsGame::next_state(&locked, &RpsAction::Play(Player::One, Move::Paper));
+        assert_eq!(end, locked);
+    }
+
+    #[test]
+    fn draw_is_played_but_not_scored() {
+        let end = play_round(&RpsGame::new(3), Move::Scissors, Move::Scissors);
+        assert_eq!(end.wins, [0, 0]);
+        assert_eq!(end.rounds_played, 1);
+        assert_eq!(end.pending, [None, None]);
+    }
+
+    #[test]
+    fn best_of_three_ends_after_two_wins() {
+        let mut game = RpsGame::new(3);
+        game = play_round(&game, Move::Rock, Move::Paper);
+        game = play_round(&game, Move::Rock, Move::Rock);
+        assert_eq!(game.winner(), None);
+        game = play_round(&game, Move::Scissors, Move::Paper);
+        game = play_round(&game, Move::Paper, Move::Scissors);
+        assert_eq!(game.wins, [1, 2]);
+        assert_eq!(game.winner(), Some(Player::Two));
+    }
+
+    #[test]
+    fn no_moves_after_match_is_decided() {
+        let game = play_round(&RpsGame::new(1), Move::Rock, Move::Scissors);
+        assert_eq!(game.winner(), Some(Player::One));
+        let end = RpsGame::next_state(&game, &RpsAction::Play(Player::Two, Move::Paper));
+        assert_eq!(end, game);
+    }
+
+    #[test]
+    fn rematch_only_after_match_is_decided() {
+        let ongoing = play_round(&RpsGame::new(5), Move::Rock, Move::Scissors);
+        assert_eq!(RpsGame::next_state(&ongoing, &RpsAction::Rematch), ongoing);
+
+        let finished = play_round(&RpsGame::new(1), Move::Rock, Move::Scissors);
+        assert_eq!(
+            RpsGame::next_state(&finished, &RpsAction::Rematch),
+            RpsGame::new(1)
+        );
+    }
+}
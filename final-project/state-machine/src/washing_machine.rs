@@ -0,0 +1,245 @@
+//! A washing machine cycle controller.
+//!
+//! A cycle goes fill → wash → rinse → spin, driven by the water level sensor and the
+//! cycle timer. The safety invariant is that the door is locked whenever the drum
+//! holds water or spins, and only then: starting a cycle locks the door, and it is
+//! unlocked only once the cycle has finished or the drum has been drained.
+
+use super::StateMachine;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WashPhase {
+    Idle,
+    Filling,
+    Washing,
+    Rinsing,
+    Spinning,
+    /// Pumping the water out after a cancelled cycle
+    Draining,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Door {
+    Open,
+    Closed,
+    Locked,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WasherEvent {
+    OpenDoor,
+    CloseDoor,
+    /// The start button
+    Start,
+    /// The cancel button
+    Cancel,
+    /// The water level sensor reports a full drum
+    Filled,
+    /// The water level sensor reports an empty drum
+    Drained,
+    /// The timer for the current phase ran out
+    TimerElapsed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WashingMachine {
+    pub phase: WashPhase,
+    pub door: Door,
+}
+
+impl WashingMachine {
+    /// An idle machine with its door open
+    pub fn new() -> Self {
+        WashingMachine {
+            phase: WashPhase::Idle,
+            door: Door::Open,
+        }
+    }
+
+    /// The safety invariant: the door is locked exactly while a cycle runs
+    pub fn is_safe(&self) -> bool {
+        (self.phase == WashPhase::Idle) == (self.door != Door::Locked)
+    }
+
+    fn running(phase: WashPhase) -> Self {
+        WashingMachine {
+            phase,
+            door: Door::Locked,
+        }
+    }
+
+    fn finished() -> Self {
+        WashingMachine {
+            phase: WashPhase::Idle,
+            door: Door::Closed,
+        }
+    }
+}
+
+impl Default for WashingMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateMachine for WashingMachine {
+    type State = WashingMachine;
+    type Transition = WasherEvent;
+
+    fn next_state(starting_state: &WashingMachine, t: &WasherEvent) -> WashingMachine {
+        use WashPhase::*;
+
+        match (starting_state.phase, starting_state.door, t) {
+            (Idle, Door::Closed, WasherEvent::OpenDoor) => WashingMachine {
+                phase: Idle,
+                door: Door::Open,
+            },
+            (Idle, Door::Open, WasherEvent::CloseDoor) => WashingMachine::finished(),
+            // The door must be shut before it can be locked
+            (Idle, Door::Closed, WasherEvent::Start) => WashingMachine::running(Filling),
+
+            (Filling, _, WasherEvent::Filled) => WashingMachine::running(Washing),
+            (Washing, _, WasherEvent::TimerElapsed) => WashingMachine::running(Rinsing),
+            (Rinsing, _, WasherEvent::TimerElapsed) => WashingMachine::running(Spinning),
+            (Spinning, _, WasherEvent::TimerElapsed) => WashingMachine::finished(),
+
+            // Cancelling keeps the door locked until the drum is empty
+            (Filling | Washing | Rinsing | Spinning, _, WasherEvent::Cancel) => {
+                WashingMachine::running(Draining)
+            }
+            (Draining, _, WasherEvent::Drained) => WashingMachine::finished(),
+
+            _ => *starting_state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const ALL_EVENTS: [WasherEvent; 7] = [
+        WasherEvent::OpenDoor,
+        WasherEvent::CloseDoor,
+        WasherEvent::Start,
+        WasherEvent::Cancel,
+        WasherEvent::Filled,
+        WasherEvent::Drained,
+        WasherEvent::TimerElapsed,
+    ];
+
+    // Every state reachable from a new machine
+    fn state_space() -> Vec<WashingMachine> {
+        crate::reachable::<WashingMachine>(WashingMachine::new(), &ALL_EVENTS)
+    }
+
+    fn run(events: &[WasherEvent]) -> WashingMachine {
+        crate::run::<WashingMachine>(WashingMachine::new(), events)
+    }
+
+    #[test]
+    fn full_cycle() {
+        let end = run(&[
+            WasherEvent::CloseDoor,
+            WasherEvent::Start,
+            WasherEvent::Filled,
+            WasherEvent::TimerElapsed,
+            WasherEvent::TimerElapsed,
+        ]);
+        assert_eq!(end, WashingMachine::running(WashPhase::Spinning));
+        let end = WashingMachine::next_state(&end, &WasherEvent::TimerElapsed);
+        assert_eq!(end, WashingMachine::finished());
+    }
+
+    #[test]
+    fn cannot_start_with_door_open() {
+        assert_eq!(run(&[WasherEvent::Start]), WashingMachine::new());
+    }
+
+    #[test]
+    fn cannot_open_door_while_running() {
+        let running = run(&[WasherEvent::CloseDoor, WasherEvent::Start]);
+        assert_eq!(
+            WashingMachine::next_state(&running, &WasherEvent::OpenDoor),
+            running
+        );
+    }
+
+    #[test]
+    fn cancel_drains_before_unlocking() {
+        let end = run(&[
+            WasherEvent::CloseDoor,
+            WasherEvent::Start,
+            WasherEvent::Filled,
+            WasherEvent::Cancel,
+        ]);
+        assert_eq!(end, WashingMachine::running(WashPhase::Draining));
+        let end = WashingMachine::next_state(&end, &WasherEvent::Drained);
+        assert_eq!(end, WashingMachine::finished());
+    }
+
+    #[test]
+    fn every_reachable_state_is_safe() {
+        for state in state_space() {
+            assert!(
+                state.is_safe(),
+                "{state:?} violates the door lock invariant"
+            );
+        }
+    }
+
+    #[test]
+    fn door_is_locked_whenever_drum_spins() {
+        let spinning: Vec<_> = state_space()
+            .into_iter()
+            .filter(|state| state.phase == WashPhase::Spinning)
+            .collect();
+        assert!(!spinning.is_empty());
+        assert!(spinning.iter().all(|state| state.door == Door::Locked));
+    }
+
+    #[test]
+    fn every_transition_preserves_safety() {
+        // Stronger than reachability: even unreachable safe states stay safe
+        let phases = [
+            WashPhase::Idle,
+            WashPhase::Filling,
+            WashPhase::Washing,
+            WashPhase::Rinsing,
+            WashPhase::Spinning,
+            WashPhase::Draining,
+        ];
+        let doors = [Door::Open, Door::Closed, Door::Locked];
+        for phase in phases {
+            for door in doors {
+                let state = WashingMachine { phase, door };
+                if !state.is_safe() {
+                    continue;
+                }
+                for event in ALL_EVENTS {
+                    let next = WashingMachine::next_state(&state, &event);
+                    assert!(next.is_safe(), "{state:?} --{event:?}--> {next:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_phase_is_reachable() {
+        let phases: HashSet<WashPhase> = state_space().iter().map(|state| state.phase).collect();
+        assert_eq!(phases.len(), 6);
+    }
+
+    #[test]
+    fn machine_can_always_get_back_to_idle() {
+        let space = state_space();
+        for start in &space {
+            let seen = crate::reachable::<WashingMachine>(*start, &ALL_EVENTS);
+            assert!(
+                seen.iter().any(|state| state.phase == WashPhase::Idle),
+                "{start:?} can never finish"
+            );
+        }
+    }
+}
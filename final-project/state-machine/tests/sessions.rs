@@ -0,0 +1,165 @@
+// End-to-end sessions through the final project's public API only
+use state_machine::auth_session::{AuthEvent, AuthPhase, AuthSession};
+use state_machine::chess_clock::{ChessClock, ClockEvent, Side};
+use state_machine::coffee_machine::{
+    CoffeeAction, CoffeeMachine, CoffeeMode, BEANS_PER_CUP, BREWS_PER_DESCALE, WATER_PER_CUP,
+};
+use state_machine::{run, Adapted, DynMachine, Runner};
+
+const CARD_PIN: u16 = 1234;
+
+fn session() -> Runner<AuthSession<u16>> {
+    Runner::new(AuthSession::new(3, 5))
+}
+
+#[test]
+fn swipe_and_enter_the_right_pin() {
+    let mut atm = session();
+    atm.step(&AuthEvent::Identify(CARD_PIN));
+    assert!(!atm.state().is_authenticated());
+    atm.step(&AuthEvent::Submit(CARD_PIN));
+    assert!(atm.state().is_authenticated());
+    atm.step(&AuthEvent::End);
+    assert_eq!(atm.into_state().phase, AuthPhase::Waiting);
+}
+
+#[test]
+fn wrong_pin_then_the_right_one() {
+    let end = run::<AuthSession<u16>>(
+        AuthSession::new(3, 5),
+        &[
+            AuthEvent::Identify(CARD_PIN),
+            AuthEvent::Submit(1111),
+            AuthEvent::Submit(2222),
+            AuthEvent::Submit(CARD_PIN),
+        ],
+    );
+    assert!(end.is_authenticated());
+    assert_eq!(end.idle_ticks, 0);
+}
+
+#[test]
+fn lockout_until_an_operator_resets() {
+    let mut atm = session();
+    atm.step(&AuthEvent::Identify(CARD_PIN));
+    for wrong in [1, 2, 3] {
+        atm.step(&AuthEvent::Submit(wrong));
+    }
+    assert!(atm.state().is_locked_out());
+
+    // Neither the right PIN nor a new card gets past a lockout
+    atm.step(&AuthEvent::Submit(CARD_PIN));
+    atm.step(&AuthEvent::Identify(CARD_PIN));
+    atm.step(&AuthEvent::End);
+    assert!(atm.state().is_locked_out());
+
+    atm.step(&AuthEvent::Reset);
+    atm.step(&AuthEvent::Identify(CARD_PIN));
+    atm.step(&AuthEvent::Submit(CARD_PIN));
+    assert!(atm.state().is_authenticated());
+}
+
+#[test]
+fn idle_session_times_out() {
+    let mut atm = session();
+    atm.step(&AuthEvent::Identify(CARD_PIN));
+    for _ in 0..4 {
+        atm.step(&AuthEvent::Tick);
+    }
+    // Activity restarts the countdown
+    atm.step(&AuthEvent::Submit(CARD_PIN));
+    for _ in 0..4 {
+        atm.step(&AuthEvent::Tick);
+    }
+    assert!(atm.state().is_authenticated());
+    atm.step(&AuthEvent::Tick);
+    assert_eq!(atm.state().phase, AuthPhase::Waiting);
+    assert_eq!(atm.steps(), 11);
+}
+
+#[test]
+fn brewing_stops_when_supplies_run_out() {
+    let end = run::<CoffeeMachine>(
+        CoffeeMachine::new(3 * WATER_PER_CUP, 100, 10),
+        &[CoffeeAction::Brew; 5],
+    );
+    assert_eq!(end.water, 0);
+    assert_eq!(end.beans, 100 - 3 * BEANS_PER_CUP);
+    assert_eq!(end.cups, 7);
+    assert!(!end.can_brew());
+}
+
+#[test]
+fn maintenance_refill_and_descale() {
+    let mut machine = Runner::<CoffeeMachine>::new(CoffeeMachine::new(0, 0, 0));
+    machine.step(&CoffeeAction::RefillWater(20 * WATER_PER_CUP));
+    machine.step(&CoffeeAction::RefillBeans(20 * BEANS_PER_CUP));
+    machine.step(&CoffeeAction::RefillCups(20));
+    for _ in 0..BREWS_PER_DESCALE {
+        machine.step(&CoffeeAction::Brew);
+    }
+    assert!(machine.state().needs_descale());
+
+    machine.step(&CoffeeAction::StartDescale);
+    // Nothing is brewed or refilled while descaling
+    machine.step(&CoffeeAction::Brew);
+    machine.step(&CoffeeAction::RefillCups(5));
+    assert_eq!(machine.state().mode, CoffeeMode::Descaling);
+    machine.step(&CoffeeAction::FinishDescale);
+
+    let end = machine.into_state();
+    assert_eq!(end.mode, CoffeeMode::Ready);
+    assert_eq!(end.brews_since_descale, 0);
+    assert_eq!(end.cups, 20 - BREWS_PER_DESCALE);
+    assert_eq!(end.water, (20 - BREWS_PER_DESCALE) * WATER_PER_CUP);
+}
+
+// What the machines of one kiosk hear about, each taking only its own events
+enum Kiosk {
+    Tick,
+    Card(u16),
+    Pin(u16),
+    Press(Side),
+}
+
+#[test]
+fn one_event_loop_drives_different_machines() {
+    let mut machines: Vec<Box<dyn DynMachine<Kiosk>>> = vec![
+        Box::new(Adapted::<AuthSession<u16>, Kiosk>::new(
+            AuthSession::new(3, 2),
+            |event| match event {
+                Kiosk::Tick => Some(AuthEvent::Tick),
+                Kiosk::Card(pin) => Some(AuthEvent::Identify(*pin)),
+                Kiosk::Pin(pin) => Some(AuthEvent::Submit(*pin)),
+                Kiosk::Press(_) => None,
+            },
+        )),
+        Box::new(Adapted::<ChessClock, Kiosk>::new(
+            ChessClock::new(2, 0),
+            |event| match event {
+                Kiosk::Tick => Some(ClockEvent::Tick),
+                Kiosk::Press(side) => Some(ClockEvent::Press(*side)),
+                Kiosk::Card(_) | Kiosk::Pin(_) => None,
+            },
+        )),
+    ];
+    let events = [
+        Kiosk::Card(CARD_PIN),
+        Kiosk::Press(Side::Black),
+        Kiosk::Pin(CARD_PIN),
+        Kiosk::Tick,
+        Kiosk::Tick,
+        Kiosk::Tick,
+    ];
+    for event in &events {
+        for machine in machines.iter_mut() {
+            machine.handle(event);
+        }
+    }
+    // White's flag fell on the second tick, so the clock missed the third
+    assert!(machines[1].is_terminal());
+    assert_eq!(machines[1].steps(), 3);
+    // Two idle ticks timed the session out
+    assert!(format!("{:?}", machines[0].state()).contains("phase: Waiting"));
+    assert_eq!(machines[0].steps(), 5);
+}
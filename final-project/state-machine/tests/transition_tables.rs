@@ -0,0 +1,88 @@
+// The reachable transitions of each machine, checked against a golden file in
+// tests/golden. A change in behaviour shows up there as a reviewable diff.
+//
+// Run with UPDATE_GOLDEN=1 to rewrite the files after an intended change.
+use state_machine::auth_session::{AuthEvent, AuthSession};
+use state_machine::garage_door::{DoorEvent, DoorState, GarageDoor};
+use state_machine::to_dot;
+use state_machine::washing_machine::{WasherEvent, WashingMachine};
+use std::path::PathBuf;
+
+fn check_golden(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.dot"));
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("could not read {}: {err}", path.display()));
+    if actual == expected {
+        return;
+    }
+    let mut diff = String::new();
+    for line in expected
+        .lines()
+        .filter(|l| !actual.lines().any(|a| a == *l))
+    {
+        diff.push_str(&format!("- {line}\n"));
+    }
+    for line in actual
+        .lines()
+        .filter(|l| !expected.lines().any(|e| e == *l))
+    {
+        diff.push_str(&format!("+ {line}\n"));
+    }
+    panic!(
+        "the transitions of `{name}` changed, rerun with UPDATE_GOLDEN=1 if that was intended\n{diff}"
+    );
+}
+
+#[test]
+fn garage_door() {
+    let events = [
+        DoorEvent::Button,
+        DoorEvent::Obstruction,
+        DoorEvent::ReachedTop,
+        DoorEvent::ReachedBottom,
+    ];
+    check_golden(
+        "garage_door",
+        &to_dot::<GarageDoor>("garage_door", DoorState::Closed, &events),
+    );
+}
+
+#[test]
+fn washing_machine() {
+    let events = [
+        WasherEvent::OpenDoor,
+        WasherEvent::CloseDoor,
+        WasherEvent::Start,
+        WasherEvent::Cancel,
+        WasherEvent::Filled,
+        WasherEvent::Drained,
+        WasherEvent::TimerElapsed,
+    ];
+    check_golden(
+        "washing_machine",
+        &to_dot::<WashingMachine>("washing_machine", WashingMachine::new(), &events),
+    );
+}
+
+#[test]
+fn auth_session() {
+    // One card, its PIN and one wrong PIN are enough to reach every phase
+    let events = [
+        AuthEvent::Identify(1),
+        AuthEvent::Submit(1),
+        AuthEvent::Submit(2),
+        AuthEvent::Tick,
+        AuthEvent::End,
+        AuthEvent::Reset,
+    ];
+    check_golden(
+        "auth_session",
+        &to_dot::<AuthSession<u8>>("auth_session", AuthSession::new(2, 2), &events),
+    );
+}
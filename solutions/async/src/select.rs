@@ -0,0 +1,81 @@
+#[path = "../../../exercises/async/src/executor.rs"]
+mod executor;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// Exercise 1
+// `Select` finishes with the output of whichever future is ready first,
+// dropping the other one, like `select!` in async runtimes.
+// When both are ready on the same poll, `First` wins
+#[derive(Debug, PartialEq)]
+enum Either<A, B> {
+    First(A),
+    Second(B),
+}
+
+struct Select<A, B> {
+    a: Pin<Box<A>>,
+    b: Pin<Box<B>>,
+}
+
+fn select<A: Future, B: Future>(a: A, b: B) -> Select<A, B> {
+    Select {
+        a: Box::pin(a),
+        b: Box::pin(b),
+    }
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = self.a.as_mut().poll(cx) {
+            return Poll::Ready(Either::First(output));
+        }
+        if let Poll::Ready(output) = self.b.as_mut().poll(cx) {
+            return Poll::Ready(Either::Second(output));
+        }
+        Poll::Pending
+    }
+}
+
+// Exercise 2
+// Input: a future doing some work, and how many ticks it may take
+// Output: its value, or Err("timed out") when the deadline comes first
+async fn with_deadline<F: Future>(work: F, ticks: u32) -> Result<F::Output, &'static str> {
+    match select(work, executor::Yield { times: ticks }).await {
+        Either::First(output) => Ok(output),
+        Either::Second(()) => Err("timed out"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::executor::{after, block_on};
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn fastest_wins() {
+        assert_eq!(
+            block_on(select(after(5, 'a'), after(2, 1))),
+            Either::Second(1)
+        );
+        assert_eq!(
+            block_on(select(after(0, 'a'), after(0, 1))),
+            Either::First('a')
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn deadlines() {
+        assert_eq!(block_on(with_deadline(after(2, "done"), 5)), Ok("done"));
+        assert_eq!(
+            block_on(with_deadline(after(9, "done"), 5)),
+            Err("timed out")
+        );
+    }
+}
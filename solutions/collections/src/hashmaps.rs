@@ -0,0 +1,135 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+// Exercise 1
+// Output: how many times each word appears in the text
+fn word_count(text: &str) -> HashMap<&str, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Exercise 2
+// The cache below looks a key up, inserts a computed value when it is
+// missing, then looks it up again: up to three hash lookups per call.
+// Rewrite `get_or_insert_entry` with `entry().or_insert_with()` so the key
+// is looked up only once, and the value is only computed when missing
+fn get_or_insert_lookups<K: Hash + Eq + Clone, V, S: BuildHasher>(
+    cache: &mut HashMap<K, V, S>,
+    key: K,
+    compute: impl FnOnce() -> V,
+) -> &mut V {
+    if !cache.contains_key(&key) {
+        cache.insert(key.clone(), compute());
+    }
+    cache.get_mut(&key).unwrap()
+}
+
+fn get_or_insert_entry<K: Hash + Eq, V, S: BuildHasher>(
+    cache: &mut HashMap<K, V, S>,
+    key: K,
+    compute: impl FnOnce() -> V,
+) -> &mut V {
+    cache.entry(key).or_insert_with(compute)
+}
+
+// Exercise 3
+// Group the words by their first letter, keeping their order in each group
+fn group_by_initial<'a>(words: &[&'a str]) -> HashMap<char, Vec<&'a str>> {
+    let mut groups: HashMap<char, Vec<&str>> = HashMap::new();
+    for word in words {
+        if let Some(initial) = word.chars().next() {
+            groups.entry(initial).or_default().push(word);
+        }
+    }
+    groups
+}
+
+/// Counts every key hashed by the map, to compare the two versions of
+/// exercise 2
+#[derive(Default)]
+struct CountingState {
+    inner: RandomState,
+    hashes: std::cell::Cell<usize>,
+}
+
+impl BuildHasher for CountingState {
+    type Hasher = <RandomState as BuildHasher>::Hasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        self.hashes.set(self.hashes.get() + 1);
+        self.inner.build_hasher()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn count_words() {
+        let counts = word_count("the cat saw the other cat the end");
+        assert_eq!(counts["the"], 3);
+        assert_eq!(counts["cat"], 2);
+        assert_eq!(counts["end"], 1);
+        assert_eq!(counts.get("dog"), None);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn both_versions_agree() {
+        let mut a = HashMap::new();
+        let mut b = HashMap::new();
+        for key in ["x", "y", "x"] {
+            *get_or_insert_lookups(&mut a, key, || 0) += 1;
+            *get_or_insert_entry(&mut b, key, || 0) += 1;
+        }
+        assert_eq!(a, b);
+        assert_eq!(b["x"], 2);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn compute_only_when_missing() {
+        let mut cache = HashMap::new();
+        let mut calls = 0;
+        get_or_insert_entry(&mut cache, 1, || {
+            calls += 1;
+            "one"
+        });
+        get_or_insert_entry(&mut cache, 1, || {
+            calls += 1;
+            "uno"
+        });
+        assert_eq!(calls, 1);
+        assert_eq!(cache[&1], "one");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn entry_hashes_once() {
+        let mut lookups = HashMap::with_hasher(CountingState::default());
+        let mut entry = HashMap::with_hasher(CountingState::default());
+        for key in ["a", "b", "a", "a"] {
+            get_or_insert_lookups(&mut lookups, key, || 0);
+            get_or_insert_entry(&mut entry, key, || 0);
+        }
+        // At least a lookup and a `get_mut` for every call, plus one more
+        // hash per insert, against one hash per call
+        assert!(lookups.hasher().hashes.get() >= 2 * 4);
+        assert_eq!(entry.hasher().hashes.get(), 4);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn groups() {
+        let groups = group_by_initial(&["apple", "banana", "avocado", "blueberry", "cherry"]);
+        assert_eq!(groups[&'a'], vec!["apple", "avocado"]);
+        assert_eq!(groups[&'b'], vec!["banana", "blueberry"]);
+        assert_eq!(groups.len(), 3);
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::{BTreeMap, HashSet};
+
+// Exercise 1
+// Output: the scores by name, iterated in alphabetical order of the names.
+// A `HashMap` has no order: use a `BTreeMap`
+fn scoreboard(scores: &[(&str, u32)]) -> Vec<String> {
+    let board: BTreeMap<&str, u32> = scores.iter().copied().collect();
+    board
+        .iter()
+        .map(|(name, score)| format!("{name}: {score}"))
+        .collect()
+}
+
+// Exercise 2
+// Output: the keys of `map` between `from` (included) and `to` (excluded)
+fn keys_between(map: &BTreeMap<u32, &str>, from: u32, to: u32) -> Vec<u32> {
+    map.range(from..to).map(|(key, _)| *key).collect()
+}
+
+// Exercise 3
+// Output: true if any number appears more than once
+fn has_duplicates(numbers: &[i32]) -> bool {
+    let mut seen = HashSet::new();
+    numbers.iter().any(|n| !seen.insert(n))
+}
+
+// Exercise 4
+// Output: the sorted tags present in both lists
+fn common_tags(a: &[&str], b: &[&str]) -> Vec<String> {
+    let a: HashSet<&str> = a.iter().copied().collect();
+    let b: HashSet<&str> = b.iter().copied().collect();
+    let mut common: Vec<String> = a.intersection(&b).map(|tag| tag.to_string()).collect();
+    common.sort();
+    common
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn sorted_scoreboard() {
+        assert_eq!(
+            scoreboard(&[("zoe", 3), ("adam", 5), ("mia", 1)]),
+            vec!["adam: 5", "mia: 1", "zoe: 3"]
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn range_of_keys() {
+        let map = BTreeMap::from([(1, "a"), (5, "b"), (9, "c"), (12, "d")]);
+        assert_eq!(keys_between(&map, 5, 12), vec![5, 9]);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn duplicates() {
+        assert!(has_duplicates(&[1, 2, 3, 2]));
+        assert!(!has_duplicates(&[1, 2, 3]));
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn intersection() {
+        assert_eq!(
+            common_tags(&["rust", "web", "cli"], &["cli", "rust", "game"]),
+            vec!["cli", "rust"]
+        );
+    }
+}
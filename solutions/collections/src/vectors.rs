@@ -0,0 +1,66 @@
+// Exercise 1
+// Output: a vector with every number of `input` doubled, in the same order
+fn double_all(input: &[i32]) -> Vec<i32> {
+    input.iter().map(|n| n * 2).collect()
+}
+
+// Exercise 2
+// Remove every negative number in place, keeping the order of the rest
+fn drop_negatives(numbers: &mut Vec<i32>) {
+    numbers.retain(|n| *n >= 0);
+}
+
+// Exercise 3
+// Make me compile!
+// Holding a reference into a vector while pushing to it is not allowed:
+// the push may move the items. Copy the first item out before pushing
+fn push_first_again(numbers: &mut Vec<i32>) {
+    if let Some(&first) = numbers.first() {
+        numbers.push(first);
+    }
+}
+
+// Exercise 4
+// Output: the numbers split into chunks of `size`, the last one shorter if needed
+fn chunks(numbers: &[i32], size: usize) -> Vec<Vec<i32>> {
+    numbers.chunks(size).map(|chunk| chunk.to_vec()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn doubled() {
+        assert_eq!(double_all(&[1, -2, 3]), vec![2, -4, 6]);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn negatives_removed() {
+        let mut numbers = vec![3, -1, 0, -7, 5];
+        drop_negatives(&mut numbers);
+        assert_eq!(numbers, vec![3, 0, 5]);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn first_pushed_again() {
+        let mut numbers = vec![4, 5];
+        push_first_again(&mut numbers);
+        assert_eq!(numbers, vec![4, 5, 4]);
+        let mut empty = Vec::new();
+        push_first_again(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn split_into_chunks() {
+        assert_eq!(
+            chunks(&[1, 2, 3, 4, 5], 2),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+}
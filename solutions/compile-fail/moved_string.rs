@@ -0,0 +1,14 @@
+// Exercise 1
+// `take` takes ownership of its argument. Make this file fail to compile by
+// printing `name` after it was passed to `take`. The compiler must report the
+// error in `moved_string.stderr`
+
+fn take(text: String) -> usize {
+    text.len()
+}
+
+pub fn greet() {
+    let name = String::from("Ferris");
+    let length = take(name);
+    println!("{name} has {length} letters");
+}
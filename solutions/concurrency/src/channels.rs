@@ -0,0 +1,87 @@
+use std::sync::mpsc;
+use std::thread;
+
+#[cfg(test)]
+#[path = "../../../exercises/concurrency/src/timeout.rs"]
+mod timeout;
+
+// Exercise 1
+// A worker thread sends every word of the text, then the channel closes.
+// Receive them all on the calling thread
+fn words_through_channel(text: &str) -> Vec<String> {
+    let (sender, receiver) = mpsc::channel();
+    let text = text.to_string();
+    thread::spawn(move || {
+        for word in text.split_whitespace() {
+            sender.send(word.to_string()).unwrap();
+        }
+    });
+    receiver.iter().collect()
+}
+
+// Exercise 2
+// Several producers, one consumer: each of `producers` threads sends its id
+// `count` times. Add up everything received.
+// Note: the loop only ends once every sender is dropped
+fn total_from_producers(producers: u64, count: u64) -> u64 {
+    let (sender, receiver) = mpsc::channel();
+    for id in 0..producers {
+        let sender = sender.clone();
+        thread::spawn(move || {
+            for _ in 0..count {
+                sender.send(id).unwrap();
+            }
+        });
+    }
+    drop(sender);
+    receiver.iter().sum()
+}
+
+// Exercise 3
+// A worker answers requests: each request carries the number to double and
+// the channel to answer on
+fn doubling_service(requests: Vec<u64>) -> Vec<u64> {
+    let (request_sender, request_receiver) = mpsc::channel::<(u64, mpsc::Sender<u64>)>();
+    thread::spawn(move || {
+        for (value, reply) in request_receiver {
+            let _ = reply.send(value * 2);
+        }
+    });
+    requests
+        .into_iter()
+        .map(|value| {
+            let (reply_sender, reply_receiver) = mpsc::channel();
+            request_sender.send((value, reply_sender)).unwrap();
+            reply_receiver.recv().unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::timeout::with_timeout;
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn words_arrive_in_order() {
+        let words = with_timeout(5, || words_through_channel("send me over"));
+        assert_eq!(words, vec!["send", "me", "over"]);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn every_producer_is_counted() {
+        // 0 + 1 + 2 + 3, ten times each
+        assert_eq!(with_timeout(5, || total_from_producers(4, 10)), 60);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn request_reply() {
+        assert_eq!(
+            with_timeout(5, || doubling_service(vec![1, 5, 21])),
+            vec![2, 10, 42]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[cfg(test)]
+#[path = "../../../exercises/concurrency/src/timeout.rs"]
+mod timeout;
+
+// Exercise 1
+// Transfers between two accounts used to hang forever: a transfer from A to B
+// locked A then B while a transfer from B to A locked B then A, so each thread
+// waited for the lock the other one held.
+// Fix `transfer` so every thread takes the locks in the same order
+struct Account {
+    id: u32,
+    balance: Mutex<i64>,
+}
+
+fn transfer(from: &Account, to: &Account, amount: i64) {
+    // Always lock the account with the smaller id first
+    let (first, second) = if from.id < to.id {
+        (from, to)
+    } else {
+        (to, from)
+    };
+    let mut first = first.balance.lock().unwrap();
+    let mut second = second.balance.lock().unwrap();
+    let (from_balance, to_balance) = if from.id < to.id {
+        (&mut *first, &mut *second)
+    } else {
+        (&mut *second, &mut *first)
+    };
+    *from_balance -= amount;
+    *to_balance += amount;
+}
+
+// Exercise 2
+// Make me stop hanging!
+// The guard from the first `lock()` lives until the end of the `if`, so
+// locking again in the body waits for ourselves. Read the value first
+fn increment_if_even(counter: &Mutex<i64>) {
+    let current = *counter.lock().unwrap();
+    if current % 2 == 0 {
+        *counter.lock().unwrap() += 1;
+    }
+}
+
+fn run_transfers(rounds: usize) -> (i64, i64) {
+    let a = Arc::new(Account {
+        id: 1,
+        balance: Mutex::new(1000),
+    });
+    let b = Arc::new(Account {
+        id: 2,
+        balance: Mutex::new(1000),
+    });
+    let forth = {
+        let (a, b) = (Arc::clone(&a), Arc::clone(&b));
+        thread::spawn(move || (0..rounds).for_each(|_| transfer(&a, &b, 1)))
+    };
+    let back = {
+        let (a, b) = (Arc::clone(&a), Arc::clone(&b));
+        thread::spawn(move || (0..rounds).for_each(|_| transfer(&b, &a, 2)))
+    };
+    forth.join().unwrap();
+    back.join().unwrap();
+    let balances = (*a.balance.lock().unwrap(), *b.balance.lock().unwrap());
+    balances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::timeout::with_timeout;
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn opposite_transfers_finish() {
+        assert_eq!(with_timeout(10, || run_transfers(10_000)), (11_000, -9_000));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn lock_only_once_at_a_time() {
+        let result = with_timeout(5, || {
+            let counter = Mutex::new(4);
+            increment_if_even(&counter);
+            increment_if_even(&counter);
+            counter.into_inner().unwrap()
+        });
+        assert_eq!(result, 5);
+    }
+}
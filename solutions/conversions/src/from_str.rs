@@ -0,0 +1,81 @@
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+// Exercise 1
+// Implement `FromStr` for `Point`, parsed from "(x, y)"
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq)]
+enum ParsePointError {
+    MissingParens,
+    MissingComma,
+    BadNumber(ParseIntError),
+}
+
+// Exercise 2
+// Let `?` turn a `ParseIntError` into a `ParsePointError`
+impl From<ParseIntError> for ParsePointError {
+    fn from(err: ParseIntError) -> Self {
+        ParsePointError::BadNumber(err)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .trim()
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or(ParsePointError::MissingParens)?;
+        let (x, y) = inner.split_once(',').ok_or(ParsePointError::MissingComma)?;
+        Ok(Point {
+            x: x.trim().parse()?,
+            y: y.trim().parse()?,
+        })
+    }
+}
+
+// Exercise 3
+// With `FromStr` implemented, `str::parse` works for `Point`: parse a path
+// of points separated by ';'
+fn path(text: &str) -> Result<Vec<Point>, ParsePointError> {
+    text.split(';').map(str::parse).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn parse_a_point() {
+        assert_eq!("(3, -4)".parse(), Ok(Point { x: 3, y: -4 }));
+        assert_eq!("3, 4".parse::<Point>(), Err(ParsePointError::MissingParens));
+        assert_eq!("(3 4)".parse::<Point>(), Err(ParsePointError::MissingComma));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn bad_numbers() {
+        assert!(matches!(
+            "(3, four)".parse::<Point>(),
+            Err(ParsePointError::BadNumber(_))
+        ));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn parse_a_path() {
+        assert_eq!(
+            path("(0,0);(1,2)"),
+            Ok(vec![Point { x: 0, y: 0 }, Point { x: 1, y: 2 }])
+        );
+        assert!(path("(0,0);oops").is_err());
+    }
+}
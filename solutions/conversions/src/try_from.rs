@@ -0,0 +1,82 @@
+// Exercise 1
+// A percentage is between 0 and 100. Implement `TryFrom<i32>`, failing with
+// `PercentError::OutOfRange` holding the bad value
+#[derive(Debug, PartialEq)]
+struct Percent(u8);
+
+#[derive(Debug, PartialEq)]
+enum PercentError {
+    OutOfRange(i32),
+}
+
+impl TryFrom<i32> for Percent {
+    type Error = PercentError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match u8::try_from(value) {
+            Ok(percent) if percent <= 100 => Ok(Percent(percent)),
+            _ => Err(PercentError::OutOfRange(value)),
+        }
+    }
+}
+
+// Exercise 2
+// Implement `TryFrom<&[i16]>` for `Rgb`: exactly three values, each 0-255
+#[derive(Debug, PartialEq)]
+struct Rgb(u8, u8, u8);
+
+#[derive(Debug, PartialEq)]
+enum RgbError {
+    WrongLength(usize),
+    OutOfRange(i16),
+}
+
+impl TryFrom<&[i16]> for Rgb {
+    type Error = RgbError;
+
+    fn try_from(values: &[i16]) -> Result<Self, Self::Error> {
+        let [r, g, b] = values else {
+            return Err(RgbError::WrongLength(values.len()));
+        };
+        let channel = |v: i16| u8::try_from(v).map_err(|_| RgbError::OutOfRange(v));
+        Ok(Rgb(channel(*r)?, channel(*g)?, channel(*b)?))
+    }
+}
+
+// Exercise 3
+// `TryInto` comes for free too: convert a list of scores into percentages,
+// failing on the first bad one
+fn percentages(scores: &[i32]) -> Result<Vec<Percent>, PercentError> {
+    scores.iter().map(|score| (*score).try_into()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn percent_in_range() {
+        assert_eq!(Percent::try_from(42), Ok(Percent(42)));
+        assert_eq!(Percent::try_from(101), Err(PercentError::OutOfRange(101)));
+        assert_eq!(Percent::try_from(-1), Err(PercentError::OutOfRange(-1)));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn rgb_from_slice() {
+        assert_eq!(Rgb::try_from(&[1, 2, 3][..]), Ok(Rgb(1, 2, 3)));
+        assert_eq!(Rgb::try_from(&[1, 2][..]), Err(RgbError::WrongLength(2)));
+        assert_eq!(
+            Rgb::try_from(&[1, 256, 3][..]),
+            Err(RgbError::OutOfRange(256))
+        );
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn try_into_for_free() {
+        assert_eq!(percentages(&[10, 20]), Ok(vec![Percent(10), Percent(20)]));
+        assert_eq!(percentages(&[10, 200]), Err(PercentError::OutOfRange(200)));
+    }
+}
@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+// Each exercise is a question about what `Cow` does. Write your answer after
+// the question, then a test that checks it
+
+fn shout(text: &str) -> Cow<'_, str> {
+    if text.chars().any(char::is_lowercase) {
+        Cow::Owned(text.to_uppercase())
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exercise 1
+    // Does `shout("HEY")` borrow or own its result? Borrow
+    #[test]
+    fn already_upper_case() {
+        assert!(matches!(shout("HEY"), Cow::Borrowed("HEY")));
+        assert!(matches!(shout("hey"), Cow::Owned(_)));
+    }
+
+    // Exercise 2
+    // After `to_mut` on a borrowed `Cow`, is it still borrowed? No, it owns
+    // a copy, and the original is left untouched
+    #[test]
+    fn to_mut_clones_on_write() {
+        let original = String::from("ferris");
+        let mut cow = Cow::Borrowed(original.as_str());
+        cow.to_mut().push_str(" the crab");
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow, "ferris the crab");
+        assert_eq!(original, "ferris");
+    }
+
+    // Exercise 3
+    // Does `into_owned` on an owned `Cow` copy the data? No, it hands over
+    // the `String` it already has
+    #[test]
+    fn into_owned_reuses_the_allocation() {
+        let owned = shout("quiet");
+        let Cow::Owned(ref inner) = owned else {
+            panic!("lower-case text is upper-cased into a new String");
+        };
+        let address = inner.as_ptr();
+        let string: String = owned.into_owned();
+        assert_eq!(string.as_ptr(), address);
+    }
+
+    // Exercise 4
+    // Can a function return `Cow<'static, str>` that is sometimes a literal
+    // and sometimes built at runtime? Yes, literals live for `'static`
+    #[test]
+    fn static_or_formatted() {
+        fn label(count: usize) -> Cow<'static, str> {
+            match count {
+                0 => Cow::Borrowed("none"),
+                1 => Cow::Borrowed("one"),
+                n => Cow::Owned(format!("{n} items")),
+            }
+        }
+        assert_eq!(label(0), "none");
+        assert_eq!(label(3), "3 items");
+        assert!(matches!(label(1), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+struct EmptyConfig;
+
+impl fmt::Display for EmptyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the config is empty")
+    }
+}
+
+// Exercise 1
+// Make `EmptyConfig` a real error so it fits in a `Box<dyn Error>`
+impl Error for EmptyConfig {}
+
+// Exercise 2
+// Input: a config line like "port=8080"
+// Output: the port number. Any kind of error can come out: an empty line
+// (`EmptyConfig`), a missing '=' (a `String` message) or a bad number
+fn port(line: &str) -> Result<u16, Box<dyn Error>> {
+    if line.trim().is_empty() {
+        return Err(Box::new(EmptyConfig));
+    }
+    let (_, value) = line.split_once('=').ok_or("expected key=value")?;
+    Ok(value.trim().parse()?)
+}
+
+// Exercise 3
+// Boxed errors can still be inspected: say whether the error is an `EmptyConfig`
+fn is_empty_config(err: &(dyn Error + 'static)) -> bool {
+    err.downcast_ref::<EmptyConfig>().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn empty_config_is_an_error() {
+        let err: Box<dyn Error> = Box::new(EmptyConfig);
+        assert_eq!(err.to_string(), "the config is empty");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn port_works() {
+        assert_eq!(port("port = 8080").unwrap(), 8080);
+        assert_eq!(
+            port("port 8080").unwrap_err().to_string(),
+            "expected key=value"
+        );
+        assert!(port("port=eighty").is_err());
+        assert!(port("port=99999").is_err());
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn downcast_the_error() {
+        assert!(is_empty_config(port("  ").unwrap_err().as_ref()));
+        assert!(!is_empty_config(port("port").unwrap_err().as_ref()));
+    }
+}
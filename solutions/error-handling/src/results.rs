@@ -0,0 +1,76 @@
+use std::num::ParseIntError;
+
+// Exercise 1
+// Input: a quantity typed by a customer, like "3"
+// Output: the total cost at 5 per item, or the parse error
+fn total_cost(quantity: &str) -> Result<i32, ParseIntError> {
+    let quantity: i32 = quantity.trim().parse()?;
+    Ok(quantity * 5)
+}
+
+// Exercise 2
+// Make me compile!
+// Add both numbers, using `?` instead of `match` to hand errors back
+fn add_strings(a: &str, b: &str) -> Result<i32, ParseIntError> {
+    Ok(a.parse::<i32>()? + b.parse::<i32>()?)
+}
+
+// Exercise 3
+// Input: an age
+// Output: Ok with the age if it is between 0 and 150, Err with a message otherwise
+fn check_age(age: i32) -> Result<u8, String> {
+    if (0..=150).contains(&age) {
+        Ok(age as u8)
+    } else {
+        Err(format!("{age} is not a valid age"))
+    }
+}
+
+// Exercise 4
+// Parse every number of a comma separated list,
+// stopping at the first one that is not a number
+fn parse_all(list: &str) -> Result<Vec<i32>, ParseIntError> {
+    list.split(',').map(|item| item.trim().parse()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn item_quantity_is_a_valid_number() {
+        assert_eq!(total_cost("34"), Ok(170));
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn item_quantity_is_an_invalid_number() {
+        assert_eq!(
+            total_cost("beep boop").unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn add_strings_works() {
+        assert_eq!(add_strings("2", "40"), Ok(42));
+        assert!(add_strings("2", "forty").is_err());
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn check_age_works() {
+        assert_eq!(check_age(30), Ok(30));
+        assert_eq!(check_age(-3), Err("-3 is not a valid age".to_string()));
+        assert!(check_age(200).is_err());
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn parse_all_works() {
+        assert_eq!(parse_all("1, 2,3"), Ok(vec![1, 2, 3]));
+        assert!(parse_all("1, two, 3").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+// Exercise 1
+// `hashmap!` builds a `HashMap` from `key => value` pairs, like `vec!`
+// builds a `Vec`. It accepts an empty map and a trailing comma
+macro_rules! hashmap {
+    () => {
+        ::std::collections::HashMap::new()
+    };
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let mut map = ::std::collections::HashMap::new();
+        $(map.insert($key, $value);)+
+        map
+    }};
+}
+
+// Exercise 2
+// `count!` expands to the number of tokens it is given, as a constant.
+// Use it to give the map enough capacity up front in `hashmap_with_capacity!`
+macro_rules! count {
+    () => { 0usize };
+    ($head:tt $($tail:tt)*) => { 1usize + count!($($tail)*) };
+}
+
+macro_rules! hashmap_with_capacity {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        let mut map = ::std::collections::HashMap::with_capacity(count!($($key)*));
+        $(map.insert($key, $value);)*
+        map
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    // Test for exercise 1
+    #[test]
+    fn builds_a_map() {
+        let ages = hashmap! {
+            "alice" => 31,
+            "bob" => 27,
+        };
+        assert_eq!(ages.len(), 2);
+        assert_eq!(ages["alice"], 31);
+        assert_eq!(ages["bob"], 27);
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn empty_and_single_entry() {
+        let empty: HashMap<u8, u8> = hashmap!();
+        assert!(empty.is_empty());
+        let one = hashmap! { 1 + 1 => "two" };
+        assert_eq!(one.get(&2), Some(&"two"));
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn later_keys_win() {
+        let map = hashmap! { 'a' => 1, 'a' => 2 };
+        assert_eq!(map, HashMap::from([('a', 2)]));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn counts_tokens() {
+        const THREE: usize = count!(a b c);
+        assert_eq!(THREE, 3);
+        assert_eq!(count!(), 0);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn reserves_capacity() {
+        let map = hashmap_with_capacity! { 1 => 'a', 2 => 'b', 3 => 'c' };
+        assert_eq!(map.len(), 3);
+        assert!(map.capacity() >= 3);
+    }
+}
@@ -0,0 +1,133 @@
+use state_machine::StateMachine;
+
+// Exercise 1
+// `state!` writes the boilerplate of a state machine from a table:
+//
+//     state! {
+//         Turnstile {
+//             states: Gate { Locked, Unlocked },
+//             events: Action { Coin, Push },
+//             Locked + Coin => Unlocked,
+//             Unlocked + Push => Locked,
+//         }
+//     }
+//
+// expands to a unit struct `Turnstile`, `Copy` enums `Gate` and `Action`
+// and an `impl StateMachine for Turnstile` where every pair that is not in
+// the table leaves the state as it is
+macro_rules! state {
+    (
+        $machine:ident {
+            states: $state:ident { $($variant:ident),+ $(,)? },
+            events: $event:ident { $($action:ident),+ $(,)? },
+            $($from:ident + $on:ident => $to:ident),* $(,)?
+        }
+    ) => {
+        struct $machine;
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum $state {
+            $($variant),+
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum $event {
+            $($action),+
+        }
+
+        impl StateMachine for $machine {
+            type State = $state;
+            type Transition = $event;
+
+            fn next_state(starting_state: &$state, t: &$event) -> $state {
+                match (starting_state, t) {
+                    $(($state::$from, $event::$on) => $state::$to,)*
+                    #[allow(unreachable_patterns)]
+                    _ => *starting_state,
+                }
+            }
+        }
+    };
+}
+
+state! {
+    Turnstile {
+        states: Gate { Locked, Unlocked },
+        events: Action { Coin, Push },
+        Locked + Coin => Unlocked,
+        Unlocked + Push => Locked,
+    }
+}
+
+// Exercise 2
+// Use `state!` to write a traffic light that cycles Red, Green, Yellow on
+// `Timer` and goes straight to Red from any colour on `Emergency`
+state! {
+    TrafficLight {
+        states: Light { Red, Green, Yellow },
+        events: Signal { Timer, Emergency },
+        Red + Timer => Green,
+        Green + Timer => Yellow,
+        Yellow + Timer => Red,
+        Green + Emergency => Red,
+        Yellow + Emergency => Red,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use state_machine::run;
+
+    // Test for exercise 1
+    #[test]
+    fn turnstile_transitions() {
+        assert_eq!(
+            Turnstile::next_state(&Gate::Locked, &Action::Coin),
+            Gate::Unlocked
+        );
+        assert_eq!(
+            Turnstile::next_state(&Gate::Unlocked, &Action::Push),
+            Gate::Locked
+        );
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn missing_pairs_keep_the_state() {
+        assert_eq!(
+            Turnstile::next_state(&Gate::Locked, &Action::Push),
+            Gate::Locked
+        );
+        assert_eq!(
+            Turnstile::next_state(&Gate::Unlocked, &Action::Coin),
+            Gate::Unlocked
+        );
+        assert!(!Turnstile::is_terminal(&Gate::Locked));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn traffic_light_cycles() {
+        use Signal::*;
+        assert_eq!(
+            run::<TrafficLight>(Light::Red, &[Timer, Timer]),
+            Light::Yellow
+        );
+        assert_eq!(
+            run::<TrafficLight>(Light::Red, &[Timer, Timer, Timer]),
+            Light::Red
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn emergency_goes_to_red() {
+        for light in [Light::Red, Light::Green, Light::Yellow] {
+            assert_eq!(
+                TrafficLight::next_state(&light, &Signal::Emergency),
+                Light::Red
+            );
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Exercise 1
+// All of the library used to live in this one file. It is now split into
+// `library/books.rs` and `library/members.rs`: declare both modules here
+#[path = "library/books.rs"]
+mod books;
+#[path = "library/members.rs"]
+mod members;
+
+// Exercise 2
+// Re-export what users need so they can write `library::Book` instead of
+// `library::books::Book`
+pub use books::Book;
+pub use members::{borrow, Member};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn books_and_members() {
+        let book = Book::new("Rust in Action", 2);
+        let member = Member::new("Ferris");
+        assert_eq!(book.title(), "Rust in Action");
+        assert_eq!(member.name, "Ferris");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn borrow_through_the_public_path() {
+        let mut book = Book::new("The Rust Book", 1);
+        let mut member = Member::new("Corro");
+        assert_eq!(borrow(&mut member, &mut book), Ok(()));
+        assert_eq!(member.borrowed, vec!["The Rust Book"]);
+        assert_eq!(
+            borrow(&mut member, &mut book),
+            Err("no copies of The Rust Book left".to_string())
+        );
+    }
+}
@@ -0,0 +1,27 @@
+// Exercise 1
+// `Member` and `borrow` moved here. `borrow` needs `Book` from the sibling
+// module: reach it through `super`
+use super::books::Book;
+
+pub struct Member {
+    pub name: String,
+    pub borrowed: Vec<String>,
+}
+
+impl Member {
+    pub fn new(name: &str) -> Member {
+        Member {
+            name: name.to_string(),
+            borrowed: Vec::new(),
+        }
+    }
+}
+
+pub fn borrow(member: &mut Member, book: &mut Book) -> Result<(), String> {
+    if book.copies == 0 {
+        return Err(format!("no copies of {} left", book.title()));
+    }
+    book.copies -= 1;
+    member.borrowed.push(book.title().to_string());
+    Ok(())
+}
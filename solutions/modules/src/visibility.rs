@@ -0,0 +1,89 @@
+// Exercise 1
+// Make me compile!
+// Items are private to their module unless marked `pub`
+mod kitchen {
+    pub fn make_sandwich() -> String {
+        format!("sandwich with {}", secret_sauce())
+    }
+
+    // The recipe stays private: only code inside `kitchen` may call it
+    fn secret_sauce() -> &'static str {
+        "mayo"
+    }
+
+    // Exercise 2
+    // A `pub` struct still has private fields. Customers may read the
+    // toppings but only `Order::new` may build an order, so the price can
+    // not be made up
+    pub struct Order {
+        pub toppings: Vec<String>,
+        price: u32,
+    }
+
+    impl Order {
+        pub fn new(toppings: &[&str]) -> Order {
+            Order {
+                toppings: toppings.iter().map(|t| t.to_string()).collect(),
+                price: 5 + 2 * toppings.len() as u32,
+            }
+        }
+
+        pub fn price(&self) -> u32 {
+            self.price
+        }
+    }
+
+    // Exercise 3
+    // The variants of a `pub` enum are public too
+    pub enum Size {
+        Small,
+        Large,
+    }
+
+    // Exercise 4
+    // Nested modules: `staff` is visible to the whole crate but not outside it
+    pub(crate) mod staff {
+        pub fn chef() -> &'static str {
+            "Remy"
+        }
+    }
+}
+
+fn describe(size: kitchen::Size) -> &'static str {
+    match size {
+        kitchen::Size::Small => "small",
+        kitchen::Size::Large => "large",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn public_function() {
+        assert_eq!(kitchen::make_sandwich(), "sandwich with mayo");
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn private_fields_need_a_constructor() {
+        let order = kitchen::Order::new(&["ham", "cheese"]);
+        assert_eq!(order.toppings, vec!["ham", "cheese"]);
+        assert_eq!(order.price(), 9);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn public_enum_variants() {
+        assert_eq!(describe(kitchen::Size::Small), "small");
+        assert_eq!(describe(kitchen::Size::Large), "large");
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn crate_visible_module() {
+        assert_eq!(kitchen::staff::chef(), "Remy");
+    }
+}
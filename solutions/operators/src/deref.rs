@@ -0,0 +1,81 @@
+use std::ops::{Deref, DerefMut};
+
+// Exercise 1
+// `Sorted` is a `Vec` that stays sorted. Implement `Deref` to a slice so
+// every read-only slice method (`len`, `iter`, `first`, `binary_search`...)
+// works on it directly
+#[derive(Debug, Default)]
+struct Sorted(Vec<i32>);
+
+impl Sorted {
+    fn insert(&mut self, value: i32) {
+        let position = self.0.partition_point(|v| *v < value);
+        self.0.insert(position, value);
+    }
+}
+
+impl Deref for Sorted {
+    type Target = [i32];
+
+    fn deref(&self) -> &[i32] {
+        &self.0
+    }
+}
+
+// Exercise 2
+// `DerefMut` would let callers write any value anywhere and break the
+// order. Implement it for `Name` instead, where any change is fine
+struct Name(String);
+
+impl Deref for Name {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl DerefMut for Name {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
+// Exercise 3
+// Deref coercion: a `&Name` is accepted where a `&str` is expected
+fn shout(text: &str) -> String {
+    text.to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn slice_methods_on_sorted() {
+        let mut sorted = Sorted::default();
+        for value in [5, 1, 4, 1] {
+            sorted.insert(value);
+        }
+        assert_eq!(&*sorted, &[1, 1, 4, 5]);
+        assert_eq!(sorted.len(), 4);
+        assert_eq!(sorted.first(), Some(&1));
+        assert_eq!(sorted.binary_search(&4), Ok(2));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn string_methods_on_name() {
+        let mut name = Name("ferris".to_string());
+        name.push_str(" the crab");
+        assert_eq!(name.len(), 15);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn deref_coercion() {
+        let name = Name("corro".to_string());
+        assert_eq!(shout(&name), "CORRO");
+    }
+}
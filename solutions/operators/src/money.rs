@@ -0,0 +1,130 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+// Amounts of money are counted in cents, never in floating point
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+struct Money {
+    cents: i64,
+}
+
+impl Money {
+    fn new(units: i64, cents: i64) -> Money {
+        Money {
+            cents: units * 100 + cents,
+        }
+    }
+}
+
+// Exercise 1
+// Implement `Add` and `Sub` so `a + b` and `a - b` work on `Money`
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money {
+            cents: self.cents + other.cents,
+        }
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money {
+            cents: self.cents - other.cents,
+        }
+    }
+}
+
+// Exercise 2
+// `money * 3` multiplies an amount by a count. The right hand side is an
+// `i64`, not `Money`: multiplying money by money means nothing
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, count: i64) -> Money {
+        Money {
+            cents: self.cents * count,
+        }
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money { cents: -self.cents }
+    }
+}
+
+// Exercise 3
+// `+=` is its own trait, and `Sum` lets `.sum()` add up an iterator of `Money`
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        self.cents += other.cents;
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::default(), Add::add)
+    }
+}
+
+// Exercise 4
+// Display amounts like "$12.05" and "-$0.50"
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let cents = self.cents.abs();
+        write!(f, "{sign}${}.{:02}", cents / 100, cents % 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn add_and_subtract() {
+        assert_eq!(Money::new(1, 50) + Money::new(2, 75), Money::new(4, 25));
+        assert_eq!(Money::new(1, 0) - Money::new(0, 1), Money::new(0, 99));
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn addition_laws() {
+        let (a, b, c) = (Money::new(3, 10), Money::new(0, 95), Money::new(-2, 0));
+        assert_eq!(a + b, b + a);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + Money::default(), a);
+        assert_eq!(a - a, Money::default());
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn multiply_and_negate() {
+        assert_eq!(Money::new(2, 50) * 3, Money::new(7, 50));
+        assert_eq!(-Money::new(1, 0), Money::new(-1, 0));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn add_assign_and_sum() {
+        let mut wallet = Money::new(5, 0);
+        wallet += Money::new(0, 25);
+        assert_eq!(wallet, Money::new(5, 25));
+        let total: Money = [Money::new(1, 0), Money::new(2, 0)].into_iter().sum();
+        assert_eq!(total, Money::new(3, 0));
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn display() {
+        assert_eq!(Money::new(12, 5).to_string(), "$12.05");
+        assert_eq!(Money::new(0, -50).to_string(), "-$0.50");
+    }
+}
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+// A version number like 1.10.2
+#[derive(Debug, Clone, Copy)]
+struct Version {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    /// Build metadata, ignored when comparing versions
+    build: u32,
+}
+
+fn v(major: u32, minor: u32, patch: u32) -> Version {
+    Version {
+        major,
+        minor,
+        patch,
+        build: 0,
+    }
+}
+
+// Exercise 1
+// `#[derive(PartialEq)]` would compare `build` too. Implement `PartialEq`
+// and `Eq` by hand so two versions differing only in build are equal
+impl PartialEq for Version {
+    fn eq(&self, other: &Version) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+// Exercise 2
+// Implement `Ord` to compare major, then minor, then patch, as numbers:
+// 1.10.0 is newer than 1.9.0. `PartialOrd` must agree with `Ord`
+impl Ord for Version {
+    fn cmp(&self, other: &Version) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Exercise 3
+// With `Ord` in place, `sort`, `max` and `BTreeSet` all work on versions
+fn newest(versions: &[Version]) -> Option<Version> {
+    versions.iter().copied().max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn build_is_ignored() {
+        let mut other = v(1, 2, 3);
+        other.build = 99;
+        assert_eq!(v(1, 2, 3), other);
+        assert_ne!(v(1, 2, 3), v(1, 2, 4));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn numeric_order() {
+        assert!(v(1, 10, 0) > v(1, 9, 0));
+        assert!(v(2, 0, 0) > v(1, 99, 99));
+        assert!(v(0, 1, 2) < v(0, 1, 3));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn ordering_laws() {
+        let versions = [v(1, 0, 0), v(0, 9, 9), v(1, 0, 1), v(1, 0, 0)];
+        for a in versions {
+            for b in versions {
+                // Antisymmetric, and consistent with `==` and `partial_cmp`
+                assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+                assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+                assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+                for c in versions {
+                    if a <= b && b <= c {
+                        assert!(a <= c);
+                    }
+                }
+            }
+        }
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn sort_versions() {
+        let mut versions = vec![v(1, 10, 0), v(1, 2, 0), v(1, 9, 9)];
+        versions.sort();
+        assert_eq!(versions, vec![v(1, 2, 0), v(1, 9, 9), v(1, 10, 0)]);
+        assert_eq!(newest(&versions), Some(v(1, 10, 0)));
+        assert_eq!(newest(&[]), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Stage 3: a store kept in a file.
+
+use crate::command::Command;
+use crate::store::Store;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+// Stage 3
+// The file is a log of every change, one `SET` or `DEL` command per line,
+// written with the `Display` of `Command` from stage 2. Opening the store
+// replays the log into a `Store`. Every change is appended to the log
+// before it is applied, so nothing is lost if the program stops.
+// `compact` rewrites the log with one `SET` per key that is left
+
+/// A `Store` that keeps a log of its changes in a file.
+#[derive(Debug)]
+pub struct FileStore {
+    path: PathBuf,
+    log: File,
+    store: Store,
+}
+
+fn invalid(line: usize, message: impl std::fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {line}: {message}"),
+    )
+}
+
+impl FileStore {
+    /// Open the store at `path`, creating the file if it does not exist yet
+    pub fn open(path: impl AsRef<Path>) -> io::Result<FileStore> {
+        let path = path.as_ref().to_path_buf();
+        let log = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&path)?;
+
+        let mut store = Store::new();
+        for (number, line) in BufReader::new(&log).lines().enumerate() {
+            match Command::parse(&line?).map_err(|err| invalid(number + 1, err))? {
+                command @ (Command::Set { .. } | Command::Del { .. }) => {
+                    command.run(&mut store);
+                }
+                other => return Err(invalid(number + 1, format!("`{other}` is not a change"))),
+            }
+        }
+        Ok(FileStore { path, log, store })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.store.get(key)
+    }
+
+    pub fn keys(&self) -> Vec<&str> {
+        self.store.keys()
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> io::Result<()> {
+        let command = Command::Set {
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        self.append(&command)?;
+        command.run(&mut self.store);
+        Ok(())
+    }
+
+    /// Remove `key`, returning whether it was there
+    pub fn remove(&mut self, key: &str) -> io::Result<bool> {
+        if self.store.get(key).is_none() {
+            return Ok(false);
+        }
+        let command = Command::Del {
+            key: key.to_string(),
+        };
+        self.append(&command)?;
+        command.run(&mut self.store);
+        Ok(true)
+    }
+
+    /// Rewrite the log so it only holds the entries that are left
+    pub fn compact(&mut self) -> io::Result<()> {
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".compact");
+        let mut file = File::create(&temporary)?;
+        for key in self.store.keys() {
+            let value = self.store.get(key).unwrap_or_default();
+            writeln!(file, "SET {key} {value}")?;
+        }
+        file.sync_all()?;
+        std::fs::rename(&temporary, &self.path)?;
+        self.log = OpenOptions::new().append(true).open(&self.path)?;
+        Ok(())
+    }
+
+    fn append(&mut self, command: &Command) -> io::Result<()> {
+        writeln!(self.log, "{command}")?;
+        self.log.flush()
+    }
+}
@@ -0,0 +1,73 @@
+//! Stage 1: what to search for, and where.
+
+use std::fmt;
+
+pub const USAGE: &str = "usage: minigrep [-i | --ignore-case] <query> <file>";
+
+/// The environment variable that turns on case-insensitive search
+pub const IGNORE_CASE: &str = "IGNORE_CASE";
+
+// Stage 1
+// `build` takes the arguments after the program name and a way to look up
+// environment variables, so tests do not have to change the real ones.
+// The search ignores case when `-i` or `--ignore-case` is given, or when
+// `IGNORE_CASE` is set to anything but `0`. Options may come anywhere
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub query: String,
+    pub file_path: String,
+    pub ignore_case: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    MissingQuery,
+    MissingFile,
+    UnexpectedArgument(String),
+    UnknownOption(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingQuery => write!(f, "missing the query"),
+            ConfigError::MissingFile => write!(f, "missing the file to search"),
+            ConfigError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            ConfigError::UnknownOption(option) => write!(f, "unknown option `{option}`"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    pub fn build(
+        args: impl IntoIterator<Item = String>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, ConfigError> {
+        let mut ignore_case = env(IGNORE_CASE).is_some_and(|value| value != "0");
+        let mut positional = Vec::new();
+        for arg in args {
+            match arg.as_str() {
+                "-i" | "--ignore-case" => ignore_case = true,
+                option if option.starts_with('-') && option.len() > 1 => {
+                    return Err(ConfigError::UnknownOption(arg));
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let query = positional.next().ok_or(ConfigError::MissingQuery)?;
+        let file_path = positional.next().ok_or(ConfigError::MissingFile)?;
+        if let Some(extra) = positional.next() {
+            return Err(ConfigError::UnexpectedArgument(extra));
+        }
+        Ok(Config {
+            query,
+            file_path,
+            ignore_case,
+        })
+    }
+}
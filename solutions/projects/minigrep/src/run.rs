@@ -0,0 +1,20 @@
+//! Stage 3: search a file.
+
+use crate::config::Config;
+use crate::search::{search, search_case_insensitive};
+use std::error::Error;
+
+// Stage 3
+// Read the file of `config`, search it and return the matching lines. When
+// the file cannot be read, the error says which file it was
+
+pub fn run(config: &Config) -> Result<Vec<String>, Box<dyn Error>> {
+    let contents = std::fs::read_to_string(&config.file_path)
+        .map_err(|err| format!("could not read {}: {err}", config.file_path))?;
+    let lines = if config.ignore_case {
+        search_case_insensitive(&config.query, &contents)
+    } else {
+        search(&config.query, &contents)
+    };
+    Ok(lines.into_iter().map(String::from).collect())
+}
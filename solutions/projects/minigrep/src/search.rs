@@ -0,0 +1,22 @@
+//! Stage 2: the lines that match.
+
+// Stage 2
+// Return the lines of `contents` containing `query`, in order. The results
+// borrow from `contents`, which the lifetime `'a` says. The case-insensitive
+// version lowercases both sides before comparing, but still returns the
+// lines as they are
+
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| line.contains(query))
+        .collect()
+}
+
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    contents
+        .lines()
+        .filter(|line| line.to_lowercase().contains(&query))
+        .collect()
+}
@@ -0,0 +1,2 @@
+// The stage tests are the same for the solutions, built with `--features solutions`
+include!("../../../../exercises/projects/minigrep/tests/config.rs");
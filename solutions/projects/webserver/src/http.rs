@@ -0,0 +1,76 @@
+//! Stage 2: just enough HTTP.
+
+use std::fmt;
+
+// Stage 2
+// A request starts with a line like `GET /index.html HTTP/1.1`. Parse its
+// method and path, rejecting lines that do not have exactly three parts or
+// do not end with an `HTTP/` version. A response is a status line, a
+// `Content-Length` header, a blank line and the body, with `\r\n` line ends
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadRequest(pub String);
+
+impl fmt::Display for BadRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bad request line {:?}", self.0)
+    }
+}
+
+impl std::error::Error for BadRequest {}
+
+impl Request {
+    pub fn parse(request_line: &str) -> Result<Request, BadRequest> {
+        let parts: Vec<&str> = request_line.split_whitespace().collect();
+        match parts[..] {
+            [method, path, version] if version.starts_with("HTTP/") => Ok(Request {
+                method: method.to_string(),
+                path: path.to_string(),
+            }),
+            _ => Err(BadRequest(request_line.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn new(status: u16, body: &str) -> Response {
+        Response {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    pub fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Unknown",
+        }
+    }
+
+    /// The response as it is sent over the connection
+    pub fn to_bytes(&self) -> Vec<u8> {
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Length: {}\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )
+        .into_bytes()
+    }
+}
@@ -0,0 +1,95 @@
+//! Stage 1: a pool of worker threads.
+
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+// Stage 1
+// The pool starts `size` workers that share the receiving end of a channel
+// behind an `Arc<Mutex<..>>`. `execute` sends a job down the channel and
+// the first idle worker runs it. Dropping the pool closes the channel, so
+// every worker finishes the jobs already sent and then stops, and waits for
+// all of them
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: Option<Sender<Job>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolCreationError;
+
+impl fmt::Display for PoolCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a thread pool needs at least one thread")
+    }
+}
+
+impl std::error::Error for PoolCreationError {}
+
+impl ThreadPool {
+    /// A pool of `size` threads, which must be at least one
+    pub fn build(size: usize) -> Result<ThreadPool, PoolCreationError> {
+        if size == 0 {
+            return Err(PoolCreationError);
+        }
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..size)
+            .map(|id| Worker::new(id, Arc::clone(&receiver)))
+            .collect();
+        Ok(ThreadPool {
+            workers,
+            sender: Some(sender),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Run `job` on the next idle worker
+    pub fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        self.sender
+            .as_ref()
+            .expect("the sender is only taken on drop")
+            .send(Box::new(job))
+            .expect("workers only stop once the sender is dropped");
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Closing the channel tells the workers to stop once it is empty
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            // A job that panicked already stopped its worker
+            let _ = worker.thread.join();
+        }
+    }
+}
+
+struct Worker {
+    thread: JoinHandle<()>,
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<Receiver<Job>>>) -> Worker {
+        let worker = thread::Builder::new().name(format!("worker-{id}"));
+        let thread = worker.spawn(move || loop {
+            // The lock guard is dropped at the end of this statement, so the
+            // job runs without holding it
+            let job = receiver.lock().expect("a worker panicked").recv();
+            match job {
+                Ok(job) => job(),
+                Err(_) => break,
+            }
+        });
+        Worker {
+            thread: thread.expect("the system could not start a worker thread"),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Stage 3: the server itself.
+
+use crate::http::{Request, Response};
+use crate::pool::ThreadPool;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// Stage 3
+// `start` binds the address and accepts connections on a thread of its
+// own, handing each one to a `ThreadPool`. Bind to port 0 to get a free
+// port, and read it back with `addr`.
+//
+// `shutdown` must be graceful: stop accepting new connections, but let the
+// requests already being handled finish before it returns. `accept` blocks,
+// so after raising the stop flag connect to the server once to wake it up.
+//
+// Routes:
+// + `GET /` answers 200 with `Hello!`
+// + `GET /sleep` answers the same after half a second
+// + any other `GET` path is a 404, any other method a 405, and a request
+//   line that does not parse a 400
+
+pub struct Server {
+    addr: SocketAddr,
+    stopping: Arc<AtomicBool>,
+    acceptor: JoinHandle<()>,
+}
+
+impl Server {
+    pub fn start(addr: impl ToSocketAddrs, threads: usize) -> io::Result<Server> {
+        let pool = ThreadPool::build(threads)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let stopping = Arc::new(AtomicBool::new(false));
+
+        let stop = Arc::clone(&stopping);
+        let acceptor = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    pool.execute(move || {
+                        // The client hanging up early is not the server's problem
+                        let _ = handle(stream);
+                    });
+                }
+            }
+            // Dropping the pool waits for the requests in flight
+        });
+
+        Ok(Server {
+            addr,
+            stopping,
+            acceptor,
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Stop accepting connections and wait for the ones being handled
+    pub fn shutdown(self) {
+        self.stopping.store(true, Ordering::SeqCst);
+        // Wake up the blocked `accept`
+        let _ = TcpStream::connect(self.addr);
+        self.acceptor.join().expect("the accept loop panicked");
+    }
+}
+
+fn route(request: &Request) -> Response {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/") => Response::new(200, "Hello!"),
+        ("GET", "/sleep") => {
+            thread::sleep(Duration::from_millis(500));
+            Response::new(200, "Hello!")
+        }
+        ("GET", _) => Response::new(404, "Not found"),
+        _ => Response::new(405, "Only GET is supported"),
+    }
+}
+
+fn handle(mut stream: TcpStream) -> io::Result<()> {
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let response = match Request::parse(&request_line) {
+        Ok(request) => route(&request),
+        Err(err) => Response::new(400, &err.to_string()),
+    };
+    stream.write_all(&response.to_bytes())
+}
@@ -0,0 +1,94 @@
+use std::cell::Cell;
+
+// Exercise 1
+// A `Guard` runs its closure when it is dropped, however the scope is left:
+// normally, through an early `return` or `?`, or during a panic.
+// Implement `Drop` for it
+struct Guard<F: FnMut()> {
+    on_drop: F,
+    armed: bool,
+}
+
+fn guard<F: FnMut()>(on_drop: F) -> Guard<F> {
+    Guard {
+        on_drop,
+        armed: true,
+    }
+}
+
+impl<F: FnMut()> Drop for Guard<F> {
+    fn drop(&mut self) {
+        if self.armed {
+            (self.on_drop)();
+        }
+    }
+}
+
+// Exercise 2
+// `dismiss` cancels the cleanup, for when the work succeeded
+impl<F: FnMut()> Guard<F> {
+    fn dismiss(mut self) {
+        self.armed = false;
+    }
+}
+
+// Exercise 3
+// A transfer takes money out first and must put it back if the second
+// step fails. Use a guard so every early return refunds the money
+fn transfer(
+    balance: &Cell<i64>,
+    amount: i64,
+    deliver: impl FnOnce() -> Result<(), String>,
+) -> Result<(), String> {
+    balance.set(balance.get() - amount);
+    let refund = guard(|| balance.set(balance.get() + amount));
+    deliver()?;
+    refund.dismiss();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn runs_at_end_of_scope() {
+        let cleaned = Cell::new(0);
+        {
+            let _guard = guard(|| cleaned.set(cleaned.get() + 1));
+            assert_eq!(cleaned.get(), 0);
+        }
+        assert_eq!(cleaned.get(), 1);
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn runs_during_a_panic() {
+        let cleaned = std::sync::atomic::AtomicBool::new(false);
+        let result = std::panic::catch_unwind(|| {
+            let _guard = guard(|| cleaned.store(true, std::sync::atomic::Ordering::SeqCst));
+            panic!("something went wrong");
+        });
+        assert!(result.is_err());
+        assert!(cleaned.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn dismissed_guards_do_nothing() {
+        let cleaned = Cell::new(false);
+        guard(|| cleaned.set(true)).dismiss();
+        assert!(!cleaned.get());
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn failed_transfers_refund() {
+        let balance = Cell::new(100);
+        assert_eq!(transfer(&balance, 30, || Ok(())), Ok(()));
+        assert_eq!(balance.get(), 70);
+        assert!(transfer(&balance, 50, || Err("network down".to_string())).is_err());
+        assert_eq!(balance.get(), 70);
+    }
+}
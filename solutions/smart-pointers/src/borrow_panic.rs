@@ -0,0 +1,71 @@
+use std::cell::RefCell;
+
+// Exercise 1
+// This compiles, but the tests used to panic with "already borrowed":
+// `RefCell` checks its borrow rules while the program runs.
+// Fix `transfer_all` so it never holds a `borrow()` and a `borrow_mut()`
+// of the same cell at once
+#[derive(Debug, Default)]
+struct Inbox {
+    unread: RefCell<Vec<String>>,
+    archive: RefCell<Vec<String>>,
+}
+
+impl Inbox {
+    fn receive(&self, message: &str) {
+        self.unread.borrow_mut().push(message.to_string());
+    }
+
+    // Move every unread message to the archive
+    fn transfer_all(&self) {
+        let messages: Vec<String> = self.unread.borrow_mut().drain(..).collect();
+        self.archive.borrow_mut().extend(messages);
+    }
+
+    // Exercise 2
+    // Add a copy of every unread message marked "(re)" to the unread list.
+    // Iterating over `unread` while pushing to it panics: collect first
+    fn resend_unread(&self) {
+        let copies: Vec<String> = self
+            .unread
+            .borrow()
+            .iter()
+            .map(|message| format!("(re) {message}"))
+            .collect();
+        self.unread.borrow_mut().extend(copies);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn transfer_does_not_panic() {
+        let inbox = Inbox::default();
+        inbox.receive("hello");
+        inbox.receive("bye");
+        inbox.transfer_all();
+        assert!(inbox.unread.borrow().is_empty());
+        assert_eq!(*inbox.archive.borrow(), vec!["hello", "bye"]);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn resend_does_not_panic() {
+        let inbox = Inbox::default();
+        inbox.receive("ping");
+        inbox.resend_unread();
+        assert_eq!(*inbox.unread.borrow(), vec!["ping", "(re) ping"]);
+    }
+
+    // Test for exercise 1
+    #[test]
+    #[should_panic(expected = "already")]
+    fn overlapping_borrows_panic() {
+        let cell = RefCell::new(vec![1]);
+        let _reading = cell.borrow();
+        cell.borrow_mut().push(2);
+    }
+}
@@ -0,0 +1,69 @@
+// Exercise 1
+// Make me compile!
+// A recursive type needs a known size: put the rest of the list in a `Box`
+#[derive(Debug, PartialEq)]
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+// Exercise 2
+// Input: a slice of numbers
+// Output: the list holding them in the same order
+fn from_slice(values: &[i32]) -> List {
+    match values {
+        [] => Nil,
+        [first, rest @ ..] => Cons(*first, Box::new(from_slice(rest))),
+    }
+}
+
+// Exercise 3
+// Output: the sum of every number of the list
+fn sum(list: &List) -> i32 {
+    match list {
+        Cons(value, rest) => value + sum(rest),
+        Nil => 0,
+    }
+}
+
+// Exercise 4
+// Return a boxed closure that adds `n` to its argument
+fn adder(n: i32) -> Box<dyn Fn(i32) -> i32> {
+    Box::new(move |x| x + n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn build_a_list() {
+        let list = Cons(1, Box::new(Cons(2, Box::new(Nil))));
+        assert_ne!(list, Nil);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn list_from_slice() {
+        assert_eq!(from_slice(&[]), Nil);
+        assert_eq!(from_slice(&[7]), Cons(7, Box::new(Nil)));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn sum_of_list() {
+        assert_eq!(sum(&from_slice(&[1, 2, 3, 4])), 10);
+        assert_eq!(sum(&Nil), 0);
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn boxed_closures() {
+        let adders: Vec<Box<dyn Fn(i32) -> i32>> = vec![adder(1), adder(10)];
+        let results: Vec<i32> = adders.iter().map(|add| add(5)).collect();
+        assert_eq!(results, vec![6, 15]);
+    }
+}
@@ -0,0 +1,89 @@
+// Exercise 1
+// `len()` counts bytes, not characters.
+// Output: the number of characters of the text
+fn char_count(text: &str) -> usize {
+    text.chars().count()
+}
+
+// Exercise 2
+// Input: a text and a number of characters
+// Output: the first `n` characters. Slicing with `&text[..n]` counts bytes
+// and panics in the middle of a multi-byte character
+fn first_chars(text: &str, n: usize) -> &str {
+    match text.char_indices().nth(n) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+// Exercise 3
+// Output: `Some` slice of the bytes `start..end`, or `None` when one of them
+// is not on a character boundary or past the end, instead of panicking
+fn safe_slice(text: &str, start: usize, end: usize) -> Option<&str> {
+    text.get(start..end)
+}
+
+// Exercise 4
+// Output: the text with its characters in reverse order
+fn reverse(text: &str) -> String {
+    text.chars().rev().collect()
+}
+
+// Exercise 5
+// Output: how many bytes of the text are ASCII digits. Bytes are fine here:
+// a multi-byte character never contains bytes in the ASCII range
+fn ascii_digits(text: &str) -> usize {
+    text.bytes().filter(u8::is_ascii_digit).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn count_characters() {
+        assert_eq!(char_count("hello"), 5);
+        assert_eq!(char_count("Việt Nam"), 8);
+        assert_eq!("Việt Nam".len(), 10);
+        assert_eq!(char_count("🦀🦀"), 2);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn first_characters() {
+        assert_eq!(first_chars("Việt Nam", 3), "Việ");
+        assert_eq!(first_chars("🦀 crab", 1), "🦀");
+        assert_eq!(first_chars("ab", 5), "ab");
+    }
+
+    // Test for exercise 2
+    #[test]
+    #[should_panic(expected = "char boundary")]
+    fn byte_slicing_panics() {
+        let text = String::from("🦀 crab");
+        let _ = &text[..1];
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn slice_without_panic() {
+        assert_eq!(safe_slice("Việt", 0, 2), Some("Vi"));
+        assert_eq!(safe_slice("Việt", 0, 3), None);
+        assert_eq!(safe_slice("Việt", 0, 99), None);
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn reverse_characters() {
+        assert_eq!(reverse("abc"), "cba");
+        assert_eq!(reverse("añb🦀"), "🦀bña");
+    }
+
+    // Test for exercise 5
+    #[test]
+    fn count_bytes() {
+        assert_eq!(ascii_digits("a1b2🦀3"), 3);
+        assert_eq!(ascii_digits("١٢٣"), 0);
+    }
+}
@@ -0,0 +1,109 @@
+// The median of a list is its middle value once sorted, or the average of
+// the two middle values when the list has an even length. An empty list
+// has no median
+type Median = fn(&[i32]) -> Option<f64>;
+
+fn median(values: &[i32]) -> Option<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort();
+    let middle = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[middle] as f64),
+        _ => Some((sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0),
+    }
+}
+
+// Exercise 1
+// Write checks for a `median` function: return Err with a message as soon as
+// it gets something wrong. The grader below runs your checks against the
+// correct `median` above and several broken versions
+fn check_median(median: Median) -> Result<(), String> {
+    let cases: [(&[i32], Option<f64>); 6] = [
+        (&[], None),
+        (&[7], Some(7.0)),
+        (&[3, 1, 2], Some(2.0)),
+        (&[4, 1, 3, 2], Some(2.5)),
+        (&[-5, -1], Some(-3.0)),
+        (&[1, 2, 2, 100], Some(2.0)),
+    ];
+    for (input, expected) in cases {
+        let actual = median(input);
+        if actual != expected {
+            return Err(format!(
+                "median({input:?}) = {actual:?}, expected {expected:?}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Bugs planted in copies of `median`, your checks must catch each one
+    fn forgets_to_sort(values: &[i32]) -> Option<f64> {
+        let middle = values.len() / 2;
+        match values.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(values[middle] as f64),
+            _ => Some((values[middle - 1] as f64 + values[middle] as f64) / 2.0),
+        }
+    }
+
+    fn upper_middle_only(values: &[i32]) -> Option<f64> {
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        sorted.get(sorted.len() / 2).map(|v| *v as f64)
+    }
+
+    fn integer_division(values: &[i32]) -> Option<f64> {
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        match sorted.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(sorted[middle] as f64),
+            _ => Some(((sorted[middle - 1] + sorted[middle]) / 2) as f64),
+        }
+    }
+
+    fn zero_for_empty(values: &[i32]) -> Option<f64> {
+        if values.is_empty() {
+            return Some(0.0);
+        }
+        median(values)
+    }
+
+    fn mean_instead(values: &[i32]) -> Option<f64> {
+        if values.is_empty() {
+            return None;
+        }
+        Some(values.iter().sum::<i32>() as f64 / values.len() as f64)
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn checks_pass_for_the_correct_median() {
+        assert_eq!(check_median(median), Ok(()));
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn checks_catch_every_planted_bug() {
+        let bugs: [(&str, Median); 5] = [
+            ("forgets to sort", forgets_to_sort),
+            ("only takes the upper middle", upper_middle_only),
+            ("divides integers", integer_division),
+            ("returns 0 for an empty list", zero_for_empty),
+            ("computes the mean", mean_instead),
+        ];
+        for (bug, broken) in bugs {
+            assert!(
+                check_median(broken).is_err(),
+                "your checks miss a median that {bug}"
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// A property test checks that something holds for many generated inputs,
+// instead of a few examples picked by hand.
+// `Rng` is a small random number generator with a fixed seed, so a failing
+// case fails the same way every time
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        // xorshift64
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn vec(&mut self, max_len: u64) -> Vec<i32> {
+        let len = self.next() % (max_len + 1);
+        (0..len).map(|_| (self.next() % 201) as i32 - 100).collect()
+    }
+}
+
+// Run `property` on `cases` generated vectors, reporting the first input
+// it does not hold for
+fn check(cases: usize, property: impl Fn(&[i32]) -> bool) -> Result<(), Vec<i32>> {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..cases {
+        let input = rng.vec(20);
+        if !property(&input) {
+            return Err(input);
+        }
+    }
+    Ok(())
+}
+
+fn dedup_sorted(values: &[i32]) -> Vec<i32> {
+    let mut sorted = values.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exercise 1
+    // Reversing twice gives back the input
+    #[test]
+    fn reverse_twice() {
+        let result = check(500, |input| {
+            let mut twice = input.to_vec();
+            twice.reverse();
+            twice.reverse();
+            twice == input
+        });
+        assert_eq!(result, Ok(()));
+    }
+
+    // Exercise 2
+    // Write three properties of `dedup_sorted`: its output is sorted, has no
+    // repeated value, and contains every value of the input
+    #[test]
+    fn dedup_sorted_properties() {
+        let result = check(500, |input| {
+            let output = dedup_sorted(input);
+            output.windows(2).all(|pair| pair[0] < pair[1])
+                && input.iter().all(|value| output.contains(value))
+        });
+        assert_eq!(result, Ok(()));
+    }
+
+    // Exercise 3
+    // A property that does not hold: `check` finds an input breaking it
+    #[test]
+    fn false_property_is_caught() {
+        let result = check(500, |input| input.iter().sum::<i32>() >= 0);
+        let counterexample = result.unwrap_err();
+        assert!(counterexample.iter().sum::<i32>() < 0);
+    }
+}
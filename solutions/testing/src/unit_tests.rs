@@ -0,0 +1,84 @@
+// The code under test is finished. The exercises are the tests at the bottom
+
+#[derive(Debug, PartialEq)]
+struct Temperature {
+    celsius: f64,
+}
+
+impl Temperature {
+    fn new(celsius: f64) -> Temperature {
+        if celsius < -273.15 {
+            panic!("below absolute zero: {celsius}");
+        }
+        Temperature { celsius }
+    }
+
+    fn fahrenheit(&self) -> f64 {
+        self.celsius * 9.0 / 5.0 + 32.0
+    }
+
+    fn parse(text: &str) -> Result<Temperature, String> {
+        let number = text
+            .strip_suffix("C")
+            .ok_or_else(|| format!("`{text}` does not end in C"))?;
+        let celsius: f64 = number
+            .trim()
+            .parse()
+            .map_err(|_| format!("`{number}` is not a number"))?;
+        Ok(Temperature::new(celsius))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exercise 1
+    // A fixture builds the data several tests share
+    fn readings() -> Vec<Temperature> {
+        ["0C", "100C", "-40C"]
+            .iter()
+            .map(|text| Temperature::parse(text).unwrap())
+            .collect()
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn converts_to_fahrenheit() {
+        let fahrenheit: Vec<f64> = readings().iter().map(Temperature::fahrenheit).collect();
+        assert_eq!(fahrenheit, vec![32.0, 212.0, -40.0]);
+    }
+
+    // Exercise 2
+    // Test that too cold temperatures panic, and check the panic message
+    #[test]
+    #[should_panic(expected = "below absolute zero")]
+    fn absolute_zero_is_the_limit() {
+        Temperature::new(-300.0);
+    }
+
+    // Exercise 3
+    // Tests can return a `Result` and use `?`: an `Err` fails the test
+    #[test]
+    fn parse_with_question_mark() -> Result<(), String> {
+        let warm = Temperature::parse("25.5C")?;
+        assert_eq!(warm.celsius, 25.5);
+        Ok(())
+    }
+
+    // Exercise 4
+    // Test the error messages of `parse`, one case per input
+    #[test]
+    fn parse_errors() {
+        for (input, message) in [
+            ("25", "`25` does not end in C"),
+            ("warmC", "`warm` is not a number"),
+        ] {
+            assert_eq!(
+                Temperature::parse(input),
+                Err(message.to_string()),
+                "{input}"
+            );
+        }
+    }
+}
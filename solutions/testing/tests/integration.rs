@@ -0,0 +1,40 @@
+// Integration tests only see what the `testing` crate makes public, like
+// any other user of the crate would
+use testing::stack::Stack;
+
+// Exercise 1
+// Write a test that fills a stack to capacity, checks that one more push is
+// refused, then empties it again in the reverse order
+#[test]
+fn fill_and_empty() {
+    let mut stack = Stack::with_capacity(3);
+    for i in 1..=3 {
+        assert_eq!(stack.push(i), Ok(()));
+    }
+    assert_eq!(stack.push(4), Err(4));
+    assert_eq!(stack.len(), 3);
+    for i in (1..=3).rev() {
+        assert_eq!(stack.pop(), Some(i));
+    }
+    assert!(stack.is_empty());
+}
+
+// Exercise 2
+// Write a test showing that `peek` leaves the stack unchanged
+#[test]
+fn peek_does_not_pop() {
+    let mut stack = Stack::with_capacity(2);
+    stack.push("only").unwrap();
+    assert_eq!(stack.peek(), Some(&"only"));
+    assert_eq!(stack.peek(), Some(&"only"));
+    assert_eq!(stack.len(), 1);
+}
+
+// Exercise 3
+// A stack of capacity zero can never hold anything
+#[test]
+fn zero_capacity() {
+    let mut stack = Stack::with_capacity(0);
+    assert_eq!(stack.push('x'), Err('x'));
+    assert_eq!(stack.pop(), None);
+}
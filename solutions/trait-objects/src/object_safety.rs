@@ -0,0 +1,101 @@
+// Exercise 1
+// Make me compile!
+// A trait can only become `dyn Trait` if every method can be called without
+// knowing the concrete type. `duplicate` returns `Self`, whose size is not
+// known behind a `dyn`: keep it off trait objects with `where Self: Sized`
+trait Shape {
+    fn area(&self) -> f64;
+
+    fn duplicate(&self) -> Self
+    where
+        Self: Sized;
+}
+
+#[derive(Debug, PartialEq)]
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn duplicate(&self) -> Self {
+        Square(self.0)
+    }
+}
+
+// Exercise 2
+// Generic methods can not be called through a `dyn` either: there would be
+// one version per type argument. Take a trait object argument instead
+trait Logger {
+    fn log(&mut self, message: &dyn std::fmt::Display);
+}
+
+#[derive(Default)]
+struct Memory {
+    lines: Vec<String>,
+}
+
+impl Logger for Memory {
+    fn log(&mut self, message: &dyn std::fmt::Display) {
+        self.lines.push(message.to_string());
+    }
+}
+
+// Exercise 3
+// Cloning a `Box<dyn Shape>` needs a helper method returning a box, since
+// `Clone` itself returns `Self`
+trait CloneShape: Shape {
+    fn clone_box(&self) -> Box<dyn CloneShape>;
+}
+
+impl<T: Shape + Clone + 'static> CloneShape for T {
+    fn clone_box(&self) -> Box<dyn CloneShape> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Clone)]
+struct Circle(f64);
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        3.0 * self.0 * self.0
+    }
+
+    fn duplicate(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn shape_objects() {
+        let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Square(2.0)), Box::new(Circle(1.0))];
+        let total: f64 = shapes.iter().map(|shape| shape.area()).sum();
+        assert_eq!(total, 7.0);
+        assert_eq!(Square(3.0).duplicate(), Square(3.0));
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn log_anything() {
+        let mut memory = Memory::default();
+        let logger: &mut dyn Logger = &mut memory;
+        logger.log(&42);
+        logger.log(&"started");
+        assert_eq!(memory.lines, vec!["42", "started"]);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn clone_a_boxed_shape() {
+        let original: Box<dyn CloneShape> = Box::new(Circle(2.0));
+        let copy = original.clone_box();
+        assert_eq!(copy.area(), original.area());
+    }
+}
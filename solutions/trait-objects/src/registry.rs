@@ -0,0 +1,120 @@
+use state_machine::garage_door::{DoorEvent, DoorState, GarageDoor};
+use state_machine::StateMachine;
+
+// Exercise 1
+// `StateMachine` can not be a trait object: `next_state` has no `self` and
+// every machine has its own `State` and `Transition` types. Wrap a machine
+// and its current state in `Running<M>`, and implement the object-safe
+// `AnyMachine` for it so machines of any kind fit in one `Vec`
+trait AnyMachine {
+    fn name(&self) -> &str;
+    /// Parse `input` into a transition and apply it
+    fn step(&mut self, input: &str) -> Result<(), String>;
+    fn state(&self) -> String;
+}
+
+struct Running<M: StateMachine> {
+    name: String,
+    state: M::State,
+    parse: fn(&str) -> Option<M::Transition>,
+}
+
+impl<M: StateMachine> AnyMachine for Running<M>
+where
+    M::State: std::fmt::Debug,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn step(&mut self, input: &str) -> Result<(), String> {
+        let transition =
+            (self.parse)(input).ok_or_else(|| format!("{}: unknown input `{input}`", self.name))?;
+        self.state = M::next_state(&self.state, &transition);
+        Ok(())
+    }
+
+    fn state(&self) -> String {
+        format!("{:?}", self.state)
+    }
+}
+
+// A second machine with different state and transition types
+struct Counter;
+
+impl StateMachine for Counter {
+    type State = u32;
+    type Transition = u32;
+
+    fn next_state(starting_state: &u32, t: &u32) -> u32 {
+        starting_state + t
+    }
+}
+
+// Exercise 2
+// Output: a registry holding a garage door and a counter
+fn registry() -> Vec<Box<dyn AnyMachine>> {
+    vec![
+        Box::new(Running::<GarageDoor> {
+            name: "door".to_string(),
+            state: DoorState::Closed,
+            parse: |input| match input {
+                "button" => Some(DoorEvent::Button),
+                "top" => Some(DoorEvent::ReachedTop),
+                "bottom" => Some(DoorEvent::ReachedBottom),
+                _ => None,
+            },
+        }),
+        Box::new(Running::<Counter> {
+            name: "counter".to_string(),
+            state: 0,
+            parse: |input| input.parse().ok(),
+        }),
+    ]
+}
+
+// Exercise 3
+// Send `input` to the machine called `name`
+fn send(machines: &mut [Box<dyn AnyMachine>], name: &str, input: &str) -> Result<(), String> {
+    machines
+        .iter_mut()
+        .find(|machine| machine.name() == name)
+        .ok_or_else(|| format!("no machine called `{name}`"))?
+        .step(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 2
+    #[test]
+    fn machines_of_every_kind() {
+        let machines = registry();
+        let states: Vec<String> = machines.iter().map(|m| m.state()).collect();
+        assert_eq!(states, vec!["Closed", "0"]);
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn drive_machines_by_name() {
+        let mut machines = registry();
+        send(&mut machines, "door", "button").unwrap();
+        send(&mut machines, "door", "top").unwrap();
+        send(&mut machines, "counter", "5").unwrap();
+        send(&mut machines, "counter", "7").unwrap();
+        assert_eq!(machines[0].state(), "Open");
+        assert_eq!(machines[1].state(), "12");
+    }
+
+    // Test for exercise 1
+    #[test]
+    fn bad_input_is_an_error() {
+        let mut machines = registry();
+        assert_eq!(
+            send(&mut machines, "counter", "lots"),
+            Err("counter: unknown input `lots`".to_string())
+        );
+        assert!(send(&mut machines, "toaster", "on").is_err());
+    }
+}
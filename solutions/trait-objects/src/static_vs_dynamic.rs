@@ -0,0 +1,89 @@
+trait Scorer {
+    fn score(&self, value: u32) -> u32;
+}
+
+struct Double;
+struct AddTen;
+
+impl Scorer for Double {
+    fn score(&self, value: u32) -> u32 {
+        value * 2
+    }
+}
+
+impl Scorer for AddTen {
+    fn score(&self, value: u32) -> u32 {
+        value + 10
+    }
+}
+
+// Exercise 1
+// Write the same function twice: once generic (static dispatch, one copy of
+// the function compiled per scorer type, calls can be inlined) and once
+// with a trait object (dynamic dispatch, one copy, calls go through a vtable)
+fn total_static<S: Scorer>(scorer: &S, values: &[u32]) -> u32 {
+    values.iter().map(|v| scorer.score(*v)).sum()
+}
+
+fn total_dynamic(scorer: &dyn Scorer, values: &[u32]) -> u32 {
+    values.iter().map(|v| scorer.score(*v)).sum()
+}
+
+// Exercise 2
+// A trait object reference is a "fat" pointer: the data pointer plus a
+// pointer to the vtable. Fill in how many `usize`s each reference takes
+const PLAIN_REFERENCE_WORDS: usize = 1;
+const TRAIT_OBJECT_WORDS: usize = 2;
+
+// Exercise 3
+// Sometimes only a trait object works: choose the scorer at runtime.
+// A generic function can not return different types from its branches
+fn pick(name: &str) -> Option<Box<dyn Scorer>> {
+    match name {
+        "double" => Some(Box::new(Double)),
+        "add-ten" => Some(Box::new(AddTen)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn same_results() {
+        let values = [1, 2, 3];
+        assert_eq!(total_static(&Double, &values), 12);
+        assert_eq!(total_dynamic(&Double, &values), 12);
+        assert_eq!(
+            total_static(&AddTen, &values),
+            total_dynamic(&AddTen, &values)
+        );
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn fat_pointers() {
+        assert_eq!(
+            size_of::<&Double>(),
+            PLAIN_REFERENCE_WORDS * size_of::<usize>()
+        );
+        assert_eq!(
+            size_of::<&dyn Scorer>(),
+            TRAIT_OBJECT_WORDS * size_of::<usize>()
+        );
+        assert_eq!(
+            size_of::<Box<dyn Scorer>>(),
+            TRAIT_OBJECT_WORDS * size_of::<usize>()
+        );
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn pick_at_runtime() {
+        assert_eq!(pick("double").unwrap().score(4), 8);
+        assert_eq!(pick("add-ten").unwrap().score(4), 14);
+        assert!(pick("triple").is_none());
+    }
+}
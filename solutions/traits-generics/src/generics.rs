@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+// Exercise 1
+// Make me generic!
+// Return the largest item of a non-empty slice of anything that can be compared
+fn largest<T: PartialOrd + Copy>(items: &[T]) -> T {
+    let mut largest = items[0];
+    for &item in items {
+        if item > largest {
+            largest = item;
+        }
+    }
+    largest
+}
+
+// Exercise 2
+// A `Pair` holds two values of the same type
+struct Pair<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Pair<T> {
+    fn new(x: T, y: T) -> Self {
+        Pair { x, y }
+    }
+
+    fn swap(self) -> Pair<T> {
+        Pair {
+            x: self.y,
+            y: self.x,
+        }
+    }
+}
+
+// Exercise 3
+// Only pairs of values that can be compared and printed get `winner`,
+// returning "<bigger value> wins"
+impl<T: PartialOrd + Display> Pair<T> {
+    fn winner(&self) -> String {
+        if self.x >= self.y {
+            format!("{} wins", self.x)
+        } else {
+            format!("{} wins", self.y)
+        }
+    }
+}
+
+// Exercise 4
+// Rewrite the bounds of this function with a `where` clause
+// Output: every item printed and joined with `separator`, then the count
+fn summarize<T, S>(items: &[T], separator: S) -> String
+where
+    T: Display,
+    S: AsRef<str>,
+{
+    let parts: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    format!("{} ({} items)", parts.join(separator.as_ref()), items.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn largest_of_anything() {
+        assert_eq!(largest(&[3, 7, 2]), 7);
+        assert_eq!(largest(&[1.5, -2.0]), 1.5);
+        assert_eq!(largest(&['r', 'u', 's', 't']), 'u');
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn pair_swaps() {
+        let pair = Pair::new("left", "right").swap();
+        assert_eq!((pair.x, pair.y), ("right", "left"));
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn pair_winner() {
+        assert_eq!(Pair::new(3, 9).winner(), "9 wins");
+        assert_eq!(Pair::new("b", "a").winner(), "b wins");
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn summarize_with_where_clause() {
+        assert_eq!(summarize(&[1, 2, 3], ", "), "1, 2, 3 (3 items)");
+        assert_eq!(summarize(&["a"], String::from("-")), "a (1 items)");
+    }
+}
@@ -0,0 +1,79 @@
+// Exercise 1
+// Define the `Shape` trait with an `area` method returning an f64,
+// then implement it for `Square` and `Circle`
+trait Shape {
+    fn area(&self) -> f64;
+
+    // Exercise 2
+    // Add a default `describe` method returning "shape with area <area>",
+    // with the area rounded to two decimals
+    fn describe(&self) -> String {
+        format!("shape with area {:.2}", self.area())
+    }
+}
+
+struct Square {
+    side: f64,
+}
+
+struct Circle {
+    radius: f64,
+}
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    // Exercise 3
+    // Override `describe` for squares only: "square of side <side>"
+    fn describe(&self) -> String {
+        format!("square of side {}", self.side)
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+}
+
+// Exercise 4
+// Input: a list of shapes of any kind
+// Output: the sum of their areas
+fn total_area(shapes: &[&dyn Shape]) -> f64 {
+    shapes.iter().map(|shape| shape.area()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test for exercise 1
+    #[test]
+    fn areas() {
+        assert_eq!(Square { side: 3.0 }.area(), 9.0);
+        assert!((Circle { radius: 1.0 }.area() - std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    // Test for exercise 2
+    #[test]
+    fn default_description() {
+        assert_eq!(Circle { radius: 1.0 }.describe(), "shape with area 3.14");
+    }
+
+    // Test for exercise 3
+    #[test]
+    fn overridden_description() {
+        assert_eq!(Square { side: 2.0 }.describe(), "square of side 2");
+    }
+
+    // Test for exercise 4
+    #[test]
+    fn total_area_of_mixed_shapes() {
+        let square = Square { side: 2.0 };
+        let circle = Circle { radius: 0.0 };
+        assert_eq!(total_area(&[&square, &circle, &square]), 8.0);
+        assert_eq!(total_area(&[]), 0.0);
+    }
+}
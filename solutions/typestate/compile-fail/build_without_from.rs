@@ -0,0 +1,7 @@
+// `build` must not exist before `from` is set
+#[path = "../src/builder.rs"]
+mod builder;
+
+pub fn send() {
+    builder::EmailBuilder::new().to("bob@example.com").build();
+}
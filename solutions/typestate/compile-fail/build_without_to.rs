@@ -0,0 +1,7 @@
+// `build` must not exist before `to` is set
+#[path = "../src/builder.rs"]
+mod builder;
+
+pub fn send() {
+    builder::EmailBuilder::new().from("alice@example.com").build();
+}
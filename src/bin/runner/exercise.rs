@@ -0,0 +1,438 @@
+use crate::i18n::Lang;
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// How an exercise is checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// It only has to compile
+    Compile,
+    /// It has to compile and its tests have to pass
+    Test,
+    /// It has to compile without any clippy warning
+    Clippy,
+    /// It must not compile, failing with the errors listed in the `.stderr` file
+    /// next to it
+    CompileFail,
+}
+
+impl Mode {
+    pub fn parse(mode: &str) -> Option<Mode> {
+        match mode {
+            "compile" => Some(Mode::Compile),
+            "test" => Some(Mode::Test),
+            "clippy" => Some(Mode::Clippy),
+            "compile_fail" => Some(Mode::CompileFail),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Mode::Compile => "compile",
+            Mode::Test => "test",
+            Mode::Clippy => "clippy",
+            Mode::CompileFail => "compile_fail",
+        }
+    }
+}
+
+/// A style check that an exercise has to pass on top of its mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gate {
+    /// No clippy warning, apart from the ones in [`CLIPPY_ALLOWED`]
+    Clippy,
+    /// `rustfmt --check` finds nothing to change
+    Rustfmt,
+}
+
+impl Gate {
+    pub fn parse(gate: &str) -> Option<Gate> {
+        match gate {
+            "clippy" => Some(Gate::Clippy),
+            "rustfmt" => Some(Gate::Rustfmt),
+            _ => None,
+        }
+    }
+}
+
+/// Lints the clippy gate lets through: they flag the scaffolding of the
+/// exercises, such as helpers only some exercises use, not the learner's style
+pub const CLIPPY_ALLOWED: &[&str] = &[
+    "dead_code",
+    "clippy::new_without_default",
+    "clippy::len_without_is_empty",
+];
+
+/// One exercise of the course, described by its `info.toml` entry and verified
+/// through the `cargo test --test <name>` target of the same name. `compile_fail`
+/// exercises have no target, they are handed to `rustc` on their own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exercise {
+    pub name: String,
+    pub path: String,
+    pub topic: String,
+    pub difficulty: u8,
+    pub mode: Mode,
+    /// Shown one at a time by `runner hint`, from a nudge to nearly the answer
+    pub hints: Vec<String>,
+    /// The same hints in Vietnamese, empty when they are not translated yet
+    pub hints_vi: Vec<String>,
+    /// Points the exercise is worth in `runner grade`
+    pub weight: u32,
+    /// Style checks run once the exercise passes its mode
+    pub gates: Vec<Gate>,
+    /// Names of the exercises to finish first
+    pub requires: Vec<String>,
+    /// Files that use the exercise in ways that must not compile, each with
+    /// the expected errors in a `.stderr` file next to it
+    pub rejects: Vec<String>,
+}
+
+/// How far an exercise got when it was run
+pub enum Outcome {
+    CompileError(Output),
+    TestFailure(Output),
+    /// A `compile_fail` exercise built without errors
+    Compiled,
+    /// A `compile_fail` exercise failed, but without these expected error lines
+    WrongErrors(Output, Vec<String>),
+    /// The exercise works, but a file in its `rejects` compiled
+    Accepted(String),
+    /// A file in `rejects` failed, but without these expected error lines
+    WrongRejection(String, Output, Vec<String>),
+    /// It works, but clippy still has warnings for it
+    Lints(Output),
+    /// It works, but it is not formatted the way `rustfmt` would
+    Unformatted(Output),
+    Passed,
+}
+
+impl Exercise {
+    /// The hints in `lang`, falling back to English when they are not translated
+    pub fn hints_in(&self, lang: Lang) -> &[String] {
+        match lang {
+            Lang::Vi if !self.hints_vi.is_empty() => &self.hints_vi,
+            _ => &self.hints,
+        }
+    }
+
+    /// An exercise `name` of `topic` for tests, with one hint and no extras
+    #[cfg(test)]
+    pub fn fixture(name: &str, topic: &str) -> Exercise {
+        Exercise {
+            name: name.to_string(),
+            path: format!("exercises/{topic}/src/{name}.rs"),
+            topic: topic.to_string(),
+            difficulty: 1,
+            mode: Mode::Test,
+            hints: vec!["hint".to_string()],
+            hints_vi: Vec::new(),
+            weight: 1,
+            gates: Vec::new(),
+            requires: Vec::new(),
+            rejects: Vec::new(),
+        }
+    }
+
+    /// Check the exercise according to its mode from the workspace root `root`
+    pub fn run(&self, root: &Path) -> std::io::Result<Outcome> {
+        self.check(root, &self.path, &self.name, &self.rejects, &[])
+    }
+
+    /// Check the reference solution instead of the learner's file. The
+    /// `rejects` files have copies next to it that use the solution
+    pub fn run_solution(&self, root: &Path) -> std::io::Result<Outcome> {
+        let target = format!("solution_{}", self.name);
+        let rejects: Vec<String> = self.rejects.iter().map(|f| solution_path(f)).collect();
+        self.check(
+            root,
+            &self.solution_path(),
+            &target,
+            &rejects,
+            &["--features", "solutions"],
+        )
+    }
+
+    /// Where the reference solution lives: the same path, under `solutions/`
+    /// instead of `exercises/`
+    pub fn solution_path(&self) -> String {
+        solution_path(&self.path)
+    }
+
+    // Check `file` through the cargo test `target` and the files that must not
+    // compile against it, passing `features` to cargo
+    fn check(
+        &self,
+        root: &Path,
+        file: &str,
+        target: &str,
+        rejects: &[String],
+        features: &[&str],
+    ) -> std::io::Result<Outcome> {
+        let cargo =
+            |command: &str, args: &[&str]| cargo(root, &[&[command], features, args].concat());
+        let compiled = match self.mode {
+            Mode::CompileFail => return self.run_compile_fail(root, file),
+            Mode::Clippy => cargo("clippy", &["--test", target, "--", "-D", "warnings"])?,
+            Mode::Compile | Mode::Test => cargo("test", &["--test", target, "--no-run"])?,
+        };
+        if !compiled.status.success() {
+            return Ok(Outcome::CompileError(compiled));
+        }
+        if self.mode == Mode::Test {
+            let tested = cargo("test", &["--test", target])?;
+            if !tested.status.success() {
+                return Ok(Outcome::TestFailure(tested));
+            }
+        }
+        // The copies for a solution are held to the snapshots of the originals
+        for (reject, original) in rejects.iter().zip(&self.rejects) {
+            match compile_fail(root, reject, &snapshot_path(original))? {
+                Rejection::Expected => {}
+                Rejection::Compiled => return Ok(Outcome::Accepted(reject.clone())),
+                Rejection::Unexpected(output, missing) => {
+                    return Ok(Outcome::WrongRejection(reject.clone(), output, missing))
+                }
+            }
+        }
+        for gate in &self.gates {
+            match gate {
+                Gate::Clippy => {
+                    let allowed = CLIPPY_ALLOWED.iter().flat_map(|lint| ["-A", lint]);
+                    let args: Vec<&str> = ["--test", target, "--", "-D", "warnings"]
+                        .into_iter()
+                        .chain(allowed)
+                        .collect();
+                    let linted = cargo("clippy", &args)?;
+                    if !linted.status.success() {
+                        return Ok(Outcome::Lints(linted));
+                    }
+                }
+                Gate::Rustfmt => {
+                    let formatted = rustfmt(root, file)?;
+                    if !formatted.status.success() {
+                        return Ok(Outcome::Unformatted(formatted));
+                    }
+                }
+            }
+        }
+        Ok(Outcome::Passed)
+    }
+
+    /// The expected errors of a `compile_fail` exercise, one per line
+    pub fn snapshot_path(&self) -> String {
+        snapshot_path(&self.path)
+    }
+
+    fn run_compile_fail(&self, root: &Path, file: &str) -> std::io::Result<Outcome> {
+        // A solution is held to the snapshot of its exercise
+        Ok(match compile_fail(root, file, &self.snapshot_path())? {
+            Rejection::Expected => Outcome::Passed,
+            Rejection::Compiled => Outcome::Compiled,
+            Rejection::Unexpected(output, missing) => Outcome::WrongErrors(output, missing),
+        })
+    }
+
+    /// Put the exercise file back the way it is in the last commit, throwing away
+    /// the learner's changes
+    pub fn restore(&self, root: &Path) -> Result<(), String> {
+        git(root, &["checkout", "HEAD", "--", &self.path])
+    }
+}
+
+// How a file that must not compile did
+enum Rejection {
+    /// With every error of its snapshot
+    Expected,
+    Compiled,
+    /// With errors, but not these lines of its snapshot
+    Unexpected(Output, Vec<String>),
+}
+
+fn solution_path(file: &str) -> String {
+    let relative = file.strip_prefix("exercises/").unwrap_or(file);
+    format!("solutions/{relative}")
+}
+
+fn snapshot_path(file: &str) -> String {
+    Path::new(file)
+        .with_extension("stderr")
+        .to_string_lossy()
+        .into_owned()
+}
+
+// Hand `file` to `rustc` on its own and compare the errors with `snapshot`
+fn compile_fail(root: &Path, file: &str, snapshot: &str) -> std::io::Result<Rejection> {
+    let expected = std::fs::read_to_string(root.join(snapshot))?;
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let compiled = Command::new(rustc)
+        .args([
+            "--edition",
+            "2021",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .args(["--color", "never", "--out-dir"])
+        .arg(root.join("target").join("compile_fail"))
+        .arg(file)
+        .current_dir(root)
+        .output()?;
+    if compiled.status.success() {
+        return Ok(Rejection::Compiled);
+    }
+    let missing = missing_lines(&expected, &String::from_utf8_lossy(&compiled.stderr));
+    if missing.is_empty() {
+        Ok(Rejection::Expected)
+    } else {
+        Ok(Rejection::Unexpected(compiled, missing))
+    }
+}
+
+// The lines of the snapshot `expected` that do not appear in the compiler's
+// `stderr`. Only those lines have to match, so the snapshot can leave out the
+// parts of the message that change between compiler versions
+fn missing_lines(expected: &str, stderr: &str) -> Vec<String> {
+    let actual: Vec<&str> = stderr.lines().map(str::trim).collect();
+    expected
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !actual.contains(line))
+        .map(String::from)
+        .collect()
+}
+
+fn git(root: &Path, args: &[&str]) -> Result<(), String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .map_err(|err| format!("could not run git: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+fn rustfmt(root: &Path, file: &str) -> std::io::Result<Output> {
+    let rustfmt = std::env::var("RUSTFMT").unwrap_or_else(|_| "rustfmt".to_string());
+    Command::new(rustfmt)
+        .args(["--check", "--edition", "2021", file])
+        .current_dir(root)
+        .output()
+}
+
+pub fn cargo(root: &Path, args: &[&str]) -> std::io::Result<Output> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    // Before the subcommand, so it never ends up after a `--` meant for clippy
+    Command::new(cargo)
+        .arg("--quiet")
+        .args(args)
+        .current_dir(root)
+        .output()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::Manifest;
+
+    #[test]
+    fn snapshot_lines_must_all_appear() {
+        let stderr = "error[E0382]: borrow of moved value: `name`\n  --> a.rs:3:5\n";
+        assert!(
+            missing_lines("error[E0382]: borrow of moved value: `name`\n\n", stderr).is_empty()
+        );
+        assert_eq!(
+            missing_lines(
+                "error[E0382]: borrow of moved value: `name`\nerror[E0499]: x\n",
+                stderr
+            ),
+            vec!["error[E0499]: x".to_string()]
+        );
+    }
+
+    #[test]
+    fn every_exercise_has_a_solution() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = Manifest::load(root).unwrap();
+        for exercise in &manifest.exercises {
+            assert!(
+                root.join(exercise.solution_path()).is_file(),
+                "no solution for `{}` at {}",
+                exercise.name,
+                exercise.solution_path()
+            );
+        }
+    }
+
+    #[test]
+    fn compile_fail_exercises_match_their_snapshots() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = Manifest::load(root).unwrap();
+        for exercise in &manifest.exercises {
+            if exercise.mode != Mode::CompileFail {
+                continue;
+            }
+            match exercise.run(root).unwrap() {
+                Outcome::Passed => {}
+                Outcome::Compiled => panic!("`{}` compiles", exercise.name),
+                Outcome::WrongErrors(_, missing) => {
+                    panic!("`{}` is missing errors {missing:?}", exercise.name)
+                }
+                _ => unreachable!("compile_fail exercises are not tested"),
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_uses_of_the_exercises_do_not_compile() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = Manifest::load(root).unwrap();
+        for original in manifest.exercises.iter().flat_map(|e| &e.rejects) {
+            // And the copy that uses the reference solution
+            for file in [original.clone(), solution_path(original)] {
+                match compile_fail(root, &file, &snapshot_path(original)).unwrap() {
+                    Rejection::Expected => {}
+                    Rejection::Compiled => panic!("`{file}` compiles"),
+                    Rejection::Unexpected(_, missing) => {
+                        panic!("`{file}` is missing errors {missing:?}")
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn a_builder_that_allows_any_order_is_caught() {
+        let root = std::env::temp_dir().join(format!("runner-rejects-{}", std::process::id()));
+        for dir in ["src", "compile-fail"] {
+            std::fs::create_dir_all(root.join("exercises/typestate").join(dir)).unwrap();
+        }
+        std::fs::write(
+            root.join("exercises/typestate/src/builder.rs"),
+            "pub struct EmailBuilder;\n\
+             impl EmailBuilder {\n\
+             pub fn new() -> Self { EmailBuilder }\n\
+             pub fn from(self, _: &str) -> Self { self }\n\
+             pub fn to(self, _: &str) -> Self { self }\n\
+             pub fn build(self) {}\n\
+             }\n",
+        )
+        .unwrap();
+        let file = "exercises/typestate/compile-fail/build_without_from.rs";
+        let here = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for copied in [file.to_string(), snapshot_path(file)] {
+            std::fs::copy(here.join(&copied), root.join(&copied)).unwrap();
+        }
+        let rejection = compile_fail(&root, file, &snapshot_path(file)).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(matches!(rejection, Rejection::Compiled));
+    }
+}
@@ -0,0 +1,208 @@
+//! `runner grade`: a weighted score over every exercise and the final project,
+//! written to `grade.json` for the instructors.
+
+use crate::exercise::{self, Exercise, Outcome};
+use crate::i18n::{self, tr_in, Lang};
+use crate::json::Json;
+use std::fmt;
+use std::path::Path;
+
+pub const REPORT_FILE: &str = "grade.json";
+
+/// The final project is graded as a whole, by its own test suite
+pub const FINAL_PROJECT: &str = "final-project";
+pub const FINAL_PROJECT_WEIGHT: u32 = 20;
+
+/// One graded item: an exercise or the final project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graded {
+    pub name: String,
+    pub topic: String,
+    pub weight: u32,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Seconds since the Unix epoch
+    pub graded_at: u64,
+    pub graded: Vec<Graded>,
+}
+
+/// Run every exercise and the final project's tests
+pub fn grade(exercises: &[Exercise], root: &Path, graded_at: u64) -> Result<Report, String> {
+    let mut graded = exercises
+        .iter()
+        .map(|exercise| grade_exercise(exercise, root))
+        .collect::<Result<Vec<_>, _>>()?;
+    let final_project = exercise::cargo(root, &["test", "-p", "state-machine"])
+        .map_err(|err| format!("could not run cargo: {err}"))?;
+    graded.push(Graded {
+        name: FINAL_PROJECT.to_string(),
+        topic: FINAL_PROJECT.to_string(),
+        weight: FINAL_PROJECT_WEIGHT,
+        passed: final_project.status.success(),
+    });
+    Ok(Report { graded_at, graded })
+}
+
+/// Run one exercise, without recording the attempt in the learner's progress
+pub fn grade_exercise(exercise: &Exercise, root: &Path) -> Result<Graded, String> {
+    let outcome = exercise
+        .run(root)
+        .map_err(|err| format!("could not run cargo: {err}"))?;
+    Ok(Graded {
+        name: exercise.name.clone(),
+        topic: exercise.topic.clone(),
+        weight: exercise.weight,
+        passed: matches!(outcome, Outcome::Passed),
+    })
+}
+
+impl Report {
+    pub fn earned(&self) -> u32 {
+        self.graded
+            .iter()
+            .filter(|item| item.passed)
+            .map(|item| item.weight)
+            .sum()
+    }
+
+    pub fn possible(&self) -> u32 {
+        self.graded.iter().map(|item| item.weight).sum()
+    }
+
+    /// The score out of 100, rounded to one decimal
+    pub fn percent(&self) -> f64 {
+        match self.possible() {
+            0 => 0.0,
+            possible => (f64::from(self.earned()) * 1000.0 / f64::from(possible)).round() / 10.0,
+        }
+    }
+
+    pub fn to_json(&self) -> Json {
+        let number = |n: u32| Json::Number(n.into());
+        Json::Object(vec![
+            ("graded_at".to_string(), Json::Number(self.graded_at as f64)),
+            ("earned".to_string(), number(self.earned())),
+            ("possible".to_string(), number(self.possible())),
+            ("percent".to_string(), Json::Number(self.percent())),
+            (
+                "items".to_string(),
+                Json::Array(
+                    self.graded
+                        .iter()
+                        .map(|item| {
+                            Json::Object(vec![
+                                ("name".to_string(), Json::String(item.name.clone())),
+                                ("topic".to_string(), Json::String(item.topic.clone())),
+                                ("weight".to_string(), number(item.weight)),
+                                ("passed".to_string(), Json::Bool(item.passed)),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    pub fn save(&self, root: &Path) -> Result<(), String> {
+        let path = root.join(REPORT_FILE);
+        std::fs::write(&path, self.to_json().pretty())
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+}
+
+impl Report {
+    /// The report as a table with the score line in `lang`
+    pub fn render_in(&self, lang: Lang) -> String {
+        let mut text = String::new();
+        for item in &self.graded {
+            let points = if item.passed { item.weight } else { 0 };
+            text.push_str(&format!(
+                "{} {:<20} {:<18} {points:>3}/{}\n",
+                if item.passed { "✅" } else { "❌" },
+                item.name,
+                item.topic,
+                item.weight
+            ));
+        }
+        let earned = self.earned().to_string();
+        let possible = self.possible().to_string();
+        let percent = self.percent().to_string();
+        let args = [
+            ("earned", &*earned),
+            ("possible", &possible),
+            ("percent", &percent),
+        ];
+        text.push_str(&tr_in(lang, "score", &args));
+        text
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_in(i18n::lang()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+
+    fn report() -> Report {
+        let item = |name: &str, weight, passed| Graded {
+            name: name.to_string(),
+            topic: "basics".to_string(),
+            weight,
+            passed,
+        };
+        Report {
+            graded_at: 1_700_000_000,
+            graded: vec![
+                item("conditions", 1, true),
+                item("strings", 2, false),
+                item(FINAL_PROJECT, 3, true),
+            ],
+        }
+    }
+
+    #[test]
+    fn weighted_score() {
+        let report = report();
+        assert_eq!(report.earned(), 4);
+        assert_eq!(report.possible(), 6);
+        assert_eq!(report.percent(), 66.7);
+        assert_eq!(
+            Report {
+                graded_at: 0,
+                graded: Vec::new()
+            }
+            .percent(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn json_report() {
+        let document = json::parse(&report().to_json().pretty()).unwrap();
+        assert_eq!(document.get("earned").and_then(Json::as_u64), Some(4));
+        assert_eq!(document.get("possible").and_then(Json::as_u64), Some(6));
+        assert_eq!(document.get("percent"), Some(&Json::Number(66.7)));
+        let Some(Json::Array(items)) = document.get("items") else {
+            panic!("items is an array");
+        };
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1].get("passed"), Some(&Json::Bool(false)));
+        assert_eq!(items[1].get("weight").and_then(Json::as_u64), Some(2));
+    }
+
+    #[test]
+    fn readable_report() {
+        let text = report().render_in(Lang::En);
+        assert!(text.contains("❌ strings"), "{text}");
+        assert!(text.contains("  0/2"), "{text}");
+        assert!(text.ends_with("Score: 4/6 (66.7%)"), "{text}");
+    }
+}
@@ -0,0 +1,265 @@
+//! Runner messages in English and Vietnamese. The language comes from the
+//! usual locale variables, so `LANG=vi_VN.UTF-8` switches to Vietnamese.
+
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    Vi,
+}
+
+impl Lang {
+    /// The first locale variable that is set decides, as for other programs
+    pub fn from_env() -> Lang {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .map_or(Lang::En, |value| Lang::parse(&value))
+    }
+
+    /// `vi`, `vi_VN` or `vi_VN.UTF-8` is Vietnamese, anything else English
+    pub fn parse(locale: &str) -> Lang {
+        let language = locale.split(['_', '.', '@', '-']).next().unwrap_or("");
+        if language.eq_ignore_ascii_case("vi") {
+            Lang::Vi
+        } else {
+            Lang::En
+        }
+    }
+}
+
+/// The language of this run
+pub fn lang() -> Lang {
+    static LANG: OnceLock<Lang> = OnceLock::new();
+    *LANG.get_or_init(Lang::from_env)
+}
+
+// Key, English, Vietnamese. `{name}`-style placeholders are filled in by `tr`
+const CATALOG: &[(&str, &str, &str)] = &[
+    (
+        "running",
+        "Running `{name}` ({path})",
+        "Đang chạy `{name}` ({path})",
+    ),
+    ("passed", "✅ `{name}` passed!", "✅ `{name}` đã hoàn thành!"),
+    (
+        "compile-error",
+        "❌ `{name}` does not compile yet.",
+        "❌ `{name}` chưa biên dịch được.",
+    ),
+    (
+        "test-failure",
+        "❌ `{name}` compiles but its tests fail.",
+        "❌ `{name}` biên dịch được nhưng các bài kiểm thử vẫn thất bại.",
+    ),
+    (
+        "compiled",
+        "❌ `{name}` still compiles, but it must not.",
+        "❌ `{name}` vẫn biên dịch được, nhưng lẽ ra không được.",
+    ),
+    (
+        "wrong-errors",
+        "❌ `{name}` does not compile, but not for the expected reason. Missing from the errors:",
+        "❌ `{name}` không biên dịch được, nhưng không phải vì lý do mong đợi. Các lỗi còn thiếu:",
+    ),
+    (
+        "accepted",
+        "❌ `{name}` passes its tests, but {path} still compiles against it, and it must not.",
+        "❌ `{name}` đã qua các bài kiểm thử, nhưng {path} vẫn biên dịch được với nó, trong khi lẽ ra không được.",
+    ),
+    (
+        "wrong-rejection",
+        "❌ `{name}` passes its tests and {path} does not compile against it, but not for the expected reason. Missing from the errors:",
+        "❌ `{name}` đã qua các bài kiểm thử và {path} không biên dịch được với nó, nhưng không phải vì lý do mong đợi. Các lỗi còn thiếu:",
+    ),
+    (
+        "lints",
+        "❌ `{name}` works, but clippy has suggestions. Fix the warnings above.",
+        "❌ `{name}` chạy đúng, nhưng clippy vẫn còn góp ý. Hãy sửa các cảnh báo ở trên.",
+    ),
+    (
+        "unformatted",
+        "❌ `{name}` works, but it is not formatted. Run `rustfmt --edition 2021 {path}`.",
+        "❌ `{name}` chạy đúng, nhưng chưa được định dạng. Hãy chạy `rustfmt --edition 2021 {path}`.",
+    ),
+    (
+        "stuck",
+        "Stuck? Try `runner hint {name}`",
+        "Bị kẹt? Hãy thử `runner hint {name}`",
+    ),
+    (
+        "hint",
+        "Hint {level}/{total}: {hint}",
+        "Gợi ý {level}/{total}: {hint}",
+    ),
+    (
+        "more-hints",
+        "Still stuck? Run `runner hint {name}` again.",
+        "Vẫn bị kẹt? Hãy chạy lại `runner hint {name}`.",
+    ),
+    (
+        "all-done",
+        "🎉 All {count} exercises are done!",
+        "🎉 Bạn đã hoàn thành cả {count} bài tập!",
+    ),
+    (
+        "waiting",
+        "Waiting for you to save {path} ...",
+        "Đang chờ bạn lưu {path} ...",
+    ),
+    (
+        "reset",
+        "`{name}` is back to its original state.",
+        "`{name}` đã trở về trạng thái ban đầu.",
+    ),
+    (
+        "unknown-exercise",
+        "no exercise named `{name}`, see `runner list` for the available ones",
+        "không có bài tập nào tên `{name}`, xem `runner list` để biết các bài tập hiện có",
+    ),
+    (
+        "locked",
+        "`{name}` requires {required} first, see `runner path`",
+        "`{name}` cần hoàn thành {required} trước, xem `runner path`",
+    ),
+    (
+        "hints-disabled",
+        "hints are disabled during a quiz, `runner quiz --finish` ends it",
+        "gợi ý bị tắt trong lúc làm bài kiểm tra, `runner quiz --finish` để kết thúc",
+    ),
+    ("column-name", "Name", "Tên"),
+    ("column-topic", "Topic", "Chủ đề"),
+    ("column-difficulty", "Difficulty", "Độ khó"),
+    ("column-mode", "Mode", "Chế độ"),
+    ("column-status", "Status", "Trạng thái"),
+    ("column-path", "Path", "Đường dẫn"),
+    ("column-done", "Done", "Xong"),
+    ("column-attempts", "Attempts (avg)", "Số lần (TB)"),
+    ("column-time", "Time to complete", "Thời gian hoàn thành"),
+    ("state-done", "done", "xong"),
+    ("state-pending", "pending", "chưa xong"),
+    ("state-ready", "ready", "sẵn sàng"),
+    ("state-locked", "locked", "bị khoá"),
+    ("total", "total", "tổng"),
+    ("slowest", "Slowest exercises:", "Các bài tập tốn thời gian nhất:"),
+    ("slow-exercise", "{attempts} attempts, {time}", "{attempts} lần thử, {time}"),
+    ("after", "after {required}", "sau {required}"),
+    ("solution-passed", "✅ {path}", "✅ {path}"),
+    ("solution-failed", "❌ {path}", "❌ {path}"),
+    ("all-solutions", "All {count} solutions pass.", "Cả {count} lời giải đều đạt."),
+    ("failing-solutions", "Failing solutions: {names}", "Các lời giải chưa đạt: {names}"),
+    ("grading", "Grading {count} exercises and the final project ...", "Đang chấm {count} bài tập và dự án cuối khoá ..."),
+    ("score", "Score: {earned}/{possible} ({percent}%)", "Điểm: {earned}/{possible} ({percent}%)"),
+    ("report-saved", "The report is saved in {path}.", "Báo cáo được lưu trong {path}."),
+    ("submit-opt-in", "submitting is opt-in: set {url} and {token} to the leaderboard's URL and your token", "việc nộp bài là tuỳ chọn: hãy đặt {url} và {token} thành URL của bảng xếp hạng và mã của bạn"),
+    ("sent", "✅ sent {path}", "✅ đã gửi {path}"),
+    ("offline", "📡 the leaderboard is out of reach: {message}", "📡 không kết nối được tới bảng xếp hạng: {message}"),
+    ("still-queued", "{count} report(s) wait in {path}, the next `runner submit` sends them.", "{count} báo cáo đang chờ trong {path}, lần `runner submit` tiếp theo sẽ gửi chúng."),
+    ("refused", "❌ the leaderboard refused {path}: {message}", "❌ bảng xếp hạng đã từ chối {path}: {message}"),
+    ("set-aside", "It is set aside in {path}.", "Báo cáo được để riêng trong {path}."),
+    ("quiz-running", "a quiz is already running, see `runner quiz` or end it with `runner quiz --finish`", "một bài kiểm tra đang diễn ra, xem `runner quiz` hoặc kết thúc bằng `runner quiz --finish`"),
+    ("nothing-to-quiz", "every exercise is done, there is nothing left to quiz on", "bạn đã hoàn thành mọi bài tập, không còn gì để kiểm tra"),
+    ("quiz-started", "Quiz started: {count} exercises in {minutes} minutes, hints are disabled. They are back to their original state, and one left unchanged does not count.", "Bài kiểm tra bắt đầu: {count} bài tập trong {minutes} phút, gợi ý bị tắt. Các bài đã trở về trạng thái ban đầu, bài nào không được sửa sẽ không được tính điểm."),
+    ("quiz-commands", "Check your time with `runner quiz`, hand in with `runner quiz --finish`.", "Xem thời gian bằng `runner quiz`, nộp bài bằng `runner quiz --finish`."),
+    ("no-quiz", "no quiz is running, start one with `runner quiz --minutes <n>`", "không có bài kiểm tra nào đang diễn ra, bắt đầu bằng `runner quiz --minutes <n>`"),
+    ("time-up", "⏰ Time is up!", "⏰ Hết giờ!"),
+    ("time-left", "{time} left.", "Còn {time}."),
+    ("checking", "Checking {count} exercises ...", "Đang kiểm tra {count} bài tập ..."),
+    ("quiz-time", "Time: {used} of {allowed}", "Thời gian: {used} trên {allowed}"),
+];
+
+/// The message `key` in `lang`, with each `{placeholder}` replaced by its value
+pub fn tr_in(lang: Lang, key: &str, args: &[(&str, &str)]) -> String {
+    let &(_, en, vi) = CATALOG
+        .iter()
+        .find(|(k, ..)| *k == key)
+        .unwrap_or_else(|| panic!("no message `{key}` in the catalog"));
+    let mut rest = match lang {
+        Lang::En => en,
+        Lang::Vi => vi,
+    };
+    // One pass over the template, so a value is never searched for placeholders
+    let mut text = String::new();
+    while let Some(open) = rest.find('{') {
+        text.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let filled = after.split_once('}').and_then(|(placeholder, tail)| {
+            let &(_, value) = args.iter().find(|(name, _)| *name == placeholder)?;
+            Some((value, tail))
+        });
+        match filled {
+            Some((value, tail)) => {
+                text.push_str(value);
+                rest = tail;
+            }
+            None => {
+                text.push('{');
+                rest = after;
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// The message `key` in the language of this run
+pub fn tr(key: &str, args: &[(&str, &str)]) -> String {
+    tr_in(lang(), key, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    fn placeholders(text: &str) -> BTreeSet<&str> {
+        text.split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}'))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn locales() {
+        assert_eq!(Lang::parse("vi_VN.UTF-8"), Lang::Vi);
+        assert_eq!(Lang::parse("vi"), Lang::Vi);
+        assert_eq!(Lang::parse("VI-vn"), Lang::Vi);
+        assert_eq!(Lang::parse("en_US.UTF-8"), Lang::En);
+        assert_eq!(Lang::parse("C"), Lang::En);
+        assert_eq!(Lang::parse("video"), Lang::En);
+    }
+
+    #[test]
+    fn translations_keep_the_placeholders() {
+        let mut keys = HashSet::new();
+        for (key, en, vi) in CATALOG {
+            assert!(keys.insert(key), "`{key}` is in the catalog twice");
+            assert_eq!(placeholders(en), placeholders(vi), "`{key}`");
+        }
+    }
+
+    #[test]
+    fn fills_in_placeholders() {
+        let args = [("name", "strings"), ("path", "src/strings.rs")];
+        assert_eq!(
+            tr_in(Lang::En, "running", &args),
+            "Running `strings` (src/strings.rs)"
+        );
+        assert_eq!(
+            tr_in(Lang::Vi, "running", &args),
+            "Đang chạy `strings` (src/strings.rs)"
+        );
+    }
+
+    #[test]
+    fn values_are_not_filled_in_again() {
+        let args = [("name", "{path}"), ("path", "src/{name}.rs")];
+        assert_eq!(
+            tr_in(Lang::En, "running", &args),
+            "Running `{path}` (src/{name}.rs)"
+        );
+    }
+}
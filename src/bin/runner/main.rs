@@ -0,0 +1,633 @@
+//! Course runner: goes through the exercises in order, like rustlings.
+//!
+//! ```text
+//! cargo run --bin runner -- list
+//! cargo run --bin runner -- run <name>
+//! cargo run --bin runner -- verify
+//! cargo run --bin runner -- verify --solutions
+//! cargo run --bin runner -- hint <name>
+//! cargo run --bin runner -- watch
+//! cargo run --bin runner -- status
+//! cargo run --bin runner -- stats
+//! cargo run --bin runner -- path
+//! cargo run --bin runner -- reset <name>
+//! cargo run --bin runner -- grade
+//! cargo run --bin runner -- submit
+//! cargo run --bin runner -- quiz --minutes 30
+//! cargo run --bin runner -- quiz [--finish]
+//! ```
+
+mod exercise;
+mod grade;
+mod i18n;
+mod json;
+mod manifest;
+mod progress;
+mod quiz;
+mod stats;
+mod submit;
+mod toml;
+mod watch;
+
+use exercise::{Exercise, Outcome};
+use i18n::tr;
+use manifest::Manifest;
+use progress::Progress;
+use quiz::Quiz;
+use std::path::Path;
+use std::process::ExitCode;
+use watch::FileWatcher;
+
+const USAGE: &str = concat!(
+    "usage: runner <list | run <name> | verify [--solutions] | hint <name> | watch | status | stats | path | ",
+    "reset <name> | grade | submit | quiz [--minutes <n> | --finish]>"
+);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    List,
+    Run(String),
+    Verify,
+    /// Check the reference solutions in `solutions/` instead of the exercises
+    VerifySolutions,
+    Hint(String),
+    Watch,
+    Status,
+    Stats,
+    /// The exercises in an order that respects their `requires`
+    Path,
+    Reset(String),
+    Grade,
+    /// Send the grade report to the leaderboard, if the learner opted in
+    Submit,
+    /// Start a timed quiz lasting this many minutes
+    QuizStart(u64),
+    /// Show the quiz in progress, ending it once the time is up
+    QuizStatus,
+    QuizFinish,
+}
+
+fn parse(args: &[String]) -> Result<Command, String> {
+    match args {
+        [cmd] if cmd == "list" => Ok(Command::List),
+        [cmd] if cmd == "verify" => Ok(Command::Verify),
+        [cmd, flag] if cmd == "verify" && flag == "--solutions" => Ok(Command::VerifySolutions),
+        [cmd] if cmd == "watch" => Ok(Command::Watch),
+        [cmd] if cmd == "status" => Ok(Command::Status),
+        [cmd] if cmd == "stats" => Ok(Command::Stats),
+        [cmd] if cmd == "path" => Ok(Command::Path),
+        [cmd] if cmd == "grade" => Ok(Command::Grade),
+        [cmd] if cmd == "submit" => Ok(Command::Submit),
+        [cmd] if cmd == "quiz" => Ok(Command::QuizStatus),
+        [cmd, flag] if cmd == "quiz" && flag == "--finish" => Ok(Command::QuizFinish),
+        [cmd, flag, minutes] if cmd == "quiz" && flag == "--minutes" => minutes
+            .parse()
+            .ok()
+            .filter(|&minutes| minutes > 0)
+            .map(Command::QuizStart)
+            .ok_or_else(|| USAGE.to_string()),
+        [cmd, name] if cmd == "reset" => Ok(Command::Reset(name.clone())),
+        [cmd, name] if cmd == "run" => Ok(Command::Run(name.clone())),
+        [cmd, name] if cmd == "hint" => Ok(Command::Hint(name.clone())),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn lookup<'a>(manifest: &'a Manifest, name: &str) -> Result<&'a Exercise, String> {
+    manifest
+        .find(name)
+        .ok_or_else(|| tr("unknown-exercise", &[("name", name)]))
+}
+
+// Runs one exercise, records the attempt and reports what happened,
+// returning whether it passed
+fn run_one(exercise: &Exercise, root: &Path, progress: &mut Progress) -> Result<bool, String> {
+    let name = [("name", exercise.name.as_str())];
+    let name_and_path = [("name", exercise.name.as_str()), ("path", &exercise.path)];
+    println!("{}", tr("running", &name_and_path));
+    let outcome = exercise
+        .run(root)
+        .map_err(|err| format!("could not run cargo: {err}"))?;
+    progress.record_attempt(
+        &exercise.name,
+        matches!(outcome, Outcome::Passed),
+        progress::now(),
+    );
+    progress.save(root)?;
+
+    match outcome {
+        Outcome::Passed => {
+            println!("{}", tr("passed", &name));
+            Ok(true)
+        }
+        Outcome::CompileError(output) => {
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("{}", tr("compile-error", &name));
+            println!("{}", tr("stuck", &name));
+            Ok(false)
+        }
+        Outcome::TestFailure(output) => {
+            println!("{}", String::from_utf8_lossy(&output.stdout));
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("{}", tr("test-failure", &name));
+            println!("{}", tr("stuck", &name));
+            Ok(false)
+        }
+        Outcome::Compiled => {
+            println!("{}", tr("compiled", &name));
+            println!("{}", tr("stuck", &name));
+            Ok(false)
+        }
+        Outcome::WrongErrors(output, missing) => {
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("{}", tr("wrong-errors", &name));
+            for line in missing {
+                println!("    {line}");
+            }
+            println!("{}", tr("stuck", &name));
+            Ok(false)
+        }
+        Outcome::Accepted(file) => {
+            println!(
+                "{}",
+                tr("accepted", &[("name", &exercise.name), ("path", &file)])
+            );
+            println!("{}", tr("stuck", &name));
+            Ok(false)
+        }
+        Outcome::WrongRejection(file, output, missing) => {
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            println!(
+                "{}",
+                tr(
+                    "wrong-rejection",
+                    &[("name", &exercise.name), ("path", &file)]
+                )
+            );
+            for line in missing {
+                println!("    {line}");
+            }
+            println!("{}", tr("stuck", &name));
+            Ok(false)
+        }
+        Outcome::Lints(output) => {
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("{}", tr("lints", &name));
+            Ok(false)
+        }
+        Outcome::Unformatted(output) => {
+            println!("{}", String::from_utf8_lossy(&output.stdout));
+            println!("{}", tr("unformatted", &name_and_path));
+            Ok(false)
+        }
+    }
+}
+
+fn execute(command: Command, root: &Path) -> Result<bool, String> {
+    let manifest = Manifest::load(root)?;
+    let exercises = &manifest.exercises;
+    let mut progress = Progress::load(root)?;
+
+    match command {
+        Command::List => {
+            println!(
+                "{:<12} {:<14} {:<10} {:<12} {:<10} {}",
+                tr("column-name", &[]),
+                tr("column-topic", &[]),
+                tr("column-difficulty", &[]),
+                tr("column-mode", &[]),
+                tr("column-status", &[]),
+                tr("column-path", &[])
+            );
+            for exercise in exercises {
+                println!(
+                    "{:<12} {:<14} {:<10} {:<12} {:<10} {}",
+                    exercise.name,
+                    exercise.topic,
+                    "★".repeat(exercise.difficulty.into()),
+                    exercise.mode.name(),
+                    if progress.is_completed(&exercise.name) {
+                        tr("state-done", &[])
+                    } else {
+                        tr("state-pending", &[])
+                    },
+                    exercise.path
+                );
+            }
+            Ok(true)
+        }
+        Command::Status => {
+            let mut topics: Vec<&str> = Vec::new();
+            for exercise in exercises {
+                if !topics.contains(&exercise.topic.as_str()) {
+                    topics.push(&exercise.topic);
+                }
+            }
+            for topic in topics {
+                let in_topic: Vec<&Exercise> =
+                    exercises.iter().filter(|e| e.topic == topic).collect();
+                let done = in_topic
+                    .iter()
+                    .filter(|e| progress.is_completed(&e.name))
+                    .count();
+                println!(
+                    "{:<14} {} {done}/{}",
+                    topic,
+                    progress::bar(done, in_topic.len(), 20),
+                    in_topic.len()
+                );
+            }
+            let done = exercises
+                .iter()
+                .filter(|e| progress.is_completed(&e.name))
+                .count();
+            println!(
+                "{:<14} {} {done}/{}",
+                tr("total", &[]),
+                progress::bar(done, exercises.len(), 20),
+                exercises.len()
+            );
+            Ok(true)
+        }
+        Command::Stats => {
+            println!(
+                "{:<20} {:<8} {:<14} {}",
+                tr("column-topic", &[]),
+                tr("column-done", &[]),
+                tr("column-attempts", &[]),
+                tr("column-time", &[])
+            );
+            for topic in stats::by_topic(exercises, &progress) {
+                println!(
+                    "{:<20} {:<8} {:<14} {}",
+                    topic.topic,
+                    format!("{}/{}", topic.completed, topic.exercises),
+                    format!("{} ({:.1})", topic.attempts, topic.average_attempts()),
+                    progress::duration(topic.seconds)
+                );
+            }
+            let slowest = stats::slowest(exercises, &progress, 5);
+            if !slowest.is_empty() {
+                println!("\n{}", tr("slowest", &[]));
+                for (exercise, seconds) in slowest {
+                    let attempts = progress.get(&exercise.name).attempts.to_string();
+                    let time = progress::duration(seconds);
+                    println!(
+                        "    {:<20} {:<20} {}",
+                        exercise.name,
+                        exercise.topic,
+                        tr("slow-exercise", &[("attempts", &attempts), ("time", &time)])
+                    );
+                }
+            }
+            Ok(true)
+        }
+        Command::Reset(name) => {
+            let exercise = lookup(&manifest, &name)?;
+            exercise.restore(root)?;
+            progress.reset(&exercise.name);
+            progress.save(root)?;
+            println!("{}", tr("reset", &[("name", &exercise.name)]));
+            Ok(true)
+        }
+        Command::Hint(name) => {
+            // Each call shows one more hint than the last
+            let exercise = lookup(&manifest, &name)?;
+            if Quiz::load(root)?.is_some() {
+                return Err(tr("hints-disabled", &[]));
+            }
+            let hints = exercise.hints_in(i18n::lang());
+            let total = hints.len();
+            let unlocked = progress.unlock_hint(&exercise.name, total);
+            progress.save(root)?;
+            for (level, hint) in hints[..unlocked].iter().enumerate() {
+                let (level, total) = ((level + 1).to_string(), total.to_string());
+                let args = [("level", level.as_str()), ("total", &total), ("hint", hint)];
+                println!("{}\n", tr("hint", &args));
+            }
+            if unlocked < total {
+                println!("{}", tr("more-hints", &[("name", &exercise.name)]));
+            }
+            Ok(true)
+        }
+        Command::Path => {
+            for (step, exercise) in manifest.learning_path().iter().enumerate() {
+                let state = if progress.is_completed(&exercise.name) {
+                    tr("state-done", &[])
+                } else if unmet_requirements(exercise, &progress).is_empty() {
+                    tr("state-ready", &[])
+                } else {
+                    tr("state-locked", &[])
+                };
+                let line = format!("{:>3}. {state:<9} {}", step + 1, exercise.name);
+                if exercise.requires.is_empty() {
+                    println!("{line}");
+                } else {
+                    let required = exercise.requires.join(", ");
+                    println!("{line:<35} {}", tr("after", &[("required", &required)]));
+                }
+            }
+            Ok(true)
+        }
+        Command::Run(name) => {
+            let exercise = lookup(&manifest, &name)?;
+            let unmet = unmet_requirements(exercise, &progress);
+            if !unmet.is_empty() {
+                let required: Vec<String> = unmet.iter().map(|name| format!("`{name}`")).collect();
+                return Err(tr(
+                    "locked",
+                    &[("name", &exercise.name), ("required", &required.join(", "))],
+                ));
+            }
+            run_one(exercise, root, &mut progress)
+        }
+        Command::Verify => {
+            // Stop at the first exercise that is not done yet
+            for exercise in manifest.learning_path() {
+                if !run_one(exercise, root, &mut progress)? {
+                    return Ok(false);
+                }
+            }
+            println!(
+                "{}",
+                tr("all-done", &[("count", &exercises.len().to_string())])
+            );
+            Ok(true)
+        }
+        Command::VerifySolutions => {
+            // Every solution is checked, and the learner's progress is left alone
+            let mut failed = Vec::new();
+            for exercise in exercises {
+                let outcome = exercise
+                    .run_solution(root)
+                    .map_err(|err| format!("could not run cargo: {err}"))?;
+                let path = exercise.solution_path();
+                let args = [("path", path.as_str())];
+                if matches!(outcome, Outcome::Passed) {
+                    println!("{}", tr("solution-passed", &args));
+                } else {
+                    println!("{}", tr("solution-failed", &args));
+                    failed.push(exercise.name.as_str());
+                }
+            }
+            if failed.is_empty() {
+                let count = exercises.len().to_string();
+                println!("{}", tr("all-solutions", &[("count", &count)]));
+                Ok(true)
+            } else {
+                let names = failed.join(", ");
+                println!("{}", tr("failing-solutions", &[("names", &names)]));
+                Ok(false)
+            }
+        }
+        Command::Grade => {
+            println!(
+                "{}",
+                tr("grading", &[("count", &exercises.len().to_string())])
+            );
+            let report = grade::grade(exercises, root, progress::now())?;
+            report.save(root)?;
+            println!("{report}");
+            println!("{}", tr("report-saved", &[("path", grade::REPORT_FILE)]));
+            Ok(true)
+        }
+        Command::Submit => {
+            let config = submit::Config::from_env()?.ok_or_else(|| {
+                tr(
+                    "submit-opt-in",
+                    &[("url", submit::URL_VAR), ("token", submit::TOKEN_VAR)],
+                )
+            })?;
+            submit::enqueue(root)?;
+            let mut all_sent = true;
+            for (report, delivery) in submit::drain(root, &config)? {
+                let path = report.display().to_string();
+                match delivery {
+                    submit::Delivery::Sent => println!("{}", tr("sent", &[("path", &path)])),
+                    submit::Delivery::Offline(message) => {
+                        println!("{}", tr("offline", &[("message", &message)]));
+                        let count = submit::queued(root)?.len().to_string();
+                        println!(
+                            "{}",
+                            tr(
+                                "still-queued",
+                                &[("count", &count), ("path", submit::QUEUE_DIR)]
+                            )
+                        );
+                        return Ok(false);
+                    }
+                    submit::Delivery::Rejected(message) => {
+                        println!(
+                            "{}",
+                            tr("refused", &[("path", &path), ("message", &message)])
+                        );
+                        let rejected = format!("{}/{}", submit::QUEUE_DIR, submit::REJECTED_DIR);
+                        println!("{}", tr("set-aside", &[("path", &rejected)]));
+                        all_sent = false;
+                    }
+                }
+            }
+            Ok(all_sent)
+        }
+        Command::QuizStart(minutes) => {
+            if Quiz::load(root)?.is_some() {
+                return Err(tr("quiz-running", &[]));
+            }
+            // Only what is still to do, starting over as `runner reset` would
+            let open: Vec<Exercise> = exercises
+                .iter()
+                .filter(|e| !progress.is_completed(&e.name))
+                .cloned()
+                .collect();
+            let mut quiz = Quiz::start(
+                &open,
+                quiz::PER_TOPIC,
+                minutes,
+                progress::now(),
+                quiz::seed(),
+            );
+            if quiz.exercises.is_empty() {
+                return Err(tr("nothing-to-quiz", &[]));
+            }
+            for exercise in quiz.exercises.iter().filter_map(|name| manifest.find(name)) {
+                exercise.restore(root)?;
+                progress.reset(&exercise.name);
+                let fingerprint = quiz::fingerprint(root, &exercise.path)?;
+                quiz.started_with.push((exercise.name.clone(), fingerprint));
+            }
+            progress.save(root)?;
+            quiz.save(root)?;
+            let (count, minutes) = (quiz.exercises.len().to_string(), minutes.to_string());
+            println!(
+                "{}",
+                tr("quiz-started", &[("count", &count), ("minutes", &minutes)])
+            );
+            print_quiz(&quiz, &manifest);
+            println!("{}", tr("quiz-commands", &[]));
+            Ok(true)
+        }
+        Command::QuizStatus => {
+            let quiz = Quiz::load(root)?.ok_or_else(|| tr("no-quiz", &[]))?;
+            let now = progress::now();
+            if quiz.is_over(now) {
+                println!("{}", tr("time-up", &[]));
+                return finish_quiz(&quiz, &manifest, root, now);
+            }
+            let time = progress::duration(quiz.remaining(now));
+            println!("{}", tr("time-left", &[("time", &time)]));
+            print_quiz(&quiz, &manifest);
+            Ok(true)
+        }
+        Command::QuizFinish => {
+            let quiz = Quiz::load(root)?.ok_or_else(|| tr("no-quiz", &[]))?;
+            finish_quiz(&quiz, &manifest, root, progress::now())
+        }
+        Command::Watch => {
+            // Re-run the current exercise on every save and move on once it passes
+            for exercise in manifest.learning_path() {
+                let mut watcher = FileWatcher::new(&root.join(&exercise.path));
+                while !run_one(exercise, root, &mut progress)? {
+                    println!("{}", tr("waiting", &[("path", &exercise.path)]));
+                    watcher.wait();
+                    println!();
+                }
+            }
+            println!(
+                "{}",
+                tr("all-done", &[("count", &exercises.len().to_string())])
+            );
+            Ok(true)
+        }
+    }
+}
+
+// The exercises `exercise` requires that are not done yet
+fn unmet_requirements<'a>(exercise: &'a Exercise, progress: &Progress) -> Vec<&'a str> {
+    exercise
+        .requires
+        .iter()
+        .filter(|name| !progress.is_completed(name))
+        .map(String::as_str)
+        .collect()
+}
+
+fn print_quiz(quiz: &Quiz, manifest: &Manifest) {
+    for exercise in quiz.exercises.iter().filter_map(|name| manifest.find(name)) {
+        println!("    {:<20} {}", exercise.name, exercise.path);
+    }
+}
+
+// Scores the quiz and ends it, passing only when every exercise does
+fn finish_quiz(quiz: &Quiz, manifest: &Manifest, root: &Path, now: u64) -> Result<bool, String> {
+    let count = quiz.exercises.len().to_string();
+    println!("{}", tr("checking", &[("count", &count)]));
+    let graded = quiz
+        .exercises
+        .iter()
+        .filter_map(|name| manifest.find(name))
+        .map(|exercise| {
+            let mut graded = grade::grade_exercise(exercise, root)?;
+            let fingerprint = quiz::fingerprint(root, &exercise.path)?;
+            graded.passed &= quiz.counts(&exercise.name, &fingerprint);
+            Ok(graded)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let report = grade::Report {
+        graded_at: now,
+        graded,
+    };
+    Quiz::remove(root)?;
+    println!("{report}");
+    let allowed = quiz.deadline - quiz.started_at;
+    let used = now.saturating_sub(quiz.started_at).min(allowed);
+    let (used, allowed) = (progress::duration(used), progress::duration(allowed));
+    println!(
+        "{}",
+        tr("quiz-time", &[("used", &used), ("allowed", &allowed)])
+    );
+    Ok(report.earned() == report.possible())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    match parse(&args).and_then(|command| execute(command, root)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_subcommands() {
+        assert_eq!(parse(&args("list")), Ok(Command::List));
+        assert_eq!(parse(&args("verify")), Ok(Command::Verify));
+        assert_eq!(
+            parse(&args("verify --solutions")),
+            Ok(Command::VerifySolutions)
+        );
+        assert_eq!(parse(&args("watch")), Ok(Command::Watch));
+        assert_eq!(parse(&args("status")), Ok(Command::Status));
+        assert_eq!(parse(&args("stats")), Ok(Command::Stats));
+        assert_eq!(parse(&args("path")), Ok(Command::Path));
+        assert_eq!(parse(&args("grade")), Ok(Command::Grade));
+        assert_eq!(parse(&args("submit")), Ok(Command::Submit));
+        assert_eq!(parse(&args("quiz")), Ok(Command::QuizStatus));
+        assert_eq!(
+            parse(&args("quiz --minutes 30")),
+            Ok(Command::QuizStart(30))
+        );
+        assert_eq!(parse(&args("quiz --finish")), Ok(Command::QuizFinish));
+        assert_eq!(
+            parse(&args("reset strings")),
+            Ok(Command::Reset("strings".to_string()))
+        );
+        assert_eq!(
+            parse(&args("run strings")),
+            Ok(Command::Run("strings".to_string()))
+        );
+        assert_eq!(
+            parse(&args("hint functions")),
+            Ok(Command::Hint("functions".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_usage() {
+        for line in [
+            "",
+            "run",
+            "hint",
+            "reset",
+            "list extra",
+            "verify --solution",
+            "quiz --minutes",
+            "quiz --minutes 0",
+            "quiz --minutes soon",
+            "frobnicate",
+        ] {
+            assert_eq!(parse(&args(line)), Err(USAGE.to_string()), "{line:?}");
+        }
+    }
+
+    #[test]
+    fn unknown_exercise_is_an_error() {
+        let manifest = Manifest::load(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        assert!(lookup(&manifest, "conditions").is_ok());
+        assert!(lookup(&manifest, "missing")
+            .unwrap_err()
+            .contains("runner list"));
+    }
+}
@@ -0,0 +1,492 @@
+//! Loads the list of exercises from `info.toml` at the workspace root.
+
+use crate::exercise::{Exercise, Gate, Mode};
+use crate::toml::{self, Table, Value};
+use std::collections::HashSet;
+use std::path::Path;
+
+pub const MANIFEST_FILE: &str = "info.toml";
+
+/// Every exercise, in the order the course goes through them
+#[derive(Debug)]
+pub struct Manifest {
+    pub exercises: Vec<Exercise>,
+}
+
+impl Manifest {
+    /// Read and validate `info.toml` from the workspace root `root`
+    pub fn load(root: &Path) -> Result<Manifest, String> {
+        let path = root.join(MANIFEST_FILE);
+        let text = std::fs::read_to_string(&path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+        let manifest = Manifest::parse(&text).map_err(|err| format!("{MANIFEST_FILE}: {err}"))?;
+        for exercise in &manifest.exercises {
+            if !root.join(&exercise.path).is_file() {
+                return Err(format!(
+                    "{MANIFEST_FILE}: exercise `{}` points to missing file {}",
+                    exercise.name, exercise.path
+                ));
+            }
+            if exercise.mode == Mode::CompileFail && !root.join(exercise.snapshot_path()).is_file()
+            {
+                return Err(format!(
+                    "{MANIFEST_FILE}: exercise `{}` has no snapshot {}",
+                    exercise.name,
+                    exercise.snapshot_path()
+                ));
+            }
+        }
+        Ok(manifest)
+    }
+
+    pub fn parse(text: &str) -> Result<Manifest, String> {
+        let tables = toml::parse(text).map_err(|err| err.to_string())?;
+        let mut exercises = Vec::new();
+        let mut names = HashSet::new();
+
+        for table in &tables {
+            match table.name.as_str() {
+                "" if table.entries.is_empty() => {}
+                "exercises" => {
+                    let exercise = exercise(table)?;
+                    if !names.insert(exercise.name.clone()) {
+                        return Err(format!(
+                            "line {}: exercise `{}` is defined twice",
+                            table.line, exercise.name
+                        ));
+                    }
+                    exercises.push(exercise);
+                }
+                "" => {
+                    return Err(format!(
+                        "line {}: unexpected key outside of [[exercises]]",
+                        table.entries[0].line
+                    ))
+                }
+                other => return Err(format!("line {}: unknown table [[{other}]]", table.line)),
+            }
+        }
+
+        for (table, exercise) in tables
+            .iter()
+            .filter(|table| table.name == "exercises")
+            .zip(&exercises)
+        {
+            if let Some(missing) = exercise.requires.iter().find(|name| !names.contains(*name)) {
+                return Err(format!(
+                    "line {}: `{}` requires unknown exercise `{missing}`",
+                    line(table, "requires"),
+                    exercise.name
+                ));
+            }
+        }
+        let manifest = Manifest { exercises };
+        if let Err(stuck) = manifest.order() {
+            return Err(format!(
+                "exercises {} require each other in a cycle",
+                stuck
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        Ok(manifest)
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Exercise> {
+        self.exercises.iter().find(|exercise| exercise.name == name)
+    }
+
+    /// The exercises in an order that puts every exercise after the ones it
+    /// requires, keeping the course order otherwise
+    pub fn learning_path(&self) -> Vec<&Exercise> {
+        self.order().expect("`parse` rejects cycles")
+    }
+
+    // Repeatedly take the first exercise whose requirements are all taken. What
+    // is left when none can be taken requires itself, through a cycle
+    fn order(&self) -> Result<Vec<&Exercise>, Vec<&str>> {
+        let mut left: Vec<&Exercise> = self.exercises.iter().collect();
+        let mut path: Vec<&Exercise> = Vec::with_capacity(left.len());
+        while !left.is_empty() {
+            let ready = left.iter().position(|exercise| {
+                exercise
+                    .requires
+                    .iter()
+                    .all(|name| path.iter().any(|done| &done.name == name))
+            });
+            match ready {
+                Some(index) => path.push(left.remove(index)),
+                None => return Err(left.iter().map(|e| e.name.as_str()).collect()),
+            }
+        }
+        Ok(path)
+    }
+}
+
+const KEYS: &[&str] = &[
+    "name",
+    "path",
+    "topic",
+    "difficulty",
+    "mode",
+    "hints",
+    "hints_vi",
+    "weight",
+    "gates",
+    "requires",
+    "rejects",
+];
+
+fn exercise(table: &Table) -> Result<Exercise, String> {
+    if let Some(unknown) = table
+        .entries
+        .iter()
+        .find(|entry| !KEYS.contains(&entry.key.as_str()))
+    {
+        return Err(format!(
+            "line {}: unknown key `{}`",
+            unknown.line, unknown.key
+        ));
+    }
+
+    let mode = string(table, "mode")?;
+    let difficulty = integer(table, "difficulty")?;
+    // Harder exercises count for more unless the manifest says otherwise
+    let weight = match table.get("weight") {
+        Some(_) => u32::try_from(integer(table, "weight")?)
+            .ok()
+            .filter(|w| (1..=100).contains(w))
+            .ok_or_else(|| {
+                format!(
+                    "line {}: weight must be between 1 and 100",
+                    line(table, "weight")
+                )
+            })?,
+        None => u32::try_from(difficulty).unwrap_or(1),
+    };
+    let english = hints(table, "hints")?;
+    // `runner hint` unlocks them one at a time in either language
+    let hints_vi = match table.get("hints_vi") {
+        Some(entry) => {
+            let translated = hints(table, "hints_vi")?;
+            if translated.len() != english.len() {
+                return Err(format!(
+                    "line {}: `hints_vi` must translate all {} hints",
+                    entry.line,
+                    english.len()
+                ));
+            }
+            translated
+        }
+        None => Vec::new(),
+    };
+    Ok(Exercise {
+        name: string(table, "name")?,
+        path: string(table, "path")?,
+        topic: string(table, "topic")?,
+        difficulty: u8::try_from(difficulty)
+            .ok()
+            .filter(|d| (1..=5).contains(d))
+            .ok_or_else(|| {
+                format!(
+                    "line {}: difficulty must be between 1 and 5",
+                    line(table, "difficulty")
+                )
+            })?,
+        mode: Mode::parse(&mode).ok_or_else(|| {
+            format!(
+                "line {}: mode must be \"compile\", \"test\", \"clippy\" or \"compile_fail\", not {mode:?}",
+                line(table, "mode")
+            )
+        })?,
+        hints: english,
+        hints_vi,
+        weight,
+        gates: gates(table)?,
+        requires: requires(table)?,
+        rejects: rejects(table)?,
+    })
+}
+
+// Optional: exercises to finish before this one
+fn requires(table: &Table) -> Result<Vec<String>, String> {
+    let Some(entry) = table.get("requires") else {
+        return Ok(Vec::new());
+    };
+    let invalid = || {
+        format!(
+            "line {}: `requires` must be an array of exercise names",
+            entry.line
+        )
+    };
+    let Value::Array(items) = &entry.value else {
+        return Err(invalid());
+    };
+    items
+        .iter()
+        .map(|item| match item {
+            Value::String(s) => Ok(s.clone()),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
+
+// Optional: files that must not compile against the exercise
+fn rejects(table: &Table) -> Result<Vec<String>, String> {
+    let Some(entry) = table.get("rejects") else {
+        return Ok(Vec::new());
+    };
+    let invalid = || {
+        format!(
+            "line {}: `rejects` must be an array of `.rs` file paths",
+            entry.line
+        )
+    };
+    let Value::Array(items) = &entry.value else {
+        return Err(invalid());
+    };
+    items
+        .iter()
+        .map(|item| match item {
+            Value::String(s) if s.ends_with(".rs") => Ok(s.clone()),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
+
+// Optional, so most exercises are only checked for correctness
+fn gates(table: &Table) -> Result<Vec<Gate>, String> {
+    let Some(entry) = table.get("gates") else {
+        return Ok(Vec::new());
+    };
+    let invalid = || {
+        format!(
+            "line {}: `gates` must be an array of \"clippy\" and \"rustfmt\"",
+            entry.line
+        )
+    };
+    let Value::Array(items) = &entry.value else {
+        return Err(invalid());
+    };
+    items
+        .iter()
+        .map(|item| match item {
+            Value::String(s) => Gate::parse(s).ok_or_else(invalid),
+            _ => Err(invalid()),
+        })
+        .collect()
+}
+
+// From a small nudge to almost the solution, at least one of them
+fn hints(table: &Table, key: &str) -> Result<Vec<String>, String> {
+    let not_strings = || {
+        format!(
+            "line {}: `{key}` must be a non-empty array of strings",
+            line(table, key)
+        )
+    };
+    let Value::Array(items) = field(table, key)? else {
+        return Err(not_strings());
+    };
+    if items.is_empty() {
+        return Err(not_strings());
+    }
+    items
+        .iter()
+        .map(|item| match item {
+            Value::String(s) => Ok(s.clone()),
+            _ => Err(not_strings()),
+        })
+        .collect()
+}
+
+fn line(table: &Table, key: &str) -> usize {
+    table.get(key).map_or(table.line, |entry| entry.line)
+}
+
+fn field<'a>(table: &'a Table, key: &str) -> Result<&'a Value, String> {
+    table
+        .get(key)
+        .map(|entry| &entry.value)
+        .ok_or_else(|| format!("line {}: exercise is missing `{key}`", table.line))
+}
+
+fn string(table: &Table, key: &str) -> Result<String, String> {
+    match field(table, key)? {
+        Value::String(s) => Ok(s.clone()),
+        other => Err(format!(
+            "line {}: `{key}` must be a string, not {}",
+            line(table, key),
+            other.type_name()
+        )),
+    }
+}
+
+fn integer(table: &Table, key: &str) -> Result<i64, String> {
+    match field(table, key)? {
+        Value::Integer(i) => Ok(*i),
+        other => Err(format!(
+            "line {}: `{key}` must be an integer, not {}",
+            line(table, key),
+            other.type_name()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Lang;
+
+    const ONE: &str = r#"
+[[exercises]]
+name = "conditions"
+path = "exercises/basic-of-rust/src/conditions.rs"
+topic = "basics"
+difficulty = 1
+mode = "test"
+hints = ["Use if", "if is an expression"]
+"#;
+
+    #[test]
+    fn parses_an_exercise() {
+        let manifest = Manifest::parse(ONE).unwrap();
+        assert_eq!(
+            manifest.exercises,
+            vec![Exercise {
+                name: "conditions".to_string(),
+                path: "exercises/basic-of-rust/src/conditions.rs".to_string(),
+                topic: "basics".to_string(),
+                difficulty: 1,
+                mode: Mode::Test,
+                hints: vec!["Use if".to_string(), "if is an expression".to_string()],
+                hints_vi: Vec::new(),
+                weight: 1,
+                gates: Vec::new(),
+                requires: Vec::new(),
+                rejects: Vec::new(),
+            }]
+        );
+        assert!(manifest.find("conditions").is_some());
+        assert!(manifest.find("nope").is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        let error = |from: &str, to: &str| Manifest::parse(&ONE.replace(from, to)).unwrap_err();
+        assert_eq!(
+            error("mode = \"test\"", "mode = \"run\"").split(':').next(),
+            Some("line 7")
+        );
+        assert!(error("difficulty = 1", "difficulty = 9").contains("between 1 and 5"));
+        assert!(error("difficulty = 1", "difficulty = \"1\"").contains("must be an integer"));
+        assert!(error("hints", "hint").contains("unknown key `hint`"));
+        assert!(error("[\"Use if\", \"if is an expression\"]", "[]").contains("non-empty"));
+        assert!(error("\"Use if\",", "1,").contains("array of strings"));
+        assert!(error("topic", "topics").contains("unknown key `topics`"));
+        assert!(error("[[exercises]]", "[[exercise]]").contains("unknown table"));
+        assert!(error("mode", "weight = 0\nmode").contains("between 1 and 100"));
+        assert!(error("mode", "gates = [\"fmt\"]\nmode").contains("`gates` must be"));
+        assert!(error("mode", "gates = \"clippy\"\nmode").contains("`gates` must be"));
+    }
+
+    #[test]
+    fn parses_gates() {
+        let gated = ONE.replace("mode", "gates = [\"clippy\", \"rustfmt\"]\nmode");
+        assert_eq!(
+            Manifest::parse(&gated).unwrap().exercises[0].gates,
+            vec![Gate::Clippy, Gate::Rustfmt]
+        );
+    }
+
+    #[test]
+    fn parses_rejects() {
+        let rejecting = ONE.replace("mode", "rejects = [\"compile-fail/twice.rs\"]\nmode");
+        assert_eq!(
+            Manifest::parse(&rejecting).unwrap().exercises[0].rejects,
+            ["compile-fail/twice.rs"]
+        );
+        let not_rust = ONE.replace("mode", "rejects = [\"twice.stderr\"]\nmode");
+        assert!(Manifest::parse(&not_rust)
+            .unwrap_err()
+            .contains("`rejects` must be"));
+    }
+
+    #[test]
+    fn translated_hints() {
+        let translated = ONE.replace(
+            "mode",
+            "hints_vi = [\"Dùng if\", \"if là biểu thức\"]\nmode",
+        );
+        let exercise = &Manifest::parse(&translated).unwrap().exercises[0];
+        assert_eq!(exercise.hints_in(Lang::Vi)[0], "Dùng if");
+        assert_eq!(exercise.hints_in(Lang::En)[0], "Use if");
+        let untranslated = &Manifest::parse(ONE).unwrap().exercises[0];
+        assert_eq!(untranslated.hints_in(Lang::Vi)[0], "Use if");
+
+        let partial = ONE.replace("mode", "hints_vi = [\"Dùng if\"]\nmode");
+        assert!(Manifest::parse(&partial)
+            .unwrap_err()
+            .contains("translate all 2 hints"));
+    }
+
+    #[test]
+    fn weight_defaults_to_difficulty() {
+        let harder = ONE.replace("difficulty = 1", "difficulty = 4");
+        assert_eq!(Manifest::parse(&harder).unwrap().exercises[0].weight, 4);
+        let weighted = ONE.replace("mode", "weight = 10\nmode");
+        assert_eq!(Manifest::parse(&weighted).unwrap().exercises[0].weight, 10);
+    }
+
+    fn course(requires: [&str; 3]) -> String {
+        ["a", "b", "c"]
+            .iter()
+            .zip(requires)
+            .map(|(name, requires)| {
+                ONE.replace("\"conditions\"", &format!("\"{name}\""))
+                    .replace("mode", &format!("requires = [{requires}]\nmode"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn learning_path_puts_requirements_first() {
+        let manifest = Manifest::parse(&course(["\"c\"", "", "\"b\""])).unwrap();
+        assert_eq!(manifest.exercises[0].requires, ["c"]);
+        let path: Vec<&str> = manifest
+            .learning_path()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(path, ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn rejects_bad_requirements() {
+        let error = |requires| Manifest::parse(&course(requires)).unwrap_err();
+        assert!(error(["\"d\"", "", ""]).contains("`a` requires unknown exercise `d`"));
+        assert!(error(["1", "", ""]).contains("array of exercise names"));
+        assert_eq!(
+            error(["", "\"c\"", "\"b\""]),
+            "exercises `b`, `c` require each other in a cycle"
+        );
+        assert!(error(["\"a\"", "", ""]).contains("`a` require each other"));
+    }
+
+    #[test]
+    fn rejects_duplicate_names() {
+        let twice = format!("{ONE}{ONE}");
+        assert!(Manifest::parse(&twice)
+            .unwrap_err()
+            .contains("defined twice"));
+    }
+
+    #[test]
+    fn the_real_manifest_is_valid() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let manifest = Manifest::load(root).unwrap();
+        assert!(!manifest.exercises.is_empty());
+    }
+}
@@ -0,0 +1,263 @@
+//! What the learner has done so far, kept in `.progress.json` at the workspace root.
+
+use crate::json::{self, Json};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const PROGRESS_FILE: &str = ".progress.json";
+
+/// Progress on a single exercise. Times are seconds since the Unix epoch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExerciseProgress {
+    /// Runs of the exercise until it first passed
+    pub attempts: u32,
+    pub first_attempt_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    /// How many of the exercise's hints `runner hint` has shown
+    pub hints_unlocked: usize,
+}
+
+impl ExerciseProgress {
+    pub fn is_completed(&self) -> bool {
+        self.completed_at.is_some()
+    }
+
+    /// Seconds from the first run to the one that passed
+    pub fn time_to_complete(&self) -> Option<u64> {
+        Some(self.completed_at?.saturating_sub(self.first_attempt_at?))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub exercises: BTreeMap<String, ExerciseProgress>,
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+impl Progress {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(PROGRESS_FILE)
+    }
+
+    /// Read the progress file, starting from scratch when there is none yet
+    pub fn load(root: &Path) -> Result<Progress, String> {
+        let path = Progress::path(root);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Progress::from_json(&text).map_err(|err| format!("{PROGRESS_FILE}: {err}")),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, root: &Path) -> Result<(), String> {
+        let path = Progress::path(root);
+        std::fs::write(&path, self.to_json().pretty())
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    pub fn get(&self, name: &str) -> ExerciseProgress {
+        self.exercises.get(name).cloned().unwrap_or_default()
+    }
+
+    pub fn is_completed(&self, name: &str) -> bool {
+        self.get(name).is_completed()
+    }
+
+    /// Count one run of `name` at time `at`, marking it done if it passed
+    pub fn record_attempt(&mut self, name: &str, passed: bool, at: u64) {
+        let entry = self.exercises.entry(name.to_string()).or_default();
+        if entry.is_completed() {
+            return;
+        }
+        entry.attempts += 1;
+        entry.first_attempt_at.get_or_insert(at);
+        if passed {
+            entry.completed_at = Some(at);
+        }
+    }
+
+    /// Unlock the next of the `available` hints of `name`, returning how many are
+    /// unlocked now
+    pub fn unlock_hint(&mut self, name: &str, available: usize) -> usize {
+        let entry = self.exercises.entry(name.to_string()).or_default();
+        entry.hints_unlocked = (entry.hints_unlocked + 1).min(available);
+        entry.hints_unlocked
+    }
+
+    /// Forget everything about `name`
+    pub fn reset(&mut self, name: &str) {
+        self.exercises.remove(name);
+    }
+
+    fn to_json(&self) -> Json {
+        let optional = |time: Option<u64>| time.map_or(Json::Null, |t| Json::Number(t as f64));
+        Json::Object(vec![(
+            "exercises".to_string(),
+            Json::Object(
+                self.exercises
+                    .iter()
+                    .map(|(name, progress)| {
+                        (
+                            name.clone(),
+                            Json::Object(vec![
+                                (
+                                    "attempts".to_string(),
+                                    Json::Number(progress.attempts.into()),
+                                ),
+                                (
+                                    "first_attempt_at".to_string(),
+                                    optional(progress.first_attempt_at),
+                                ),
+                                ("completed_at".to_string(), optional(progress.completed_at)),
+                                (
+                                    "hints_unlocked".to_string(),
+                                    Json::Number(progress.hints_unlocked as f64),
+                                ),
+                            ]),
+                        )
+                    })
+                    .collect(),
+            ),
+        )])
+    }
+
+    fn from_json(text: &str) -> Result<Progress, String> {
+        let document = json::parse(text)?;
+        let entries = document
+            .get("exercises")
+            .and_then(Json::as_object)
+            .ok_or("missing `exercises` object")?;
+
+        let mut exercises = BTreeMap::new();
+        for (name, entry) in entries {
+            let number = |key: &str| entry.get(key).and_then(Json::as_u64);
+            let attempts = entry
+                .get("attempts")
+                .and_then(Json::as_u64)
+                .and_then(|attempts| u32::try_from(attempts).ok())
+                .ok_or_else(|| format!("`{name}` has no valid `attempts`"))?;
+            exercises.insert(
+                name.clone(),
+                ExerciseProgress {
+                    attempts,
+                    first_attempt_at: number("first_attempt_at"),
+                    completed_at: number("completed_at"),
+                    // Missing in files written before hints were staged
+                    hints_unlocked: number("hints_unlocked").unwrap_or(0) as usize,
+                },
+            );
+        }
+        Ok(Progress { exercises })
+    }
+}
+
+/// A `[####------]` bar showing `done` out of `total`
+pub fn bar(done: usize, total: usize, width: usize) -> String {
+    let filled = (done * width).checked_div(total).unwrap_or(width);
+    format!("[{}{}]", "#".repeat(filled), "-".repeat(width - filled))
+}
+
+/// `12m 05s`
+pub fn duration(seconds: u64) -> String {
+    format!("{}m {:02}s", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attempts_count_until_completion() {
+        let mut progress = Progress::default();
+        progress.record_attempt("strings", false, 10);
+        progress.record_attempt("strings", false, 20);
+        assert!(!progress.is_completed("strings"));
+        progress.record_attempt("strings", true, 30);
+        progress.record_attempt("strings", false, 40);
+        assert_eq!(
+            progress.get("strings"),
+            ExerciseProgress {
+                attempts: 3,
+                first_attempt_at: Some(10),
+                completed_at: Some(30),
+                hints_unlocked: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn time_from_first_attempt_to_completion() {
+        let mut progress = Progress::default();
+        progress.record_attempt("strings", false, 100);
+        assert_eq!(progress.get("strings").time_to_complete(), None);
+        progress.record_attempt("strings", true, 460);
+        assert_eq!(progress.get("strings").time_to_complete(), Some(360));
+    }
+
+    #[test]
+    fn hints_unlock_one_at_a_time() {
+        let mut progress = Progress::default();
+        assert_eq!(progress.unlock_hint("strings", 3), 1);
+        assert_eq!(progress.unlock_hint("strings", 3), 2);
+        assert_eq!(progress.unlock_hint("strings", 3), 3);
+        assert_eq!(progress.unlock_hint("strings", 3), 3);
+        assert_eq!(progress.unlock_hint("conditions", 1), 1);
+        assert_eq!(progress.get("strings").attempts, 0);
+    }
+
+    #[test]
+    fn reset_forgets_an_exercise() {
+        let mut progress = Progress::default();
+        progress.record_attempt("strings", true, 10);
+        progress.unlock_hint("strings", 2);
+        progress.reset("strings");
+        assert_eq!(progress.get("strings"), ExerciseProgress::default());
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut progress = Progress::default();
+        progress.record_attempt("conditions", true, 1_700_000_000);
+        progress.record_attempt("strings", false, 1_700_000_100);
+        progress.unlock_hint("strings", 3);
+        let text = progress.to_json().pretty();
+        assert_eq!(Progress::from_json(&text), Ok(progress));
+    }
+
+    #[test]
+    fn older_files_have_no_hints_unlocked() {
+        let progress = Progress::from_json(
+            r#"{"exercises": {"a": {"attempts": 2, "first_attempt_at": 5, "completed_at": null}}}"#,
+        )
+        .unwrap();
+        assert_eq!(progress.get("a").hints_unlocked, 0);
+    }
+
+    #[test]
+    fn invalid_file_is_reported() {
+        assert!(Progress::from_json("{}").is_err());
+        assert!(Progress::from_json(r#"{"exercises": {"a": {}}}"#)
+            .unwrap_err()
+            .contains("attempts"));
+    }
+
+    #[test]
+    fn progress_bar() {
+        assert_eq!(bar(0, 4, 8), "[--------]");
+        assert_eq!(bar(1, 4, 8), "[##------]");
+        assert_eq!(bar(4, 4, 8), "[########]");
+        assert_eq!(bar(0, 0, 4), "[####]");
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(duration(1_200), "20m 00s");
+        assert_eq!(duration(65), "1m 05s");
+    }
+}
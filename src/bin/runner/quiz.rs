@@ -0,0 +1,309 @@
+//! `runner quiz`: a timed checkpoint on a random pick of exercises, kept in
+//! `.quiz.json` at the workspace root while it runs.
+//!
+//! Only exercises that are not done yet are picked, and they start from their
+//! original state. A pick whose file is unchanged at the end does not count:
+//! an answer already there at the start is not work done in the quiz.
+
+use crate::exercise::Exercise;
+use crate::json::{self, Json};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const QUIZ_FILE: &str = ".quiz.json";
+
+/// How many exercises of each topic a quiz picks
+pub const PER_TOPIC: usize = 1;
+
+/// A quiz in progress. Times are seconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quiz {
+    pub started_at: u64,
+    pub deadline: u64,
+    /// The picked exercises, in course order
+    pub exercises: Vec<String>,
+    /// The fingerprint of each picked exercise file when the quiz started
+    pub started_with: Vec<(String, String)>,
+}
+
+impl Quiz {
+    /// Pick `per_topic` random exercises of every topic, to be done within
+    /// `minutes` from `now`
+    pub fn start(
+        exercises: &[Exercise],
+        per_topic: usize,
+        minutes: u64,
+        now: u64,
+        seed: u64,
+    ) -> Quiz {
+        let mut rng = Rng::new(seed);
+        let mut topics: Vec<&str> = Vec::new();
+        for exercise in exercises {
+            if !topics.contains(&exercise.topic.as_str()) {
+                topics.push(&exercise.topic);
+            }
+        }
+        let mut picked: Vec<&str> = Vec::new();
+        for topic in topics {
+            let mut in_topic: Vec<&str> = exercises
+                .iter()
+                .filter(|e| e.topic == topic)
+                .map(|e| e.name.as_str())
+                .collect();
+            // A partial Fisher-Yates shuffle: the first `per_topic` are the pick
+            let take = per_topic.min(in_topic.len());
+            for i in 0..take {
+                let j = i + rng.below(in_topic.len() - i);
+                in_topic.swap(i, j);
+            }
+            picked.extend_from_slice(&in_topic[..take]);
+        }
+        Quiz {
+            started_at: now,
+            deadline: now.saturating_add(minutes.saturating_mul(60)),
+            exercises: exercises
+                .iter()
+                .filter(|e| picked.contains(&e.name.as_str()))
+                .map(|e| e.name.clone())
+                .collect(),
+            started_with: Vec::new(),
+        }
+    }
+
+    /// Whether the pick `name` may count as passed with its file now at
+    /// `fingerprint`, which it may not if the file is as it was at the start
+    pub fn counts(&self, name: &str, fingerprint: &str) -> bool {
+        !self
+            .started_with
+            .iter()
+            .any(|(picked, at_start)| picked == name && at_start == fingerprint)
+    }
+
+    pub fn remaining(&self, now: u64) -> u64 {
+        self.deadline.saturating_sub(now)
+    }
+
+    pub fn is_over(&self, now: u64) -> bool {
+        now >= self.deadline
+    }
+
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(QUIZ_FILE)
+    }
+
+    /// The quiz in progress, if there is one
+    pub fn load(root: &Path) -> Result<Option<Quiz>, String> {
+        let path = Quiz::path(root);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Quiz::from_json(&text)
+                .map(Some)
+                .map_err(|err| format!("{QUIZ_FILE}: {err}")),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, root: &Path) -> Result<(), String> {
+        let path = Quiz::path(root);
+        std::fs::write(&path, self.to_json().pretty())
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    /// End the quiz in progress
+    pub fn remove(root: &Path) -> Result<(), String> {
+        let path = Quiz::path(root);
+        std::fs::remove_file(&path)
+            .map_err(|err| format!("could not remove {}: {err}", path.display()))
+    }
+
+    fn to_json(&self) -> Json {
+        Json::Object(vec![
+            (
+                "started_at".to_string(),
+                Json::Number(self.started_at as f64),
+            ),
+            ("deadline".to_string(), Json::Number(self.deadline as f64)),
+            (
+                "exercises".to_string(),
+                Json::Array(
+                    self.exercises
+                        .iter()
+                        .map(|name| Json::String(name.clone()))
+                        .collect(),
+                ),
+            ),
+            (
+                "started_with".to_string(),
+                Json::Object(
+                    self.started_with
+                        .iter()
+                        .map(|(name, fingerprint)| {
+                            (name.clone(), Json::String(fingerprint.clone()))
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    fn from_json(text: &str) -> Result<Quiz, String> {
+        let document = json::parse(text)?;
+        let time = |key: &str| {
+            document
+                .get(key)
+                .and_then(Json::as_u64)
+                .ok_or_else(|| format!("missing `{key}`"))
+        };
+        let Some(Json::Array(names)) = document.get("exercises") else {
+            return Err("missing `exercises` array".to_string());
+        };
+        let exercises = names
+            .iter()
+            .map(|name| match name {
+                Json::String(name) => Ok(name.clone()),
+                _ => Err("`exercises` must only hold names".to_string()),
+            })
+            .collect::<Result<_, _>>()?;
+        let started_with = document
+            .get("started_with")
+            .and_then(Json::as_object)
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, fingerprint)| match fingerprint {
+                Json::String(fingerprint) => Ok((name.clone(), fingerprint.clone())),
+                _ => Err("`started_with` must only hold fingerprints".to_string()),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Quiz {
+            started_at: time("started_at")?,
+            deadline: time("deadline")?,
+            exercises,
+            started_with,
+        })
+    }
+}
+
+/// A fingerprint of the file at `path` under `root`, to tell whether it changed.
+/// FNV-1a, so it stays the same whichever toolchain built the runner
+pub fn fingerprint(root: &Path, path: &str) -> Result<String, String> {
+    let bytes =
+        std::fs::read(root.join(path)).map_err(|err| format!("could not read {path}: {err}"))?;
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    Ok(format!("{hash:016x}"))
+}
+
+/// Differs from run to run, which is all a quiz needs
+pub fn seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
+
+// xorshift64*: small and good enough to shuffle exercises, not for anything
+// that has to be unpredictable
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        // The state must never be zero
+        Rng(seed | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // In `0..n`, for `n > 0`
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course() -> Vec<Exercise> {
+        vec![
+            Exercise::fixture("a1", "a"),
+            Exercise::fixture("a2", "a"),
+            Exercise::fixture("a3", "a"),
+            Exercise::fixture("b1", "b"),
+            Exercise::fixture("b2", "b"),
+            Exercise::fixture("c1", "c"),
+        ]
+    }
+
+    #[test]
+    fn picks_per_topic_in_course_order() {
+        let course = course();
+        for seed in 0..50 {
+            let quiz = Quiz::start(&course, 1, 30, 1_000, seed);
+            let topics: Vec<char> = quiz
+                .exercises
+                .iter()
+                .map(|name| name.chars().next().unwrap())
+                .collect();
+            assert_eq!(topics, ['a', 'b', 'c'], "seed {seed}");
+        }
+        let quiz = Quiz::start(&course, 2, 30, 1_000, 7);
+        assert_eq!(quiz.exercises.len(), 5);
+        let mut sorted = quiz.exercises.clone();
+        sorted.sort();
+        assert_eq!(sorted, quiz.exercises);
+    }
+
+    #[test]
+    fn picks_vary_with_the_seed() {
+        let course = course();
+        let picks: std::collections::HashSet<Vec<String>> = (0..50)
+            .map(|seed| Quiz::start(&course, 1, 30, 0, seed).exercises)
+            .collect();
+        assert!(picks.len() > 1);
+    }
+
+    #[test]
+    fn deadline() {
+        let quiz = Quiz::start(&course(), 1, 30, 1_000, 1);
+        assert_eq!(quiz.deadline, 2_800);
+        assert_eq!(quiz.remaining(1_600), 1_200);
+        assert!(!quiz.is_over(2_799));
+        assert!(quiz.is_over(2_800));
+        assert_eq!(quiz.remaining(5_000), 0);
+    }
+
+    #[test]
+    fn endless_quiz_does_not_overflow() {
+        let quiz = Quiz::start(&course(), 1, u64::MAX / 2, 1_000, 1);
+        assert_eq!(quiz.deadline, u64::MAX);
+        assert!(!quiz.is_over(u64::MAX - 1));
+    }
+
+    #[test]
+    fn unchanged_picks_do_not_count() {
+        let root = std::env::temp_dir().join(format!("runner-quiz-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("a1.rs"), "fn solved() {}\n").unwrap();
+        let mut quiz = Quiz::start(&course(), 1, 30, 1_000, 1);
+        quiz.started_with = vec![("a1".to_string(), fingerprint(&root, "a1.rs").unwrap())];
+        assert!(!quiz.counts("a1", &fingerprint(&root, "a1.rs").unwrap()));
+        std::fs::write(root.join("a1.rs"), "fn solved() { todo!() }\n").unwrap();
+        assert!(quiz.counts("a1", &fingerprint(&root, "a1.rs").unwrap()));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut quiz = Quiz::start(&course(), 2, 45, 1_700_000_000, 3);
+        quiz.started_with = vec![("a1".to_string(), "cbf29ce484222325".to_string())];
+        assert_eq!(Quiz::from_json(&quiz.to_json().pretty()), Ok(quiz));
+        assert!(Quiz::from_json(r#"{"started_at": 1, "exercises": []}"#)
+            .unwrap_err()
+            .contains("deadline"));
+    }
+}
@@ -0,0 +1,123 @@
+//! `runner stats`: where the time and the attempts went, per topic.
+
+use crate::exercise::Exercise;
+use crate::progress::Progress;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicStats {
+    pub topic: String,
+    pub exercises: usize,
+    pub completed: usize,
+    /// Runs of every exercise of the topic, finished or not
+    pub attempts: u32,
+    /// Time to complete the finished exercises, in seconds
+    pub seconds: u64,
+}
+
+impl TopicStats {
+    pub fn average_attempts(&self) -> f64 {
+        match self.exercises {
+            0 => 0.0,
+            n => f64::from(self.attempts) / n as f64,
+        }
+    }
+}
+
+/// One entry per topic, the one that took longest first
+pub fn by_topic(exercises: &[Exercise], progress: &Progress) -> Vec<TopicStats> {
+    let mut topics: Vec<TopicStats> = Vec::new();
+    for exercise in exercises {
+        let index = match topics.iter().position(|t| t.topic == exercise.topic) {
+            Some(index) => index,
+            None => {
+                topics.push(TopicStats {
+                    topic: exercise.topic.clone(),
+                    exercises: 0,
+                    completed: 0,
+                    attempts: 0,
+                    seconds: 0,
+                });
+                topics.len() - 1
+            }
+        };
+        let entry = &mut topics[index];
+        let done = progress.get(&exercise.name);
+        entry.exercises += 1;
+        entry.attempts += done.attempts;
+        if let Some(seconds) = done.time_to_complete() {
+            entry.completed += 1;
+            entry.seconds += seconds;
+        }
+    }
+    // Stable, so topics that took as long stay in course order
+    topics.sort_by_key(|t| std::cmp::Reverse(t.seconds));
+    topics
+}
+
+/// The `count` finished exercises that took longest, with their time in seconds
+pub fn slowest<'a>(
+    exercises: &'a [Exercise],
+    progress: &Progress,
+    count: usize,
+) -> Vec<(&'a Exercise, u64)> {
+    let mut timed: Vec<(&Exercise, u64)> = exercises
+        .iter()
+        .filter_map(|e| Some((e, progress.get(&e.name).time_to_complete()?)))
+        .collect();
+    timed.sort_by_key(|&(_, seconds)| std::cmp::Reverse(seconds));
+    timed.truncate(count);
+    timed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course() -> (Vec<Exercise>, Progress) {
+        let exercises = vec![
+            Exercise::fixture("a1", "a"),
+            Exercise::fixture("a2", "a"),
+            Exercise::fixture("b1", "b"),
+            Exercise::fixture("c1", "c"),
+        ];
+        let mut progress = Progress::default();
+        progress.record_attempt("a1", true, 100);
+        progress.record_attempt("a2", false, 100);
+        progress.record_attempt("a2", false, 200);
+        progress.record_attempt("b1", false, 100);
+        progress.record_attempt("b1", true, 700);
+        (exercises, progress)
+    }
+
+    #[test]
+    fn topics_sorted_by_time() {
+        let (exercises, progress) = course();
+        let stats = by_topic(&exercises, &progress);
+        let topics: Vec<&str> = stats.iter().map(|t| t.topic.as_str()).collect();
+        assert_eq!(topics, ["b", "a", "c"]);
+        assert_eq!(
+            stats[1],
+            TopicStats {
+                topic: "a".to_string(),
+                exercises: 2,
+                completed: 1,
+                attempts: 3,
+                seconds: 0,
+            }
+        );
+        assert_eq!(stats[1].average_attempts(), 1.5);
+        assert_eq!(stats[0].seconds, 600);
+        assert_eq!(stats[2].attempts, 0);
+    }
+
+    #[test]
+    fn slowest_exercises() {
+        let (exercises, progress) = course();
+        let slowest: Vec<(&str, u64)> = slowest(&exercises, &progress, 5)
+            .into_iter()
+            .map(|(e, seconds)| (e.name.as_str(), seconds))
+            .collect();
+        assert_eq!(slowest, [("b1", 600), ("a1", 0)]);
+        assert_eq!(super::slowest(&exercises, &progress, 1).len(), 1);
+    }
+}
@@ -0,0 +1,341 @@
+//! `runner submit`: posts the `runner grade` report to the bootcamp
+//! leaderboard. Nothing is sent unless the learner opts in by setting both
+//! `BOOTCAMP_LEADERBOARD_URL` and `BOOTCAMP_LEADERBOARD_TOKEN`.
+//!
+//! Reports wait in `.submissions/` until the leaderboard accepts them, so a
+//! submit without network, or during an outage, is sent by a later one.
+//! Reports the leaderboard refuses for good move to `.submissions/rejected/`,
+//! so they do not hold up the ones behind them. The request itself is made by `curl`, which
+//! brings the TLS support std does not have.
+
+use crate::grade::REPORT_FILE;
+use crate::json::{self, Json};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+pub const QUEUE_DIR: &str = ".submissions";
+/// Inside [`QUEUE_DIR`], where refused reports are set aside
+pub const REJECTED_DIR: &str = "rejected";
+pub const URL_VAR: &str = "BOOTCAMP_LEADERBOARD_URL";
+pub const TOKEN_VAR: &str = "BOOTCAMP_LEADERBOARD_TOKEN";
+
+/// Where and as whom to submit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub url: String,
+    pub token: String,
+}
+
+impl Config {
+    /// `None` when the learner has not opted in
+    pub fn from_env() -> Result<Option<Config>, String> {
+        Config::new(std::env::var(URL_VAR).ok(), std::env::var(TOKEN_VAR).ok())
+    }
+
+    fn new(url: Option<String>, token: Option<String>) -> Result<Option<Config>, String> {
+        let (url, token) = match (url, token) {
+            (None, None) => return Ok(None),
+            (Some(url), Some(token)) if !token.trim().is_empty() => (url, token),
+            _ => return Err(format!("set both {URL_VAR} and {TOKEN_VAR} to submit")),
+        };
+        // The token goes along with every report, so never in the clear
+        if !url.starts_with("https://") {
+            return Err(format!("{URL_VAR} must be an https:// URL, not {url:?}"));
+        }
+        Ok(Some(Config {
+            url,
+            token: token.trim().to_string(),
+        }))
+    }
+}
+
+/// What happened to one queued report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delivery {
+    Sent,
+    /// The leaderboard could not be reached or could not take the report for
+    /// now, the report stays queued
+    Offline(String),
+    /// The leaderboard will never take the report, which is set aside in
+    /// [`REJECTED_DIR`]
+    Rejected(String),
+}
+
+/// Copy the latest grade report into the queue, named by when it was graded
+pub fn enqueue(root: &Path) -> Result<PathBuf, String> {
+    let report = root.join(REPORT_FILE);
+    let text = match std::fs::read_to_string(&report) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(format!(
+                "no {REPORT_FILE} yet, run `runner grade` before submitting"
+            ))
+        }
+        Err(err) => return Err(format!("could not read {}: {err}", report.display())),
+    };
+    let graded_at = json::parse(&text)
+        .ok()
+        .and_then(|document| document.get("graded_at").and_then(Json::as_u64))
+        .ok_or_else(|| format!("{REPORT_FILE} has no `graded_at`, run `runner grade` again"))?;
+    let queue = root.join(QUEUE_DIR);
+    std::fs::create_dir_all(&queue)
+        .map_err(|err| format!("could not create {}: {err}", queue.display()))?;
+    let queued = queue.join(format!("{graded_at}.json"));
+    std::fs::copy(&report, &queued)
+        .map_err(|err| format!("could not queue {}: {err}", report.display()))?;
+    Ok(queued)
+}
+
+/// The queued reports, oldest first
+pub fn queued(root: &Path) -> Result<Vec<PathBuf>, String> {
+    let queue = root.join(QUEUE_DIR);
+    let entries = match std::fs::read_dir(&queue) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("could not read {}: {err}", queue.display())),
+    };
+    let mut reports: Vec<(u64, PathBuf)> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let graded_at = path.file_stem()?.to_str()?.parse().ok()?;
+            (path.extension()? == "json").then_some((graded_at, path))
+        })
+        .collect();
+    reports.sort();
+    Ok(reports.into_iter().map(|(_, path)| path).collect())
+}
+
+/// Send the queued reports oldest first, until one cannot reach the
+/// leaderboard. Sent reports leave the queue and refused ones are set aside
+pub fn drain(root: &Path, config: &Config) -> Result<Vec<(PathBuf, Delivery)>, String> {
+    drain_with(root, |report| send(config, report))
+}
+
+fn drain_with(
+    root: &Path,
+    mut send: impl FnMut(&Path) -> Result<Delivery, String>,
+) -> Result<Vec<(PathBuf, Delivery)>, String> {
+    let mut deliveries = Vec::new();
+    for report in queued(root)? {
+        let delivery = send(&report)?;
+        match delivery {
+            Delivery::Sent => std::fs::remove_file(&report)
+                .map_err(|err| format!("could not unqueue {}: {err}", report.display()))?,
+            Delivery::Rejected(_) => set_aside(root, &report)?,
+            Delivery::Offline(_) => {
+                deliveries.push((report, delivery));
+                break;
+            }
+        }
+        deliveries.push((report, delivery));
+    }
+    Ok(deliveries)
+}
+
+fn set_aside(root: &Path, report: &Path) -> Result<(), String> {
+    let rejected = root.join(QUEUE_DIR).join(REJECTED_DIR);
+    std::fs::create_dir_all(&rejected)
+        .map_err(|err| format!("could not create {}: {err}", rejected.display()))?;
+    let name = report.file_name().expect("queued reports are files");
+    std::fs::rename(report, rejected.join(name))
+        .map_err(|err| format!("could not set aside {}: {err}", report.display()))
+}
+
+/// Post one queued report
+fn send(config: &Config, report: &Path) -> Result<Delivery, String> {
+    let curl = std::env::var("CURL").unwrap_or_else(|_| "curl".to_string());
+    let mut child = Command::new(curl)
+        .args(curl_args(config, report))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("could not run curl: {err}"))?;
+    // On stdin rather than the command line, where other users could see it
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(format!("Authorization: Bearer {}\n", config.token).as_bytes())
+        .map_err(|err| format!("could not pass the token to curl: {err}"))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|err| format!("could not run curl: {err}"))?;
+    let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    // The `--write-out` line comes after whatever the leaderboard answered
+    let status = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .last()
+        .and_then(|line| line.trim().parse().ok());
+    Ok(delivery(output.status.code(), status, message))
+}
+
+fn curl_args(config: &Config, report: &Path) -> Vec<String> {
+    vec![
+        "--silent".to_string(),
+        "--show-error".to_string(),
+        "--fail".to_string(),
+        "--proto".to_string(),
+        "=https".to_string(),
+        "--max-time".to_string(),
+        "30".to_string(),
+        "--write-out".to_string(),
+        "\n%{http_code}".to_string(),
+        "--header".to_string(),
+        "@-".to_string(),
+        "--header".to_string(),
+        "Content-Type: application/json".to_string(),
+        "--data-binary".to_string(),
+        format!("@{}", report.display()),
+        config.url.clone(),
+    ]
+}
+
+// Sort curl's exit code and the HTTP `status` into what to tell the learner
+fn delivery(code: Option<i32>, status: Option<u16>, message: String) -> Delivery {
+    match (code, status) {
+        (Some(0), _) => Delivery::Sent,
+        // `--fail` turns every HTTP error into 22. Only these say the report
+        // itself is wrong: an outage, a timeout or too many requests pass, and
+        // so does anything curl could not even send
+        (Some(22), Some(400 | 401 | 403 | 404 | 409 | 422)) => Delivery::Rejected(message),
+        _ => Delivery::Offline(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn some(s: &str) -> Option<String> {
+        Some(s.to_string())
+    }
+
+    #[test]
+    fn opt_in_config() {
+        assert_eq!(Config::new(None, None), Ok(None));
+        assert_eq!(
+            Config::new(some("https://board.example/api"), some(" secret\n")),
+            Ok(Some(Config {
+                url: "https://board.example/api".to_string(),
+                token: "secret".to_string(),
+            }))
+        );
+        assert!(Config::new(some("https://board.example"), None)
+            .unwrap_err()
+            .contains(TOKEN_VAR));
+        assert!(Config::new(some("https://board.example"), some(" "))
+            .unwrap_err()
+            .contains(TOKEN_VAR));
+        assert!(Config::new(some("http://board.example"), some("secret"))
+            .unwrap_err()
+            .contains("https://"));
+    }
+
+    #[test]
+    fn token_stays_off_the_command_line() {
+        let config = Config {
+            url: "https://board.example".to_string(),
+            token: "secret".to_string(),
+        };
+        let args = curl_args(&config, Path::new(".submissions/1.json"));
+        assert!(args.iter().all(|arg| !arg.contains("secret")));
+        assert!(args.contains(&"@.submissions/1.json".to_string()));
+        assert_eq!(args.last(), Some(&config.url));
+    }
+
+    #[test]
+    fn curl_exit_codes() {
+        assert_eq!(delivery(Some(0), Some(200), String::new()), Delivery::Sent);
+        assert_eq!(
+            delivery(Some(6), Some(0), "no host".to_string()),
+            Delivery::Offline("no host".to_string())
+        );
+        assert!(matches!(
+            delivery(Some(35), None, String::new()),
+            Delivery::Offline(_)
+        ));
+        assert!(matches!(
+            delivery(None, None, String::new()),
+            Delivery::Offline(_)
+        ));
+    }
+
+    #[test]
+    fn only_permanent_http_errors_reject() {
+        for status in [400, 401, 403, 404, 409, 422] {
+            assert!(
+                matches!(
+                    delivery(Some(22), Some(status), String::new()),
+                    Delivery::Rejected(_)
+                ),
+                "{status}"
+            );
+        }
+        for status in [408, 429, 500, 502, 503, 504] {
+            assert!(
+                matches!(
+                    delivery(Some(22), Some(status), String::new()),
+                    Delivery::Offline(_)
+                ),
+                "{status}"
+            );
+        }
+        assert!(matches!(
+            delivery(Some(22), None, String::new()),
+            Delivery::Offline(_)
+        ));
+    }
+
+    #[test]
+    fn queue_oldest_first() {
+        let root = std::env::temp_dir().join(format!("runner-submit-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        assert_eq!(queued(&root), Ok(Vec::new()));
+        assert!(enqueue(&root).unwrap_err().contains("runner grade"));
+        std::fs::write(root.join(REPORT_FILE), "{}").unwrap();
+        assert!(enqueue(&root).unwrap_err().contains("graded_at"));
+
+        std::fs::write(root.join(REPORT_FILE), r#"{"graded_at": 200}"#).unwrap();
+        let later = enqueue(&root).unwrap();
+        std::fs::write(root.join(REPORT_FILE), r#"{"graded_at": 30}"#).unwrap();
+        let earlier = enqueue(&root).unwrap();
+        std::fs::write(root.join(QUEUE_DIR).join("notes.txt"), "").unwrap();
+        assert_eq!(queued(&root), Ok(vec![earlier, later]));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn rejected_reports_do_not_block_the_queue() {
+        let root = std::env::temp_dir().join(format!("runner-drain-{}", std::process::id()));
+        let queue = root.join(QUEUE_DIR);
+        std::fs::create_dir_all(&queue).unwrap();
+        for graded_at in [30, 200, 400] {
+            std::fs::write(queue.join(format!("{graded_at}.json")), "{}").unwrap();
+        }
+        let deliveries = drain_with(&root, |report| {
+            Ok(match report.file_stem().unwrap().to_str().unwrap() {
+                "30" => Delivery::Rejected("401".to_string()),
+                "200" => Delivery::Sent,
+                _ => Delivery::Offline("no host".to_string()),
+            })
+        })
+        .unwrap();
+        assert_eq!(
+            deliveries,
+            vec![
+                (queue.join("30.json"), Delivery::Rejected("401".to_string())),
+                (queue.join("200.json"), Delivery::Sent),
+                (
+                    queue.join("400.json"),
+                    Delivery::Offline("no host".to_string())
+                ),
+            ]
+        );
+        assert_eq!(queued(&root), Ok(vec![queue.join("400.json")]));
+        assert!(queue.join(REJECTED_DIR).join("30.json").is_file());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,390 @@
+//! Just enough TOML for `info.toml`: comments, `key = value` pairs and `[[array]]`
+//! tables, where a value is a string (basic `"..."` or multi-line `"""..."""`), an
+//! integer, a boolean or an array of those.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "string",
+            Value::Integer(_) => "integer",
+            Value::Boolean(_) => "boolean",
+            Value::Array(_) => "array",
+        }
+    }
+}
+
+/// A key with its value and the line it was defined on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub key: String,
+    pub value: Value,
+    pub line: usize,
+}
+
+/// One `[[name]]` table, or the root table when `name` is empty
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub name: String,
+    pub line: usize,
+    pub entries: Vec<Entry>,
+}
+
+impl Table {
+    pub fn get(&self, key: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Parse a document into its root table followed by every `[[...]]` table in order
+pub fn parse(text: &str) -> Result<Vec<Table>, ParseError> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        line: 1,
+    };
+    let mut tables = vec![Table {
+        name: String::new(),
+        line: 1,
+        entries: Vec::new(),
+    }];
+
+    loop {
+        parser.skip_blank();
+        let Some(c) = parser.peek() else { break };
+        let line = parser.line;
+        if c == '[' {
+            let name = parser.table_header()?;
+            tables.push(Table {
+                name,
+                line,
+                entries: Vec::new(),
+            });
+        } else {
+            let key = parser.key()?;
+            parser.skip_spaces();
+            parser.expect('=')?;
+            parser.skip_spaces();
+            let value = parser.value()?;
+            let table = tables.last_mut().expect("the root table is always there");
+            if table.get(&key).is_some() {
+                return Err(parser.error_at(line, format!("duplicate key `{key}`")));
+            }
+            table.entries.push(Entry { key, value, line });
+        }
+        parser.end_of_line()?;
+    }
+
+    Ok(tables)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        self.error_at(self.line, message)
+    }
+
+    fn error_at(&self, line: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        match self.bump() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(format!("expected `{expected}`, found `{c}`"))),
+            None => Err(self.error(format!("expected `{expected}`, found end of file"))),
+        }
+    }
+
+    // Whether a `\n` or `\r\n` comes next
+    fn at_newline(&self) -> bool {
+        self.peek() == Some('\n') || self.chars[self.pos..].starts_with(&['\r', '\n'])
+    }
+
+    fn skip_spaces(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t')) {
+            self.bump();
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        if self.peek() == Some('#') {
+            while !matches!(self.peek(), None | Some('\n')) {
+                self.bump();
+            }
+        }
+    }
+
+    // Whitespace, newlines and comments between statements or array items
+    fn skip_blank(&mut self) {
+        loop {
+            self.skip_spaces();
+            self.skip_comment();
+            match self.peek() {
+                Some('\n' | '\r') => {
+                    self.bump();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn end_of_line(&mut self) -> Result<(), ParseError> {
+        self.skip_spaces();
+        self.skip_comment();
+        match self.peek() {
+            None => Ok(()),
+            Some('\r' | '\n') => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("unexpected `{c}` after value"))),
+        }
+    }
+
+    fn table_header(&mut self) -> Result<String, ParseError> {
+        self.expect('[')?;
+        if self.peek() != Some('[') {
+            return Err(self.error("only `[[array]]` tables are supported"));
+        }
+        self.bump();
+        let name = self.key()?;
+        self.expect(']')?;
+        self.expect(']')?;
+        Ok(name)
+    }
+
+    fn key(&mut self) -> Result<String, ParseError> {
+        let mut key = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                key.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        if key.is_empty() {
+            return Err(self.error("expected a key"));
+        }
+        Ok(key)
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        match self.peek() {
+            Some('"') => self.string().map(Value::String),
+            Some('[') => self.array(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.integer(),
+            Some(c) if c.is_ascii_alphabetic() => match self.key()?.as_str() {
+                "true" => Ok(Value::Boolean(true)),
+                "false" => Ok(Value::Boolean(false)),
+                other => Err(self.error(format!("unknown value `{other}`, strings need quotes"))),
+            },
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn integer(&mut self) -> Result<Value, ParseError> {
+        let mut digits = String::new();
+        while let Some(c) = self.peek() {
+            if c == '-' || c == '_' || c.is_ascii_digit() {
+                if c != '_' {
+                    digits.push(c);
+                }
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        digits
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| self.error(format!("invalid integer `{digits}`")))
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_blank();
+            if self.peek() == Some(']') {
+                self.bump();
+                return Ok(Value::Array(items));
+            }
+            items.push(self.value()?);
+            self.skip_blank();
+            match self.bump() {
+                Some(',') => {}
+                Some(']') => return Ok(Value::Array(items)),
+                _ => return Err(self.error("expected `,` or `]` in array")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        let start = self.line;
+        let multiline = self.chars[self.pos..].starts_with(&['"', '"', '"']);
+        if multiline {
+            self.pos += 3;
+            // A newline right after the opening quotes is not part of the string
+            if self.at_newline() {
+                if self.peek() == Some('\r') {
+                    self.bump();
+                }
+                self.bump();
+            }
+        } else {
+            self.bump();
+        }
+
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(self.error_at(start, "unterminated string")),
+                Some('"') if !multiline => return Ok(text),
+                Some('"') if self.chars[self.pos..].starts_with(&['"', '"']) => {
+                    self.pos += 2;
+                    return Ok(text);
+                }
+                Some('\n') if !multiline => {
+                    return Err(self.error_at(start, "unterminated string"));
+                }
+                // Whatever the file uses, the string gets `\n`
+                Some('\r') if multiline && self.peek() == Some('\n') => {
+                    self.bump();
+                    text.push('\n');
+                }
+                // A trailing backslash in a multi-line string joins the lines
+                Some('\\') if multiline && self.at_newline() => {
+                    while matches!(self.peek(), Some(' ' | '\t' | '\r' | '\n')) {
+                        self.bump();
+                    }
+                }
+                Some('\\') => match self.bump() {
+                    Some('n') => text.push('\n'),
+                    Some('t') => text.push('\t'),
+                    Some('"') => text.push('"'),
+                    Some('\\') => text.push('\\'),
+                    _ => return Err(self.error("invalid escape sequence")),
+                },
+                Some(c) => text.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(table: &Table, key: &str) -> Value {
+        table.get(key).unwrap().value.clone()
+    }
+
+    #[test]
+    fn root_and_array_tables() {
+        let tables = parse(
+            "version = 1\n\n# first\n[[exercises]]\nname = \"a\"\n\n[[exercises]]\nname = \"b\" # second\n",
+        )
+        .unwrap();
+        assert_eq!(tables.len(), 3);
+        assert_eq!(entry(&tables[0], "version"), Value::Integer(1));
+        assert_eq!(tables[1].name, "exercises");
+        assert_eq!(tables[1].line, 4);
+        assert_eq!(entry(&tables[2], "name"), Value::String("b".to_string()));
+    }
+
+    #[test]
+    fn value_types() {
+        let tables =
+            parse("s = \"a \\\"q\\\"\\n\"\ni = -4_2\nb = false\na = [\"x\", 2,\n  true, ]\n")
+                .unwrap();
+        let root = &tables[0];
+        assert_eq!(entry(root, "s"), Value::String("a \"q\"\n".to_string()));
+        assert_eq!(entry(root, "i"), Value::Integer(-42));
+        assert_eq!(entry(root, "b"), Value::Boolean(false));
+        assert_eq!(
+            entry(root, "a"),
+            Value::Array(vec![
+                Value::String("x".to_string()),
+                Value::Integer(2),
+                Value::Boolean(true)
+            ])
+        );
+    }
+
+    #[test]
+    fn multiline_strings() {
+        let tables = parse("hint = \"\"\"\nfirst\nsecond \\\n   joined\"\"\"\nnext = 1\n").unwrap();
+        assert_eq!(
+            entry(&tables[0], "hint"),
+            Value::String("first\nsecond joined".to_string())
+        );
+        assert_eq!(tables[0].get("next").unwrap().line, 5);
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        let tables =
+            parse("hint = \"\"\"\r\nfirst\r\nsecond \\\r\n   joined\"\"\"\r\nnext = 1\r\n")
+                .unwrap();
+        assert_eq!(
+            entry(&tables[0], "hint"),
+            Value::String("first\nsecond joined".to_string())
+        );
+        assert_eq!(tables[0].get("next").unwrap().line, 5);
+    }
+
+    #[test]
+    fn errors_report_lines() {
+        let error = |text: &str| parse(text).unwrap_err();
+        assert_eq!(error("a = 1\nb = nope\n").line, 2);
+        assert_eq!(error("a = \"open\nb = 1").message, "unterminated string");
+        assert_eq!(error("a = 1\na = 2").message, "duplicate key `a`");
+        assert_eq!(error("[table]").line, 1);
+        assert_eq!(error("a = 1 2").message, "unexpected `2` after value");
+        assert_eq!(error("= 1").message, "expected a key");
+    }
+}
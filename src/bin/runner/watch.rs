@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often watched files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Notices when a file is saved by polling its modification time
+pub struct FileWatcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+}
+
+impl FileWatcher {
+    pub fn new(path: &Path) -> Self {
+        FileWatcher {
+            path: path.to_path_buf(),
+            last_modified: modified(path),
+        }
+    }
+
+    /// Whether the file was saved since the last call (or since the watcher was created)
+    pub fn changed(&mut self) -> bool {
+        let now = modified(&self.path);
+        if now != self.last_modified {
+            self.last_modified = now;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Sleep until the file is saved again
+    pub fn wait(&mut self) {
+        while !self.changed() {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn notices_new_modification_time() {
+        let path = std::env::temp_dir().join(format!("runner-watch-{}.rs", std::process::id()));
+        fs::write(&path, "fn main() {}").unwrap();
+        let mut watcher = FileWatcher::new(&path);
+        assert!(!watcher.changed());
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.changed());
+    }
+}